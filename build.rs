@@ -0,0 +1,44 @@
+use std::{env, fs, path::Path};
+
+/// Embed every `prompts/**/*.md` template so new prompts only need a file.
+fn main() {
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("prompts");
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    let mut files = Vec::new();
+    collect(&root, &root, &mut files);
+    files.sort();
+
+    let mut out = String::from("const PROMPT_FILES: &[(&str, &str)] = &[\n");
+    for (relative, absolute) in &files {
+        out.push_str(&format!(
+            "    ({relative:?}, include_str!({absolute:?})),\n"
+        ));
+    }
+    out.push_str("];\n");
+    fs::write(
+        Path::new(&env::var("OUT_DIR").unwrap()).join("prompt_files.rs"),
+        out,
+    )
+    .unwrap();
+}
+
+fn collect(root: &Path, dir: &Path, files: &mut Vec<(String, String)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            println!("cargo:rerun-if-changed={}", path.display());
+            collect(root, &path, files);
+        } else if path.extension().is_some_and(|ext| ext == "md") {
+            let relative = path
+                .strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/");
+            files.push((relative, path.display().to_string()));
+        }
+    }
+}
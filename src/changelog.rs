@@ -0,0 +1,386 @@
+use std::{cmp::Ordering, fmt, fmt::Write};
+
+/// How a change breaks code written against the older release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Same item under a new name or path.
+    Renamed,
+    /// Gone, with a replacement to use instead.
+    Removed,
+    /// Different arguments, return type or generics.
+    Signature,
+    /// Compiles unchanged but behaves differently.
+    Behavior,
+}
+
+impl Kind {
+    fn title(self) -> &'static str {
+        match self {
+            Kind::Renamed => "Renamed",
+            Kind::Removed => "Removed",
+            Kind::Signature => "Signature",
+            Kind::Behavior => "Behavior",
+        }
+    }
+}
+
+/// One breaking change between alloy releases. This table drives both the
+/// `alloy_changes` tool and the `alloy://changelog` resources.
+pub struct Change {
+    /// First alloy release with the change. alloy-core changes are listed
+    /// under the alloy release that moved to the new core version.
+    pub version: &'static str,
+    pub kind: Kind,
+    /// Crate the change was made in.
+    pub krate: &'static str,
+    /// The API before and after, as shown in tables.
+    pub before: &'static str,
+    pub after: &'static str,
+    /// What changed and why, and how to update.
+    pub why: &'static str,
+    /// Code against the older and the newer release.
+    pub before_code: &'static str,
+    pub after_code: &'static str,
+    /// Upstream pull request, as `alloy-rs/alloy#2225`.
+    pub pr: &'static str,
+}
+
+pub const CHANGES: &[Change] = &[
+    Change {
+        version: "0.6.0",
+        kind: Kind::Behavior,
+        krate: "alloy-provider",
+        before: "`call`/`estimate_gas` at `latest`",
+        after: "`call`/`estimate_gas` at `pending`",
+        why: "`eth_call` and `eth_estimateGas` run against the pending block unless a block is given, so they see transactions still in the mempool. Pin `.block(..)` where the result must match a mined state.",
+        before_code: "let output = provider.call(&tx).await?; // latest block",
+        after_code: "let output = provider.call(&tx).block(BlockId::latest()).await?;",
+        pr: "alloy-rs/alloy#1568",
+    },
+    Change {
+        version: "0.6.0",
+        kind: Kind::Signature,
+        krate: "alloy-provider",
+        before: "`subscribe_blocks` yields blocks",
+        after: "`subscribe_blocks` yields headers",
+        why: "`eth_subscribe(\"newHeads\")` only sends headers, so the subscription is now typed as `Subscription<N::HeaderResponse>`. Drop the `.header` step, and fetch the block by hash when the transactions are needed.",
+        before_code: "let mut stream = provider.subscribe_blocks().await?.into_stream();\nwhile let Some(block) = stream.next().await {\n    println!(\"{}\", block.header.number);\n}",
+        after_code: "let mut stream = provider.subscribe_blocks().await?.into_stream();\nwhile let Some(header) = stream.next().await {\n    println!(\"{}\", header.number);\n}",
+        pr: "alloy-rs/alloy#1586",
+    },
+    Change {
+        version: "0.7.0",
+        kind: Kind::Renamed,
+        krate: "alloy-signer",
+        before: "`Signature` (with `Parity`)",
+        after: "`PrimitiveSignature` (with `bool` parity)",
+        why: "Signers return `alloy::primitives::PrimitiveSignature`, which `alloy::signers::Signature` now aliases. Its `v()` is the y-parity as a `bool` rather than a `Parity` enum.",
+        before_code: "let sig: alloy::primitives::Signature = signer.sign_hash(&hash).await?;\nlet parity = sig.v(); // Parity",
+        after_code: "let sig: alloy::primitives::PrimitiveSignature = signer.sign_hash(&hash).await?;\nlet y_parity: bool = sig.v();",
+        pr: "alloy-rs/alloy#1671",
+    },
+    Change {
+        version: "0.11.0",
+        kind: Kind::Signature,
+        krate: "alloy-provider",
+        before: "`Provider<T, N>`",
+        after: "`Provider<N>`",
+        why: "The transport type parameter was removed from providers and contract instances: transports are boxed inside the client. Drop `T` from bounds, `RootProvider<BoxTransport>` becomes `RootProvider`, and `ContractInstance<T, P, N>` becomes `ContractInstance<P, N>`.",
+        before_code: "async fn balance<T: Transport + Clone, P: Provider<T>>(provider: &P, who: Address) -> eyre::Result<U256> {\n    Ok(provider.get_balance(who).await?)\n}",
+        after_code: "async fn balance<P: Provider>(provider: &P, who: Address) -> eyre::Result<U256> {\n    Ok(provider.get_balance(who).await?)\n}",
+        pr: "alloy-rs/alloy#1859",
+    },
+    Change {
+        version: "0.11.0",
+        kind: Kind::Behavior,
+        krate: "alloy-provider",
+        before: "`ProviderBuilder::new().with_recommended_fillers()`",
+        after: "`ProviderBuilder::new()`",
+        why: "`ProviderBuilder::new()` installs the recommended fillers (gas, nonce, chain ID) itself. Calling `.with_recommended_fillers()` on it no longer compiles; use `ProviderBuilder::default()` or `.disable_recommended_fillers()` for an empty filler stack.",
+        before_code: "let provider = ProviderBuilder::new()\n    .with_recommended_fillers()\n    .wallet(wallet)\n    .on_http(url);",
+        after_code: "let provider = ProviderBuilder::new()\n    .wallet(wallet)\n    .on_http(url);",
+        pr: "alloy-rs/alloy#1901",
+    },
+    Change {
+        version: "0.11.0",
+        kind: Kind::Removed,
+        krate: "alloy-contract",
+        before: "`.await` on a `CallBuilder`",
+        after: "`.call().await`",
+        why: "`CallBuilder` no longer implements `IntoFuture`, so awaiting it directly does not compile. Say what the builder should do: `.call()` for `eth_call`, `.send()` for a transaction.",
+        before_code: "let balance = contract.balanceOf(owner).await?;",
+        after_code: "let balance = contract.balanceOf(owner).call().await?;",
+        pr: "alloy-rs/alloy#1945",
+    },
+    Change {
+        version: "0.11.0",
+        kind: Kind::Behavior,
+        krate: "alloy",
+        before: "default features: core types only",
+        after: "default features include `essentials`",
+        why: "The umbrella crate's default features add `essentials` (`contract`, `provider-http`, `rpc-types`, `signer-local`). Builds with default features pull in reqwest; set `default-features = false` to keep the old footprint.",
+        before_code: "alloy = { version = \"0.10\", features = [\"contract\", \"provider-http\", \"signer-local\"] }",
+        after_code: "alloy = \"0.11\" # essentials included",
+        pr: "alloy-rs/alloy#1904",
+    },
+    Change {
+        version: "0.12.0",
+        kind: Kind::Renamed,
+        krate: "alloy-provider",
+        before: "`ProviderBuilder::on_builtin`",
+        after: "`ProviderBuilder::connect`",
+        why: "Connecting from a URL string that picks the transport (`http`, `ws`, `ipc`) by scheme is now `connect`, matching the later `connect_*` names.",
+        before_code: "let provider = ProviderBuilder::new().on_builtin(\"https://eth.llamarpc.com\").await?;",
+        after_code: "let provider = ProviderBuilder::new().connect(\"https://eth.llamarpc.com\").await?;",
+        pr: "alloy-rs/alloy#2078",
+    },
+    Change {
+        version: "0.12.0",
+        kind: Kind::Signature,
+        krate: "alloy-provider",
+        before: "`get_block_by_number(number, kind)`",
+        after: "`get_block_by_number(number).full()`",
+        why: "`get_block`, `get_block_by_number` and `get_block_by_hash` return an `EthGetBlock` builder instead of taking a `BlockTransactionsKind`. Await it for transaction hashes, or call `.full()` first for full transactions.",
+        before_code: "let block = provider\n    .get_block_by_number(BlockNumberOrTag::Latest, BlockTransactionsKind::Full)\n    .await?;",
+        after_code: "let block = provider\n    .get_block_by_number(BlockNumberOrTag::Latest)\n    .full()\n    .await?;",
+        pr: "alloy-rs/alloy#2044",
+    },
+    Change {
+        version: "0.12.0",
+        kind: Kind::Signature,
+        krate: "alloy-provider",
+        before: "`call(&tx)`, `estimate_gas(&tx)`",
+        after: "`call(tx)`, `estimate_gas(tx)`",
+        why: "The `eth_call` builders own their request instead of borrowing it, so the returned builder can outlive the request. Pass the request by value (clone it if it is used again).",
+        before_code: "let gas = provider.estimate_gas(&tx).await?;\nlet output = provider.call(&tx).await?;",
+        after_code: "let gas = provider.estimate_gas(tx.clone()).await?;\nlet output = provider.call(tx).await?;",
+        pr: "alloy-rs/alloy#2127",
+    },
+    Change {
+        version: "0.15.0",
+        kind: Kind::Renamed,
+        krate: "alloy-provider",
+        before: "`ProviderBuilder::on_*`",
+        after: "`ProviderBuilder::connect_*`",
+        why: "`on_http`, `on_ws`, `on_ipc`, `on_client`, `on_provider`, `on_anvil` and `on_anvil_with_wallet` became `connect_http`, `connect_ws`, `connect_ipc`, `connect_client`, `connect_provider`, `connect_anvil` and `connect_anvil_with_wallet`. The old names were deprecated, and 1.x no longer has them apart from the deprecated `on_anvil_with_config` and `on_anvil_with_wallet_and_config`.",
+        before_code: "let http = ProviderBuilder::new().on_http(url);\nlet ws = ProviderBuilder::new().on_ws(WsConnect::new(ws_url)).await?;",
+        after_code: "let http = ProviderBuilder::new().connect_http(url);\nlet ws = ProviderBuilder::new().connect_ws(WsConnect::new(ws_url)).await?;",
+        pr: "alloy-rs/alloy#2225",
+    },
+    Change {
+        version: "1.0.0",
+        kind: Kind::Signature,
+        krate: "alloy-sol-types",
+        before: "`abi_decode(data, validate)`",
+        after: "`abi_decode(data)` / `abi_decode_validate(data)`",
+        why: "Every decoder lost its `validate: bool` argument: `SolType`, `SolValue`, `SolCall`, `SolError` and `SolEvent` (`decode_log`, `decode_raw_log`). The plain form is lenient; the `_validate` form re-encodes and rejects non-canonical input, like `validate = true` did.",
+        before_code: "let value = U256::abi_decode(&data, true)?;\nlet transfer = Transfer::decode_log(&log.inner, true)?;",
+        after_code: "let value = U256::abi_decode_validate(&data)?;\nlet transfer = Transfer::decode_log(&log.inner)?;",
+        pr: "alloy-rs/core#863",
+    },
+    Change {
+        version: "1.0.0",
+        kind: Kind::Signature,
+        krate: "alloy-sol-types",
+        before: "`.call().await?._0`",
+        after: "`.call().await?`",
+        why: "Calls to functions with a single return value yield that value instead of a `<name>Return` struct, both from contract `.call()` and from `SolCall::abi_decode_returns`. Functions with several return values still yield the struct.",
+        before_code: "let balance: U256 = contract.balanceOf(owner).call().await?._0;\nlet ok: bool = transferCall::abi_decode_returns(&output, true)?._0;",
+        after_code: "let balance: U256 = contract.balanceOf(owner).call().await?;\nlet ok: bool = transferCall::abi_decode_returns(&output)?;",
+        pr: "alloy-rs/core#855",
+    },
+    Change {
+        version: "1.0.0",
+        kind: Kind::Renamed,
+        krate: "alloy-primitives",
+        before: "`PrimitiveSignature`",
+        after: "`Signature`",
+        why: "The deprecated parity-based `Signature` was removed and `PrimitiveSignature` took its name. `alloy::signers::Signature` is the same type.",
+        before_code: "use alloy::primitives::PrimitiveSignature;\nlet sig = PrimitiveSignature::new(r, s, y_parity);",
+        after_code: "use alloy::primitives::Signature;\nlet sig = Signature::new(r, s, y_parity);",
+        pr: "alloy-rs/core#899",
+    },
+    Change {
+        version: "1.0.0",
+        kind: Kind::Removed,
+        krate: "alloy-primitives",
+        before: "`Bytes::from(String)`",
+        after: "`Bytes::from(string.into_bytes())`",
+        why: "`From<String> for Bytes` was removed. Convert the text's UTF-8 bytes explicitly, or parse hex with `\"0x..\".parse::<Bytes>()`.",
+        before_code: "let data = Bytes::from(text);",
+        after_code: "let data = Bytes::from(text.into_bytes());",
+        pr: "alloy-rs/core#907",
+    },
+];
+
+/// An alloy version as `major.minor.patch`; missing parts are zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version(u64, u64, u64);
+
+impl Version {
+    /// Stands for the newest release.
+    pub const LATEST: Self = Self(u64::MAX, 0, 0);
+
+    /// Parse a release (`0.15.3`), a requirement (`^0.12`, `v1`) or `latest`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if text.eq_ignore_ascii_case("latest") {
+            return Some(Self::LATEST);
+        }
+        let text = text.trim_start_matches(['^', '=', '~', '>', '<', 'v', 'V', ' ']);
+        // Pre-release and build suffixes count as the release itself.
+        let core = text.split(['-', '+']).next()?;
+        let mut parts = core.split('.').map(|p| p.parse::<u64>());
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+        let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+        Some(Self(major, minor, patch))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Self::LATEST {
+            f.write_str("latest")
+        } else {
+            write!(f, "{}.{}.{}", self.0, self.1, self.2)
+        }
+    }
+}
+
+fn version(change: &Change) -> Version {
+    Version::parse(change.version).unwrap_or(Version::LATEST)
+}
+
+/// Releases in the table, oldest first.
+pub fn releases() -> Vec<&'static str> {
+    let mut releases: Vec<&str> = CHANGES.iter().map(|c| c.version).collect();
+    releases.dedup();
+    releases
+}
+
+/// Changes made after `from` up to and including `to`, oldest first. For a
+/// downgrade (`to` older than `from`) these are the changes to undo.
+pub fn between(from: Version, to: Version) -> Vec<&'static Change> {
+    let (low, high) = match from.cmp(&to) {
+        Ordering::Greater => (to, from),
+        _ => (from, to),
+    };
+    CHANGES
+        .iter()
+        .filter(|c| (low..=high).contains(&version(c)) && version(c) != low)
+        .collect()
+}
+
+/// Whether `change` mentions `query` (case-insensitive) in its APIs, crate
+/// or explanation.
+pub fn mentions(change: &Change, query: &str) -> bool {
+    let query = query.to_lowercase();
+    [
+        change.before,
+        change.after,
+        change.krate,
+        change.why,
+        change.before_code,
+        change.after_code,
+    ]
+    .iter()
+    .any(|text| text.to_lowercase().contains(&query))
+}
+
+/// Fence language for a snippet: the `Cargo.toml` ones are TOML.
+fn lang(code: &str) -> &'static str {
+    if code.starts_with("alloy =") {
+        "toml"
+    } else {
+        "rust"
+    }
+}
+
+fn pr_link(pr: &str) -> String {
+    match pr.split_once('#') {
+        Some((repo, number)) => format!("[{pr}](https://github.com/{repo}/pull/{number})"),
+        None => pr.to_string(),
+    }
+}
+
+/// Summary table and per-release details for `changes`, oldest first.
+pub fn render(changes: &[&Change]) -> String {
+    let mut out = String::from(
+        "| Release | Crate | Kind | Before | After |\n|---------|-------|------|--------|-------|\n",
+    );
+    for change in changes {
+        let _ = writeln!(
+            out,
+            "| {} | `{}` | {} | {} | {} |",
+            change.version,
+            change.krate,
+            change.kind.title(),
+            change.before,
+            change.after
+        );
+    }
+    let mut release = "";
+    for change in changes {
+        if change.version != release {
+            release = change.version;
+            let _ = write!(out, "\n## {release}\n");
+        }
+        let _ = write!(
+            out,
+            "\n### {} → {}\n\n{} ({})\n\nBefore:\n\n```{}\n{}\n```\n\nAfter:\n\n```{}\n{}\n```\n",
+            change.before,
+            change.after,
+            change.why,
+            pr_link(change.pr),
+            lang(change.before_code),
+            change.before_code,
+            lang(change.after_code),
+            change.after_code
+        );
+    }
+    out
+}
+
+/// `changes` between `from` and `to`. A downgrade lists them newest first,
+/// with a note on undoing them.
+pub fn render_range(from: Version, to: Version, changes: &[&Change]) -> String {
+    if from <= to {
+        return render(changes);
+    }
+    let mut newest_first = changes.to_vec();
+    newest_first.reverse();
+    format!(
+        "This is a downgrade: undo each change below, in the order listed, by going from the \
+        After form back to the Before form.\n\n{}",
+        render(&newest_first)
+    )
+}
+
+/// The `alloy://changelog/{from}..{to}` page.
+pub fn range_guide(from: Version, to: Version) -> String {
+    let changes = between(from, to);
+    let mut out = format!("# alloy Breaking Changes: {from} → {to}\n\n");
+    if changes.is_empty() {
+        let _ = writeln!(
+            out,
+            "No breaking changes are recorded between {from} and {to}. See `alloy://changelog` \
+            for every recorded change."
+        );
+    } else {
+        out.push_str(&render_range(from, to, &changes));
+    }
+    out
+}
+
+/// The `alloy://changelog` page: every recorded change.
+pub fn guide() -> String {
+    let changes: Vec<&Change> = CHANGES.iter().collect();
+    format!(
+        "# alloy Breaking Changes\n\n\
+        Renamed, removed and re-signatured APIs across alloy releases, with code before and \
+        after. Read `alloy://changelog/{{from}}..{{to}}` (e.g. `alloy://changelog/0.11..1.0`) \
+        for the changes between two versions, or call the `alloy_changes` tool.\n\n{}",
+        render(&changes)
+    )
+}
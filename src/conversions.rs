@@ -0,0 +1,780 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Write,
+};
+
+/// How a step gets from one type to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Via {
+    /// An infallible `From` impl.
+    From,
+    /// A `TryFrom` impl or a fallible `try_into_*` method.
+    TryFrom,
+    /// An inherent or trait method.
+    Method,
+    /// `str::parse` through a `FromStr` impl.
+    Parse,
+    /// Signing with a signer.
+    Sign,
+    /// Filling and signing a request through a wallet.
+    Build,
+    /// EIP-2718 encoding or decoding.
+    Codec,
+}
+
+impl Via {
+    pub fn title(self) -> &'static str {
+        match self {
+            Via::From => "From",
+            Via::TryFrom => "TryFrom",
+            Via::Method => "Method",
+            Via::Parse => "Parse",
+            Via::Sign => "Sign",
+            Via::Build => "Build",
+            Via::Codec => "Encoding",
+        }
+    }
+}
+
+/// One conversion between two alloy types. This table drives both the
+/// `find_conversion` tool and the `alloy://reference/conversions` resource.
+///
+/// `{T}` in a field stands for each of [`TX_TYPES`] and `{t}` for its
+/// `try_into_*` suffix; `{x}` in `setup` and `code` is the value converted.
+pub struct Step {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub via: Via,
+    /// Statements run before the conversion, e.g. signing the input.
+    pub setup: &'static str,
+    /// The expression producing `to`.
+    pub code: &'static str,
+    /// Values besides the input that the code reads, as `name: Type`.
+    pub needs: &'static [&'static str],
+    /// Full paths of the traits and types the code names.
+    pub uses: &'static [&'static str],
+    /// What to know before relying on the step.
+    pub note: &'static str,
+}
+
+/// The Ethereum transaction types `{T}` expands to, with the suffix of the
+/// `TxEnvelope::try_into_*` method returning each.
+pub const TX_TYPES: &[(&str, &str)] = &[
+    ("TxLegacy", "legacy"),
+    ("TxEip2930", "eip2930"),
+    ("TxEip1559", "eip1559"),
+    ("TxEip4844Variant", "eip4844"),
+    ("TxEip7702", "eip7702"),
+];
+
+pub const STEPS: &[Step] = &[
+    Step {
+        from: "TransactionRequest",
+        to: "TxEnvelope",
+        via: Via::Build,
+        setup: "",
+        code: "{x}.build(&wallet).await?",
+        needs: &["wallet: EthereumWallet"],
+        uses: &["alloy::network::TransactionBuilder"],
+        note: "`build` fails unless the request has everything its transaction type needs (nonce, gas limit, fees, chain id); set them, or let a provider with the recommended fillers and a wallet sign and send it instead.",
+    },
+    Step {
+        from: "TransactionRequest",
+        to: "TypedTransaction",
+        via: Via::Build,
+        setup: "",
+        code: "{x}.build_unsigned()?",
+        needs: &[],
+        uses: &["alloy::network::TransactionBuilder"],
+        note: "Picks the transaction type from the fields that are set and fails when a required field is missing; nothing is signed.",
+    },
+    Step {
+        from: "{T}",
+        to: "Signed<{T}>",
+        via: Via::Sign,
+        setup: "let signature = signer.sign_transaction_sync(&mut {x})?;",
+        code: "{x}.into_signed(signature)",
+        needs: &["signer: PrivateKeySigner"],
+        uses: &[
+            "alloy::consensus::SignableTransaction",
+            "alloy::network::TxSignerSync",
+        ],
+        note: "`sign_transaction_sync` sets the chain id from the signer when it has one, so it takes the transaction mutably. Remote signers (Ledger, AWS, GCP) only implement the async `TxSigner::sign_transaction(&mut tx).await?`.",
+    },
+    Step {
+        from: "TypedTransaction",
+        to: "Signed<TypedTransaction>",
+        via: Via::Sign,
+        setup: "let signature = signer.sign_transaction_sync(&mut {x})?;",
+        code: "{x}.into_signed(signature)",
+        needs: &["signer: PrivateKeySigner"],
+        uses: &[
+            "alloy::consensus::SignableTransaction",
+            "alloy::network::TxSignerSync",
+        ],
+        note: "`sign_transaction_sync` sets the chain id from the signer when it has one, so it takes the transaction mutably. Remote signers (Ledger, AWS, GCP) only implement the async `TxSigner::sign_transaction(&mut tx).await?`.",
+    },
+    Step {
+        from: "{T}",
+        to: "TypedTransaction",
+        via: Via::From,
+        setup: "",
+        code: "TypedTransaction::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "Signed<{T}>",
+        to: "TxEnvelope",
+        via: Via::From,
+        setup: "",
+        code: "TxEnvelope::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "Signed<TypedTransaction>",
+        to: "TxEnvelope",
+        via: Via::From,
+        setup: "",
+        code: "TxEnvelope::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "TxEnvelope",
+        to: "Signed<{T}>",
+        via: Via::TryFrom,
+        setup: "",
+        code: "{x}.try_into_{t}()?",
+        needs: &[],
+        uses: &[],
+        note: "Fails with `ValueError<TxEnvelope>`, which hands the envelope back, when it holds another transaction type; `match` on the variants to handle every type.",
+    },
+    Step {
+        from: "TxEnvelope",
+        to: "Signed<TypedTransaction>",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.into_signed()",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "TxEnvelope",
+        to: "TypedTransaction",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.into_typed_transaction()",
+        needs: &[],
+        uses: &[],
+        note: "Drops the signature.",
+    },
+    Step {
+        from: "Signed<{T}>",
+        to: "{T}",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.strip_signature()",
+        needs: &[],
+        uses: &[],
+        note: "Drops the signature and the cached hash.",
+    },
+    Step {
+        from: "Signed<{T}>",
+        to: "Recovered<{T}>",
+        via: Via::TryFrom,
+        setup: "",
+        code: "{x}.try_into_recovered()?",
+        needs: &[],
+        uses: &[],
+        note: "Runs ecrecover (feature `k256`) and keeps only the transaction and its sender; the signature is dropped.",
+    },
+    Step {
+        from: "TxEnvelope",
+        to: "Recovered<TxEnvelope>",
+        via: Via::TryFrom,
+        setup: "",
+        code: "{x}.try_into_recovered()?",
+        needs: &[],
+        uses: &["alloy::consensus::transaction::SignerRecoverable"],
+        note: "Runs ecrecover once and keeps the sender next to the signed envelope; fails with `RecoveryError` on an invalid signature.",
+    },
+    Step {
+        from: "TxEnvelope",
+        to: "Address",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.recover_signer()?",
+        needs: &[],
+        uses: &["alloy::consensus::transaction::SignerRecoverable"],
+        note: "Runs ecrecover on every call; convert to `Recovered<TxEnvelope>` to do it once.",
+    },
+    Step {
+        from: "Recovered<TxEnvelope>",
+        to: "Address",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.signer()",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "Recovered<TxEnvelope>",
+        to: "TxEnvelope",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.into_inner()",
+        needs: &[],
+        uses: &[],
+        note: "Drops the recovered sender; `into_parts()` returns both.",
+    },
+    Step {
+        from: "Recovered<{T}>",
+        to: "{T}",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.into_inner()",
+        needs: &[],
+        uses: &[],
+        note: "Drops the recovered sender; `into_parts()` returns both.",
+    },
+    Step {
+        from: "Recovered<TxEnvelope>",
+        to: "TransactionRequest",
+        via: Via::From,
+        setup: "",
+        code: "TransactionRequest::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "Sets `from` to the recovered sender.",
+    },
+    Step {
+        from: "TxEnvelope",
+        to: "TransactionRequest",
+        via: Via::From,
+        setup: "",
+        code: "TransactionRequest::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "Leaves `from` unset; go through `Recovered<TxEnvelope>` to keep the sender.",
+    },
+    Step {
+        from: "{T}",
+        to: "TransactionRequest",
+        via: Via::From,
+        setup: "",
+        code: "TransactionRequest::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "TxEnvelope",
+        to: "B256",
+        via: Via::Method,
+        setup: "",
+        code: "*{x}.tx_hash()",
+        needs: &[],
+        uses: &[],
+        note: "The hash is cached when the envelope is built, so this does not re-hash.",
+    },
+    Step {
+        from: "TxEnvelope",
+        to: "Vec<u8>",
+        via: Via::Codec,
+        setup: "",
+        code: "{x}.encoded_2718()",
+        needs: &[],
+        uses: &["alloy::eips::eip2718::Encodable2718"],
+        note: "The EIP-2718 bytes `eth_sendRawTransaction` expects; `provider.send_raw_transaction(&raw)` sends them.",
+    },
+    Step {
+        from: "TxEnvelope",
+        to: "Bytes",
+        via: Via::Codec,
+        setup: "",
+        code: "Bytes::from({x}.encoded_2718())",
+        needs: &[],
+        uses: &["alloy::eips::eip2718::Encodable2718"],
+        note: "The EIP-2718 bytes as `Bytes`, e.g. to log or store them.",
+    },
+    Step {
+        from: "Vec<u8>",
+        to: "TxEnvelope",
+        via: Via::Codec,
+        setup: "",
+        code: "TxEnvelope::decode_2718(&mut {x}.as_slice())?",
+        needs: &[],
+        uses: &["alloy::eips::eip2718::Decodable2718"],
+        note: "Decodes raw transaction bytes as they are broadcast, typed or legacy.",
+    },
+    Step {
+        from: "Bytes",
+        to: "TxEnvelope",
+        via: Via::Codec,
+        setup: "",
+        code: "TxEnvelope::decode_2718(&mut {x}.as_ref())?",
+        needs: &[],
+        uses: &["alloy::eips::eip2718::Decodable2718"],
+        note: "Decodes raw transaction bytes as they are broadcast, typed or legacy.",
+    },
+    Step {
+        from: "Transaction",
+        to: "Recovered<TxEnvelope>",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.into_recovered()",
+        needs: &[],
+        uses: &[],
+        note: "`Transaction` is the RPC response type; it already carries the sender, so nothing is recovered.",
+    },
+    Step {
+        from: "Transaction",
+        to: "TxEnvelope",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.into_inner()",
+        needs: &[],
+        uses: &[],
+        note: "Drops the block fields and the sender of the RPC `Transaction`.",
+    },
+    Step {
+        from: "Transaction",
+        to: "TransactionRequest",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.into_request()",
+        needs: &[],
+        uses: &[],
+        note: "Copies the fields and the sender into a request, e.g. to replay a transaction.",
+    },
+    Step {
+        from: "&str",
+        to: "PrivateKeySigner",
+        via: Via::Parse,
+        setup: "",
+        code: "{x}.parse::<PrivateKeySigner>()?",
+        needs: &[],
+        uses: &[],
+        note: "Accepts a hex private key with or without `0x`.",
+    },
+    Step {
+        from: "PrivateKeySigner",
+        to: "EthereumWallet",
+        via: Via::From,
+        setup: "",
+        code: "EthereumWallet::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "PrivateKeySigner",
+        to: "Address",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.address()",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "&str",
+        to: "Address",
+        via: Via::Parse,
+        setup: "",
+        code: "{x}.parse::<Address>()?",
+        needs: &[],
+        uses: &[],
+        note: "Accepts any checksum casing; use `Address::parse_checksummed` to enforce EIP-55. Write literals with `address!`.",
+    },
+    Step {
+        from: "&str",
+        to: "B256",
+        via: Via::Parse,
+        setup: "",
+        code: "{x}.parse::<B256>()?",
+        needs: &[],
+        uses: &[],
+        note: "Write literals with `b256!`.",
+    },
+    Step {
+        from: "&str",
+        to: "U256",
+        via: Via::Parse,
+        setup: "",
+        code: "{x}.parse::<U256>()?",
+        needs: &[],
+        uses: &[],
+        note: "Parses decimal, or hex with `0x`. Use `parse_ether`/`parse_units` for amounts with decimals.",
+    },
+    Step {
+        from: "&str",
+        to: "Bytes",
+        via: Via::Parse,
+        setup: "",
+        code: "{x}.parse::<Bytes>()?",
+        needs: &[],
+        uses: &[],
+        note: "Parses hex with or without `0x`.",
+    },
+    Step {
+        from: "Address",
+        to: "B256",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.into_word()",
+        needs: &[],
+        uses: &[],
+        note: "Left-pads the 20 bytes with zeros, as in ABI encoding and log topics.",
+    },
+    Step {
+        from: "B256",
+        to: "Address",
+        via: Via::Method,
+        setup: "",
+        code: "Address::from_word({x})",
+        needs: &[],
+        uses: &[],
+        note: "Keeps the last 20 bytes.",
+    },
+    Step {
+        from: "B256",
+        to: "U256",
+        via: Via::From,
+        setup: "",
+        code: "U256::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "Reads the bytes as big-endian.",
+    },
+    Step {
+        from: "U256",
+        to: "B256",
+        via: Via::From,
+        setup: "",
+        code: "B256::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "Writes the number as 32 big-endian bytes.",
+    },
+    Step {
+        from: "u64",
+        to: "U256",
+        via: Via::From,
+        setup: "",
+        code: "U256::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "U256",
+        to: "u64",
+        via: Via::TryFrom,
+        setup: "",
+        code: "u64::try_from({x})?",
+        needs: &[],
+        uses: &[],
+        note: "Fails when the value exceeds `u64::MAX`; `.to::<u64>()` panics instead and `.saturating_to::<u64>()` clamps.",
+    },
+    Step {
+        from: "B256",
+        to: "[u8; 32]",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.0",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "[u8; 32]",
+        to: "B256",
+        via: Via::From,
+        setup: "",
+        code: "B256::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "Vec<u8>",
+        to: "Bytes",
+        via: Via::From,
+        setup: "",
+        code: "Bytes::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "Takes the buffer without copying.",
+    },
+    Step {
+        from: "Bytes",
+        to: "Vec<u8>",
+        via: Via::Method,
+        setup: "",
+        code: "{x}.to_vec()",
+        needs: &[],
+        uses: &[],
+        note: "Copies; borrow with `.as_ref()` when a `&[u8]` is enough.",
+    },
+    Step {
+        from: "u64",
+        to: "BlockNumberOrTag",
+        via: Via::From,
+        setup: "",
+        code: "BlockNumberOrTag::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "BlockNumberOrTag",
+        to: "BlockId",
+        via: Via::From,
+        setup: "",
+        code: "BlockId::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "",
+    },
+    Step {
+        from: "u64",
+        to: "BlockId",
+        via: Via::From,
+        setup: "",
+        code: "BlockId::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "`BlockId::number(n)` reads the same.",
+    },
+    Step {
+        from: "B256",
+        to: "BlockId",
+        via: Via::From,
+        setup: "",
+        code: "BlockId::from({x})",
+        needs: &[],
+        uses: &[],
+        note: "A block hash; `BlockId::hash(h)` reads the same.",
+    },
+];
+
+/// A [`Step`] with `{T}` expanded, or a conversion read from an API page.
+#[derive(Debug, Clone)]
+pub struct Edge {
+    pub from: String,
+    pub to: String,
+    pub via: Via,
+    pub setup: String,
+    pub code: String,
+    pub needs: Vec<String>,
+    pub uses: Vec<String>,
+    pub note: String,
+    /// Read from the generated API pages rather than [`STEPS`].
+    pub api: bool,
+}
+
+/// Every step of [`STEPS`], with `{T}` expanded over [`TX_TYPES`].
+pub fn edges() -> Vec<Edge> {
+    let mut edges = Vec::new();
+    for step in STEPS {
+        let generic = [step.from, step.to, step.code, step.setup]
+            .iter()
+            .any(|s| s.contains("{T}") || s.contains("{t}"));
+        let types: &[(&str, &str)] = if generic { TX_TYPES } else { &[("", "")] };
+        for (ty, suffix) in types {
+            let fill = |s: &str| s.replace("{T}", ty).replace("{t}", suffix);
+            edges.push(Edge {
+                from: fill(step.from),
+                to: fill(step.to),
+                via: step.via,
+                setup: fill(step.setup),
+                code: fill(step.code),
+                needs: step.needs.iter().map(|n| n.to_string()).collect(),
+                uses: step.uses.iter().map(|u| u.to_string()).collect(),
+                note: step.note.to_string(),
+                api: false,
+            });
+        }
+    }
+    edges
+}
+
+/// Spellings of the same type, keyed without whitespace.
+const ALIASES: &[(&str, &str)] = &[
+    ("EthereumTxEnvelope<TxEip4844Variant>", "TxEnvelope"),
+    (
+        "EthereumTypedTransaction<TxEip4844Variant>",
+        "TypedTransaction",
+    ),
+    ("FixedBytes<32>", "B256"),
+    ("Uint<256,4>", "U256"),
+    ("LocalSigner<SigningKey>", "PrivateKeySigner"),
+    ("PrimitiveSignature", "Signature"),
+    ("String", "&str"),
+    ("str", "&str"),
+];
+
+/// The graph key of a type as written anywhere: paths and whitespace are
+/// dropped and aliases resolved, so `alloy::consensus::Signed<TxEip1559>`
+/// and `Signed < TxEip1559 >` meet.
+pub fn key(ty: &str) -> String {
+    let mut key = String::new();
+    let mut word = String::new();
+    for c in ty.chars().chain([' ']) {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            word.push(c);
+            continue;
+        }
+        key.push_str(word.rsplit("::").next().unwrap_or_default());
+        word.clear();
+        if !c.is_whitespace() {
+            key.push(c);
+        }
+    }
+    for (alias, name) in ALIASES {
+        if key == *alias {
+            return name.to_string();
+        }
+        key = key.replace(&format!("<{alias}>"), &format!("<{name}>"));
+    }
+    key
+}
+
+/// Plain values whose meaning depends on where they came from: a `B256` may
+/// be a transaction hash, a block hash or a storage slot.
+const VALUES: [&str; 8] = [
+    "Address", "B256", "U256", "u64", "Bytes", "Vec<u8>", "[u8;32]", "&str",
+];
+
+/// The fewest edges leading from `from` to `to`, preferring earlier edges
+/// when paths tie. A path only passes through a plain value on the way to
+/// another one, and only when it starts at one, so `TxEnvelope` does not
+/// reach `BlockId` through its hash.
+pub fn shortest<'a>(edges: &'a [Edge], from: &str, to: &str) -> Option<Vec<&'a Edge>> {
+    let (from, to) = (key(from), key(to));
+    let mut next: HashMap<String, Vec<&Edge>> = HashMap::new();
+    for edge in edges {
+        next.entry(key(&edge.from)).or_default().push(edge);
+    }
+    let mut came_from: HashMap<String, &Edge> = HashMap::new();
+    let mut queue = VecDeque::from([from.clone()]);
+    while let Some(node) = queue.pop_front() {
+        if node == to {
+            let mut path = Vec::new();
+            let mut at = to;
+            while at != from {
+                let edge = came_from[&at];
+                path.push(edge);
+                at = key(&edge.from);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for edge in next.get(&node).into_iter().flatten() {
+            let target = key(&edge.to);
+            let through_value = node != from && VALUES.contains(&node.as_str());
+            if through_value
+                && !(VALUES.contains(&from.as_str()) && VALUES.contains(&target.as_str()))
+            {
+                continue;
+            }
+            if target != from && !came_from.contains_key(&target) {
+                came_from.insert(target.clone(), edge);
+                queue.push_back(target);
+            }
+        }
+    }
+    None
+}
+
+/// The variable a value of `ty` is bound to in generated code.
+pub fn binding(ty: &str) -> String {
+    let key = key(ty);
+    let base = key.split('<').next().unwrap_or_default();
+    match base {
+        "TransactionRequest" => "request".into(),
+        "TxEnvelope" => "envelope".into(),
+        "TypedTransaction" => "typed_tx".into(),
+        "Signed" => "signed".into(),
+        "Recovered" => "recovered".into(),
+        "Transaction" => "rpc_tx".into(),
+        "PrivateKeySigner" => "signer".into(),
+        "EthereumWallet" => "wallet".into(),
+        "Vec" => "raw".into(),
+        "Bytes" => "bytes".into(),
+        "B256" => "hash".into(),
+        "&str" | "[u8;32]" | "u64" => "value".into(),
+        _ if base.starts_with("Tx") => "tx".into(),
+        _ => {
+            let mut name = String::new();
+            for (i, c) in base.chars().enumerate() {
+                if c.is_uppercase() && i > 0 {
+                    name.push('_');
+                }
+                name.extend(c.to_lowercase());
+            }
+            name
+        }
+    }
+}
+
+/// Markdown for `alloy://reference/conversions`: the steps grouped by the
+/// type they start from.
+pub fn guide() -> String {
+    let tx_types: Vec<String> = TX_TYPES.iter().map(|(t, _)| format!("`{t}`")).collect();
+    let suffixes: Vec<&str> = TX_TYPES.iter().map(|(_, s)| *s).collect();
+    let mut result = format!(
+        "# alloy Type Conversions\n\n\
+        The `From`/`TryFrom` impls, methods, signing and encoding steps that turn one alloy type \
+        into another. The `find_conversion` tool chains them into the shortest path between two \
+        types, with code. `T` stands for any of {}.\n",
+        tx_types.join(", ")
+    );
+    let mut from: Vec<&str> = Vec::new();
+    for step in STEPS {
+        if !from.contains(&step.from) {
+            from.push(step.from);
+        }
+    }
+    for ty in from {
+        let display = |s: &str| s.replace("{T}", "T");
+        let _ = writeln!(result, "\n## `{}`\n", display(ty));
+        for step in STEPS.iter().filter(|s| s.from == ty) {
+            let x = binding(&step.from.replace("{T}", "TxEip1559"));
+            let fill = |s: &str| {
+                display(s)
+                    .replace("{t}", &format!("{{{}}}", suffixes.join(",")))
+                    .replace("{x}", &x)
+            };
+            let _ = write!(
+                result,
+                "- → `{}` ({}): `{}`",
+                display(step.to),
+                step.via.title(),
+                fill(step.code)
+            );
+            if !step.setup.is_empty() {
+                let _ = write!(result, " after `{}`", fill(step.setup));
+            }
+            if !step.needs.is_empty() {
+                let _ = write!(result, "; needs `{}`", step.needs.join("`, `"));
+            }
+            if !step.note.is_empty() {
+                let _ = write!(result, " — {}", step.note);
+            }
+            if !step.uses.is_empty() {
+                let _ = write!(result, " (`use {};`)", step.uses.join(";`, `use "));
+            }
+            result.push('\n');
+        }
+    }
+    result
+}
@@ -1,4 +1,10 @@
+pub mod changelog;
+pub mod conversions;
+pub mod migration;
+pub mod networks;
 pub mod prompts;
 pub mod resources;
+pub mod rustdoc;
 pub mod server;
 pub mod tools;
+pub mod workspace;
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use alloy_mcp::server::AlloyMcpServer;
 use rmcp::ServiceExt;
 use tokio::io::{stdin, stdout};
@@ -14,7 +16,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     tracing::info!("Starting alloy-mcp server");
 
-    let server = AlloyMcpServer::new();
+    let mut server = match path_arg("--workspace") {
+        Some(path) => AlloyMcpServer::with_workspace(&path),
+        None => AlloyMcpServer::new(),
+    };
+    if let Some(dir) = path_arg("--rustdoc") {
+        server = server.with_rustdoc(&dir);
+    }
     let transport = (stdin(), stdout());
 
     let service = server.serve(transport).await?;
@@ -22,3 +30,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// The path given as `<flag> <path>` or `<flag>=<path>`, e.g. the project
+/// directory from `--workspace`.
+fn path_arg(flag: &str) -> Option<PathBuf> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg
+            .strip_prefix(flag)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            return Some(PathBuf::from(path));
+        }
+    }
+    None
+}
@@ -0,0 +1,964 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+/// Crates whose `use` statements and path prefixes the rewriter strips.
+const ETHERS_CRATES: [&str; 6] = [
+    "ethers",
+    "ethers_core",
+    "ethers_providers",
+    "ethers_signers",
+    "ethers_contract",
+    "ethers_middleware",
+];
+
+/// Section of the migration guide a mapping is listed under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Group {
+    Types,
+    Providers,
+    Signers,
+    Transactions,
+    Contracts,
+    Utilities,
+}
+
+impl Group {
+    const ALL: [Group; 6] = [
+        Group::Types,
+        Group::Providers,
+        Group::Signers,
+        Group::Transactions,
+        Group::Contracts,
+        Group::Utilities,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            Group::Types => "Types",
+            Group::Providers => "Providers",
+            Group::Signers => "Signers",
+            Group::Transactions => "Transactions",
+            Group::Contracts => "Contracts",
+            Group::Utilities => "Utilities",
+        }
+    }
+}
+
+/// How the rewriter applies a mapping.
+#[derive(Clone, Copy)]
+pub enum Rewrite {
+    /// Replace every occurrence of the pattern with this text.
+    Replace(&'static str),
+    /// Rewrite a whole call. The pattern ends at the opening parenthesis; the
+    /// function gets the pattern and the argument text and returns the
+    /// replacement for the call, or `None` when it needs a human.
+    Call(fn(&str, &str) -> Option<String>),
+    /// No automatic rewrite; occurrences get an inline TODO.
+    Manual,
+}
+
+/// One ethers-rs construct and its alloy equivalent. This table drives both
+/// the `migrate_from_ethers` rewriter and the `alloy://migration/ethers` guide.
+pub struct Mapping {
+    pub group: Group,
+    /// The ethers-rs construct as shown in the guide.
+    pub ethers: &'static str,
+    /// The alloy equivalent as shown in the guide.
+    pub alloy: &'static str,
+    /// Text the rewriter looks for. Identifier edges match whole words only.
+    pattern: &'static str,
+    rewrite: Rewrite,
+    /// Full paths of the items the rewritten code needs.
+    pub imports: &'static [&'static str],
+    pub note: Option<&'static str>,
+    /// Whether the note describes follow-up work, left as an inline TODO.
+    pub manual: bool,
+    /// Only rewrite when one of these identifiers also appears in the snippet.
+    only_with: &'static [&'static str],
+}
+
+impl Mapping {
+    const fn new(
+        group: Group,
+        ethers: &'static str,
+        alloy: &'static str,
+        pattern: &'static str,
+        rewrite: Rewrite,
+    ) -> Self {
+        Self {
+            group,
+            ethers,
+            alloy,
+            pattern,
+            rewrite,
+            imports: &[],
+            note: None,
+            manual: false,
+            only_with: &[],
+        }
+    }
+
+    /// An identifier rename; `ethers` is also the pattern.
+    const fn rename(group: Group, ethers: &'static str, alloy: &'static str) -> Self {
+        Self::new(group, ethers, alloy, ethers, Rewrite::Replace(alloy))
+    }
+
+    const fn imports(mut self, imports: &'static [&'static str]) -> Self {
+        self.imports = imports;
+        self
+    }
+
+    const fn note(mut self, note: &'static str) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    const fn todo(mut self, note: &'static str) -> Self {
+        self.note = Some(note);
+        self.manual = true;
+        self
+    }
+
+    const fn only_with(mut self, idents: &'static [&'static str]) -> Self {
+        self.only_with = idents;
+        self
+    }
+}
+
+/// Identifiers that mark a snippet as building transaction requests, which
+/// is when ethers' bare builder methods (`.to(..)`, `.value(..)`) are safe
+/// to rename.
+const REQUEST_TYPES: &[&str] = &[
+    "TransactionRequest",
+    "Eip1559TransactionRequest",
+    "TypedTransaction",
+];
+
+const TX_BUILDER: &[&str] = &["alloy::network::TransactionBuilder"];
+
+/// Top-level arguments of a call, split on commas outside brackets.
+fn split_args(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(args[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = args[start..].trim();
+    if !last.is_empty() {
+        parts.push(last);
+    }
+    parts
+}
+
+/// `expr.into()`, parenthesizing anything that is not a plain path.
+/// Expressions that already end in `.into()` are kept.
+fn into_expr(expr: &str) -> String {
+    if expr.ends_with(".into()") {
+        expr.to_string()
+    } else if expr
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == ':')
+    {
+        format!("{expr}.into()")
+    } else {
+        format!("({expr}).into()")
+    }
+}
+
+fn connect_http(_: &str, args: &str) -> Option<String> {
+    Some(format!("ProviderBuilder::new().connect({args}).await"))
+}
+
+fn connect_ws(_: &str, args: &str) -> Option<String> {
+    Some(format!(
+        "ProviderBuilder::new().connect_ws(WsConnect::new({args}))"
+    ))
+}
+
+fn connect_ipc(_: &str, args: &str) -> Option<String> {
+    Some(format!(
+        "ProviderBuilder::new().connect_ipc(IpcConnect::new({args}.to_string()))"
+    ))
+}
+
+fn signer_middleware(_: &str, args: &str) -> Option<String> {
+    match split_args(args).as_slice() {
+        [provider, signer] => Some(format!(
+            "ProviderBuilder::new().wallet(EthereumWallet::from({signer})).connect_provider({provider})"
+        )),
+        _ => None,
+    }
+}
+
+fn random_signer(_: &str, _: &str) -> Option<String> {
+    Some("PrivateKeySigner::random()".into())
+}
+
+fn with_chain_id(pattern: &str, args: &str) -> Option<String> {
+    Some(format!("{pattern}Some({args}))"))
+}
+
+/// `abigen!(Name, "abi.json")` becomes `sol!(#[sol(rpc)] Name, "abi.json")`;
+/// inline human-readable ABIs need rewriting as Solidity by hand.
+fn abigen(_: &str, args: &str) -> Option<String> {
+    match split_args(args).as_slice() {
+        [name, path] if path.starts_with('"') && path.ends_with(".json\"") => {
+            Some(format!("sol!(#[sol(rpc)] {name}, {path})"))
+        }
+        _ => None,
+    }
+}
+
+fn from_dec_str(_: &str, args: &str) -> Option<String> {
+    Some(format!("::from_str_radix({args}, 10)"))
+}
+
+fn to_checksum(_: &str, args: &str) -> Option<String> {
+    match split_args(args).as_slice() {
+        [address, chain_id] => Some(format!(
+            "{}.to_checksum({chain_id})",
+            address.trim_start_matches('&')
+        )),
+        _ => None,
+    }
+}
+
+/// ethers' RPC methods take a trailing `Option<BlockId>`; alloy's return a
+/// builder with `.block_id(..)` instead.
+fn block_arg(pattern: &str, args: &str) -> Option<String> {
+    match split_args(args).as_slice() {
+        [rest @ .., "None"] => Some(format!("{pattern}{})", rest.join(", "))),
+        [rest @ .., block] => {
+            let block = block.strip_prefix("Some(")?.strip_suffix(')')?;
+            Some(format!(
+                "{pattern}{}).block_id({})",
+                rest.join(", "),
+                into_expr(block)
+            ))
+        }
+        [] => None,
+    }
+}
+
+fn code_at(_: &str, args: &str) -> Option<String> {
+    block_arg(".get_code_at(", args)
+}
+
+fn send_transaction(pattern: &str, args: &str) -> Option<String> {
+    match split_args(args).as_slice() {
+        [tx, "None"] => Some(format!("{pattern}{tx})")),
+        _ => None,
+    }
+}
+
+fn block_with_txs(_: &str, args: &str) -> Option<String> {
+    Some(format!(".get_block({}).full()", into_expr(args.trim())))
+}
+
+/// Pattern of the one-line pending-transaction await. [`await_pending`]
+/// handles the form that binds the pending transaction first.
+const AWAIT_PENDING: &str = ".await?.await?";
+
+use Group::*;
+
+/// The ethers-rs → alloy mapping table.
+pub static MAPPINGS: &[Mapping] = &[
+    // Types
+    Mapping::rename(Types, "H160", "Address").imports(&["alloy::primitives::Address"]),
+    Mapping::rename(Types, "Address", "Address").imports(&["alloy::primitives::Address"]),
+    Mapping::rename(Types, "H256", "B256").imports(&["alloy::primitives::B256"]),
+    Mapping::rename(Types, "TxHash", "TxHash").imports(&["alloy::primitives::TxHash"]),
+    Mapping::rename(Types, "H64", "B64").imports(&["alloy::primitives::B64"]),
+    Mapping::rename(Types, "H512", "B512").imports(&["alloy::primitives::B512"]),
+    Mapping::rename(Types, "U256", "U256").imports(&["alloy::primitives::U256"]).note(
+        "alloy's `U256` is `ruint::Uint<256, 4>`: `U256::from(..)` works for every unsigned integer, and there are no `as_u64`-style methods.",
+    ),
+    Mapping::rename(Types, "U64", "U64").imports(&["alloy::primitives::U64"]).note(
+        "alloy returns block numbers, nonces and chain ids as plain `u64`, so most `U64` values disappear.",
+    ),
+    Mapping::rename(Types, "I256", "I256").imports(&["alloy::primitives::I256"]),
+    Mapping::rename(Types, "Bytes", "Bytes").imports(&["alloy::primitives::Bytes"]),
+    Mapping::rename(Types, "Signature", "Signature")
+        .imports(&["alloy::primitives::Signature"])
+        .note("`v` is a y-parity `bool` (`sig.v()`); `sig.recover(msg)` becomes `sig.recover_address_from_msg(msg)`."),
+    Mapping::rename(Types, "BlockNumber", "BlockNumberOrTag")
+        .imports(&["alloy::eips::BlockNumberOrTag"]),
+    Mapping::rename(Types, "BlockId", "BlockId").imports(&["alloy::eips::BlockId"]),
+    Mapping::new(Types, "NameOrAddress", "Address", "NameOrAddress", Rewrite::Manual).todo(
+        "alloy does not resolve ENS names implicitly; resolve them first (e.g. with the `alloy-ens` crate) and pass an `Address`.",
+    ),
+    Mapping::new(Types, "X::zero()", "X::ZERO", "::zero()", Rewrite::Replace("::ZERO")),
+    Mapping::new(Types, "U256::one()", "U256::from(1)", "U256::one()", Rewrite::Replace("U256::from(1)")),
+    Mapping::new(
+        Types,
+        "U256::from_dec_str(s)",
+        "U256::from_str_radix(s, 10)",
+        "::from_dec_str(",
+        Rewrite::Call(from_dec_str),
+    )
+    .note("`s.parse::<U256>()` also works and accepts `0x` hex."),
+    Mapping::new(Types, "x.as_u64()", "x.to::<u64>()", ".as_u64()", Rewrite::Replace(".to::<u64>()"))
+        .note("`to` panics when the value does not fit; `saturating_to`/`wrapping_to` are the non-panicking variants."),
+    Mapping::new(Types, "x.as_u128()", "x.to::<u128>()", ".as_u128()", Rewrite::Replace(".to::<u128>()")),
+    Mapping::new(Types, "x.as_usize()", "x.to::<usize>()", ".as_usize()", Rewrite::Replace(".to::<usize>()")),
+    Mapping::new(Types, "x.low_u64()", "x.wrapping_to::<u64>()", ".low_u64()", Rewrite::Replace(".wrapping_to::<u64>()")),
+    // Providers
+    Mapping::new(
+        Providers,
+        "Provider::<Http>::try_from(url)?",
+        "ProviderBuilder::new().connect(url).await?",
+        "Provider::<Http>::try_from(",
+        Rewrite::Call(connect_http),
+    )
+    .imports(&["alloy::providers::Provider", "alloy::providers::ProviderBuilder"])
+    .note("`connect` is async, takes a `&str` and installs the recommended fillers (gas, nonce, chain id); `connect_http(url)` takes a parsed `Url` and is synchronous."),
+    Mapping::new(
+        Providers,
+        "Provider::try_from(url)?",
+        "ProviderBuilder::new().connect(url).await?",
+        "Provider::try_from(",
+        Rewrite::Call(connect_http),
+    )
+    .imports(&["alloy::providers::Provider", "alloy::providers::ProviderBuilder"]),
+    Mapping::new(
+        Providers,
+        "Provider::<Ws>::connect(url).await?",
+        "ProviderBuilder::new().connect_ws(WsConnect::new(url)).await?",
+        "Provider::<Ws>::connect(",
+        Rewrite::Call(connect_ws),
+    )
+    .imports(&[
+        "alloy::providers::Provider",
+        "alloy::providers::ProviderBuilder",
+        "alloy::providers::WsConnect",
+    ])
+    .note("Needs the `provider-ws` feature."),
+    Mapping::new(
+        Providers,
+        "Provider::connect_ipc(path).await?",
+        "ProviderBuilder::new().connect_ipc(IpcConnect::new(path.to_string())).await?",
+        "Provider::connect_ipc(",
+        Rewrite::Call(connect_ipc),
+    )
+    .imports(&[
+        "alloy::providers::IpcConnect",
+        "alloy::providers::Provider",
+        "alloy::providers::ProviderBuilder",
+    ])
+    .note("Needs the `provider-ipc` feature."),
+    Mapping::new(Providers, "Provider<Http>", "DynProvider", "Provider<Http>", Rewrite::Replace("DynProvider"))
+        .imports(&["alloy::providers::DynProvider", "alloy::providers::Provider"])
+        .todo("`ProviderBuilder` returns a concrete filler stack: store `provider.erased()` as `DynProvider`, or take `impl Provider`."),
+    Mapping::new(Providers, "Provider<Ws>", "DynProvider", "Provider<Ws>", Rewrite::Replace("DynProvider"))
+        .imports(&["alloy::providers::DynProvider", "alloy::providers::Provider"])
+        .todo("`ProviderBuilder` returns a concrete filler stack: store `provider.erased()` as `DynProvider`, or take `impl Provider`."),
+    Mapping::rename(Providers, "Middleware", "Provider")
+        .imports(&["alloy::providers::Provider"])
+        .note("Bounds such as `M: Middleware` become `P: Provider`. There is no middleware stack: fillers and layers on `ProviderBuilder` replace it."),
+    Mapping::new(
+        Providers,
+        "SignerMiddleware::new(provider, wallet)",
+        "ProviderBuilder::new().wallet(EthereumWallet::from(wallet)).connect_provider(provider)",
+        "SignerMiddleware::new(",
+        Rewrite::Call(signer_middleware),
+    )
+    .imports(&[
+        "alloy::network::EthereumWallet",
+        "alloy::providers::Provider",
+        "alloy::providers::ProviderBuilder",
+    ])
+    .note("Usually simpler to call `.wallet(..)` on the builder that creates the provider in the first place."),
+    Mapping::new(Providers, "SignerMiddleware<M, S>", "DynProvider", "SignerMiddleware", Rewrite::Manual).todo(
+        "Signing is part of the provider: store the provider returned by `ProviderBuilder::new().wallet(..)` (e.g. as `DynProvider`).",
+    ),
+    Mapping::new(Providers, "NonceManagerMiddleware", "NonceFiller (default)", "NonceManagerMiddleware", Rewrite::Manual)
+        .todo("Nonces are filled by `NonceFiller`, which `ProviderBuilder::new()` installs; use `.with_cached_nonce_management()` for local tracking."),
+    Mapping::new(Providers, "GasOracleMiddleware", "GasFiller (default)", "GasOracleMiddleware", Rewrite::Manual)
+        .todo("Gas limits and fees are filled by `GasFiller`, which `ProviderBuilder::new()` installs."),
+    Mapping::new(Providers, "provider.get_chainid()", "provider.get_chain_id()", ".get_chainid()", Rewrite::Replace(".get_chain_id()"))
+        .note("Returns `u64` rather than `U256`."),
+    Mapping::new(
+        Providers,
+        "provider.get_balance(addr, None)",
+        "provider.get_balance(addr)",
+        ".get_balance(",
+        Rewrite::Call(block_arg),
+    )
+    .note("RPC calls drop ethers' trailing block argument; pick a block with `.block_id(..)` on the returned builder."),
+    Mapping::new(
+        Providers,
+        "provider.get_transaction_count(addr, None)",
+        "provider.get_transaction_count(addr)",
+        ".get_transaction_count(",
+        Rewrite::Call(block_arg),
+    ),
+    Mapping::new(
+        Providers,
+        "provider.get_code(addr, None)",
+        "provider.get_code_at(addr)",
+        ".get_code(",
+        Rewrite::Call(code_at),
+    ),
+    Mapping::new(
+        Providers,
+        "provider.estimate_gas(&tx, None)",
+        "provider.estimate_gas(tx)",
+        ".estimate_gas(",
+        Rewrite::Call(block_arg),
+    )
+    .note("`estimate_gas` and `call` take the request by value and return `u64` gas."),
+    Mapping::new(
+        Providers,
+        "provider.get_block_with_txs(n)",
+        "provider.get_block(n.into()).full()",
+        ".get_block_with_txs(",
+        Rewrite::Call(block_with_txs),
+    )
+    .note("`get_block` returns transaction hashes only unless `.full()` is called."),
+    // Signers
+    Mapping::rename(Signers, "LocalWallet", "PrivateKeySigner")
+        .imports(&["alloy::signers::local::PrivateKeySigner"]),
+    Mapping::new(Signers, "Wallet<SigningKey>", "PrivateKeySigner", "Wallet<SigningKey>", Rewrite::Replace("PrivateKeySigner"))
+        .imports(&["alloy::signers::local::PrivateKeySigner"]),
+    Mapping::new(
+        Signers,
+        "LocalWallet::new(&mut rng)",
+        "PrivateKeySigner::random()",
+        "LocalWallet::new(",
+        Rewrite::Call(random_signer),
+    )
+    .imports(&["alloy::signers::local::PrivateKeySigner"]),
+    Mapping::rename(Signers, "MnemonicBuilder", "MnemonicBuilder")
+        .imports(&["alloy::signers::local::MnemonicBuilder"])
+        .note("Needs the `signer-mnemonic` feature; `.phrase(..)`, `.index(..)?` and `.build()?` carry over."),
+    Mapping::rename(Signers, "English", "English").imports(&["alloy::signers::local::coins_bip39::English"]),
+    Mapping::rename(Signers, "Signer", "Signer").imports(&["alloy::signers::Signer"]),
+    Mapping::new(
+        Signers,
+        "wallet.with_chain_id(1u64)",
+        "signer.with_chain_id(Some(1))",
+        ".with_chain_id(",
+        Rewrite::Call(with_chain_id),
+    )
+    .imports(&["alloy::signers::Signer"])
+    .note("Rarely needed: the provider's `ChainIdFiller` sets the chain id on every transaction."),
+    Mapping::new(Signers, "wallet.sign_typed_data(&data)", "signer.sign_typed_data(&value, &domain)", ".sign_typed_data(", Rewrite::Manual)
+        .todo("alloy takes a `sol!` struct and an `Eip712Domain` separately (`eip712` feature); for JSON payloads use `sign_dynamic_typed_data`."),
+    // Transactions
+    Mapping::rename(Transactions, "TypedTransaction", "TransactionRequest")
+        .imports(&["alloy::rpc::types::TransactionRequest"])
+        .note("One `TransactionRequest` covers every transaction type; the type is picked from the fields that are set. `alloy::consensus::TypedTransaction` is the unsigned consensus type, not a request."),
+    Mapping::rename(Transactions, "Eip1559TransactionRequest", "TransactionRequest")
+        .imports(&["alloy::rpc::types::TransactionRequest"]),
+    Mapping::rename(Transactions, "TransactionRequest", "TransactionRequest")
+        .imports(&["alloy::rpc::types::TransactionRequest"])
+        .note("ethers' `TransactionRequest` was legacy-only; alloy's builds an EIP-1559 transaction unless `with_gas_price(..)` is set."),
+    Mapping::new(Transactions, "TransactionRequest::new()", "TransactionRequest::default()", "TransactionRequest::new()", Rewrite::Replace("TransactionRequest::default()")),
+    Mapping::new(Transactions, ".to(addr)", ".with_to(addr)", ".to(", Rewrite::Replace(".with_to("))
+        .imports(TX_BUILDER)
+        .only_with(REQUEST_TYPES)
+        .note("Request setters are the `with_*` methods of the `TransactionBuilder` trait."),
+    Mapping::new(Transactions, ".from(addr)", ".with_from(addr)", ".from(", Rewrite::Replace(".with_from("))
+        .imports(TX_BUILDER)
+        .only_with(REQUEST_TYPES),
+    Mapping::new(Transactions, ".value(v)", ".with_value(v)", ".value(", Rewrite::Replace(".with_value("))
+        .imports(TX_BUILDER)
+        .only_with(REQUEST_TYPES),
+    Mapping::new(Transactions, ".data(d)", ".with_input(d)", ".data(", Rewrite::Replace(".with_input("))
+        .imports(TX_BUILDER)
+        .only_with(REQUEST_TYPES),
+    Mapping::new(Transactions, ".gas(g)", ".with_gas_limit(g)", ".gas(", Rewrite::Replace(".with_gas_limit("))
+        .imports(TX_BUILDER)
+        .only_with(REQUEST_TYPES)
+        .note("Gas limits are `u64` and fees `u128` in alloy, not `U256`."),
+    Mapping::new(Transactions, ".gas_price(p)", ".with_gas_price(p)", ".gas_price(", Rewrite::Replace(".with_gas_price("))
+        .imports(TX_BUILDER)
+        .only_with(REQUEST_TYPES),
+    Mapping::new(Transactions, ".max_fee_per_gas(f)", ".with_max_fee_per_gas(f)", ".max_fee_per_gas(", Rewrite::Replace(".with_max_fee_per_gas("))
+        .imports(TX_BUILDER)
+        .only_with(REQUEST_TYPES),
+    Mapping::new(
+        Transactions,
+        ".max_priority_fee_per_gas(f)",
+        ".with_max_priority_fee_per_gas(f)",
+        ".max_priority_fee_per_gas(",
+        Rewrite::Replace(".with_max_priority_fee_per_gas("),
+    )
+    .imports(TX_BUILDER)
+    .only_with(REQUEST_TYPES),
+    Mapping::new(Transactions, ".nonce(n)", ".with_nonce(n)", ".nonce(", Rewrite::Replace(".with_nonce("))
+        .imports(TX_BUILDER)
+        .only_with(REQUEST_TYPES),
+    Mapping::new(Transactions, ".chain_id(id)", ".with_chain_id(id)", ".chain_id(", Rewrite::Replace(".with_chain_id("))
+        .imports(TX_BUILDER)
+        .only_with(REQUEST_TYPES),
+    Mapping::new(
+        Transactions,
+        "provider.send_transaction(tx, None)",
+        "provider.send_transaction(tx)",
+        ".send_transaction(",
+        Rewrite::Call(send_transaction),
+    )
+    .note("alloy's `send_transaction` has no block argument."),
+    Mapping::new(
+        Transactions,
+        "pending.await?",
+        "pending.get_receipt().await?",
+        AWAIT_PENDING,
+        Rewrite::Replace(".await?.get_receipt().await?"),
+    )
+    .note("ethers' `PendingTransaction` resolved to `Option<TransactionReceipt>`; `get_receipt()` returns the receipt or an error, and `watch()` returns just the hash."),
+    Mapping::new(
+        Transactions,
+        "pending.confirmations(n)",
+        "pending.with_required_confirmations(n)",
+        ".confirmations(",
+        Rewrite::Replace(".with_required_confirmations("),
+    ),
+    Mapping::rename(Transactions, "TransactionReceipt", "TransactionReceipt")
+        .imports(&["alloy::rpc::types::TransactionReceipt"])
+        .note("`receipt.status()` is a `bool`, and logs are at `receipt.inner.logs()`."),
+    // Contracts
+    Mapping::new(
+        Contracts,
+        "abigen!(Name, \"abi.json\")",
+        "sol!(#[sol(rpc)] Name, \"abi.json\")",
+        "abigen!(",
+        Rewrite::Call(abigen),
+    )
+    .imports(&["alloy::sol"])
+    .todo("Contract methods keep their Solidity names: rename abigen's `balance_of(..)` calls to `balanceOf(..)`; overloads get `_0`, `_1` suffixes. `sol!` reads JSON ABIs (`json` feature) or Solidity declarations; inline human-readable ABIs become a `sol! { #[sol(rpc)] contract Name { .. } }` block. Generated types live under the contract: `Name::transferCall`, `Name::Transfer`."),
+    Mapping::new(Contracts, "contract.event::<TransferFilter>()", "contract.Transfer_filter()", ".event::<", Rewrite::Manual)
+        .todo("`sol!` generates a `<Event>_filter()` method per event on the contract instance; abigen's `<Event>Filter` structs are `Name::<Event>`."),
+    Mapping::new(Contracts, "#[derive(EthEvent)]", "sol! { event .. }", "EthEvent", Rewrite::Manual)
+        .todo("Declare events in `sol!`; the generated structs implement `SolEvent`."),
+    Mapping::new(Contracts, "#[derive(EthAbiType)]", "sol! { struct .. }", "EthAbiType", Rewrite::Manual)
+        .todo("Declare the struct in `sol!`; it implements `SolStruct`/`SolValue` for ABI encoding."),
+    Mapping::new(Contracts, "#[derive(EthCall)]", "sol! { function .. }", "EthCall", Rewrite::Manual)
+        .todo("Declare the function in `sol!`; the generated `<name>Call` struct implements `SolCall`."),
+    // Utilities
+    Mapping::rename(Utilities, "keccak256", "keccak256").imports(&["alloy::primitives::keccak256"]),
+    Mapping::rename(Utilities, "parse_ether", "parse_ether").imports(&["alloy::primitives::utils::parse_ether"]),
+    Mapping::rename(Utilities, "parse_units", "parse_units").imports(&["alloy::primitives::utils::parse_units"]),
+    Mapping::rename(Utilities, "format_ether", "format_ether").imports(&["alloy::primitives::utils::format_ether"]),
+    Mapping::rename(Utilities, "format_units", "format_units").imports(&["alloy::primitives::utils::format_units"]),
+    Mapping::new(
+        Utilities,
+        "to_checksum(&addr, None)",
+        "addr.to_checksum(None)",
+        "to_checksum(",
+        Rewrite::Call(to_checksum),
+    ),
+    Mapping::rename(Utilities, "hex", "hex").imports(&["alloy::primitives::hex"]),
+    Mapping::rename(Utilities, "Anvil", "Anvil")
+        .imports(&["alloy::node_bindings::Anvil"])
+        .note("Needs the `node-bindings` feature; `ProviderBuilder::new().connect_anvil_with_wallet()` spawns Anvil and wires up its first key in one call."),
+];
+
+/// Result of rewriting an ethers-rs snippet.
+pub struct Migration {
+    pub code: String,
+    /// Full paths of the alloy items the rewritten code uses.
+    pub imports: Vec<&'static str>,
+    /// Mappings that were applied, with how often.
+    pub applied: Vec<(&'static Mapping, usize)>,
+    /// Mappings that need manual follow-up, left as inline TODOs.
+    pub todos: Vec<&'static Mapping>,
+    /// Number of `use ethers...` statements removed.
+    pub removed_uses: usize,
+    /// Number of `ethers::...::` path prefixes stripped.
+    pub stripped_paths: usize,
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Byte offsets where `pattern` occurs in `text`. Patterns that start or
+/// end with an identifier character only match at word boundaries.
+fn find_all(text: &str, pattern: &str) -> Vec<usize> {
+    let starts_ident = pattern.starts_with(is_ident_char);
+    let ends_ident = pattern.ends_with(is_ident_char);
+    text.match_indices(pattern)
+        .map(|(pos, _)| pos)
+        .filter(|&pos| {
+            let before = text[..pos].chars().next_back();
+            let after = text[pos + pattern.len()..].chars().next();
+            let joined_before =
+                starts_ident && before.is_some_and(|c| is_ident_char(c) || c == '.');
+            let joined_after = ends_ident && after.is_some_and(is_ident_char);
+            !(joined_before || joined_after)
+        })
+        .collect()
+}
+
+/// Offset of the `)` closing the call whose arguments start at `open`.
+fn closing_paren(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in text[open..].char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth > 0 => depth -= 1,
+            ')' => return Some(open + i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Remove `use ethers...;` statements, which may span several lines.
+fn remove_uses(source: &str) -> (String, usize) {
+    let mut out = String::new();
+    let mut removed = 0;
+    let mut in_use = false;
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        let starts = ETHERS_CRATES.iter().any(|krate| {
+            trimmed
+                .strip_prefix("use ")
+                .or_else(|| trimmed.strip_prefix("pub use "))
+                .and_then(|rest| rest.strip_prefix(krate))
+                .is_some_and(|rest| rest.starts_with("::") || rest.starts_with(';'))
+        });
+        if starts {
+            removed += 1;
+            in_use = true;
+        }
+        if in_use {
+            in_use = !line.contains(';');
+            continue;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    (out, removed)
+}
+
+/// Strip `ethers::types::`-style prefixes so the renames below see bare
+/// identifiers.
+fn strip_paths(text: &str) -> (String, usize) {
+    let mut text = text.to_string();
+    let mut stripped = 0;
+    for krate in ETHERS_CRATES {
+        let pattern = format!("{krate}::");
+        while let Some(&pos) = find_all(&text, &pattern).first() {
+            let mut end = pos + pattern.len();
+            // Module segments are lowercase; stop at the item name.
+            while let Some(segment) = text[end..].split("::").next()
+                && text[end + segment.len()..].starts_with("::")
+                && segment.starts_with(|c: char| c.is_ascii_lowercase())
+                && segment.chars().all(is_ident_char)
+            {
+                end += segment.len() + 2;
+            }
+            text.replace_range(pos..end, "");
+            stripped += 1;
+        }
+    }
+    (text, stripped)
+}
+
+/// Apply one mapping. Returns the new text, the number of rewrites, and the
+/// lines of occurrences that need a TODO.
+fn apply(text: &str, mapping: &Mapping) -> (String, usize, Vec<usize>) {
+    let mut out = String::with_capacity(text.len());
+    let mut count = 0;
+    let mut todo_lines = Vec::new();
+    let mut rest = 0;
+    for pos in find_all(text, mapping.pattern) {
+        if pos < rest {
+            continue;
+        }
+        let after = pos + mapping.pattern.len();
+        let (end, replacement) = match mapping.rewrite {
+            Rewrite::Replace(to) => (after, Some(to.to_string())),
+            Rewrite::Call(rewrite) => match closing_paren(text, after) {
+                Some(close) => (close + 1, rewrite(mapping.pattern, &text[after..close])),
+                None => (after, None),
+            },
+            Rewrite::Manual => (after, None),
+        };
+        out.push_str(&text[rest..pos]);
+        let line = out.matches('\n').count();
+        match replacement {
+            Some(replacement) => {
+                out.push_str(&replacement);
+                count += 1;
+                if mapping.manual {
+                    todo_lines.push(line);
+                }
+            }
+            None => {
+                out.push_str(&text[pos..end]);
+                todo_lines.push(line);
+            }
+        }
+        rest = end;
+    }
+    out.push_str(&text[rest..]);
+    (out, count, todo_lines)
+}
+
+/// Rewrite `pending.await?` for every `let pending = ...send_transaction(..).await?;`
+/// binding: alloy's `PendingTransactionBuilder` is not a future.
+fn await_pending(text: &str) -> (String, usize) {
+    let pattern = ".send_transaction(";
+    let mut names = Vec::new();
+    for pos in find_all(text, pattern) {
+        let start = text[..pos].rfind([';', '{', '}']).map_or(0, |i| i + 1);
+        let Some(binding) = text[start..pos].trim_start().strip_prefix("let ") else {
+            continue;
+        };
+        let binding = binding.trim_start();
+        let binding = binding.strip_prefix("mut ").unwrap_or(binding).trim_start();
+        let name: String = binding.chars().take_while(|&c| is_ident_char(c)).collect();
+        let typed = binding[name.len()..].trim_start().starts_with([':', '=']);
+        let awaited = closing_paren(text, pos + pattern.len()).is_some_and(|close| {
+            text[close + 1..]
+                .trim_start()
+                .strip_prefix(".await?")
+                .is_some_and(|rest| rest.trim_start().starts_with(';'))
+        });
+        if !name.is_empty() && typed && awaited && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut text = text.to_string();
+    let mut count = 0;
+    for name in names {
+        let pattern = format!("{name}.await?");
+        for pos in find_all(&text, &pattern).into_iter().rev() {
+            text.replace_range(
+                pos..pos + pattern.len(),
+                &format!("{name}.get_receipt().await?"),
+            );
+            count += 1;
+        }
+    }
+    (text, count)
+}
+
+/// Rewrite an ethers-rs snippet to alloy using [`MAPPINGS`].
+pub fn migrate(source: &str) -> Migration {
+    let (text, removed_uses) = remove_uses(source);
+    let (mut text, stripped_paths) = strip_paths(&text);
+
+    // Calls first so their arguments are still in ethers form, then method
+    // patterns, then plain renames, then patterns that start with a type
+    // name (so `Eip1559TransactionRequest::new()` is caught after renaming),
+    // then flags for manual work.
+    let order = |m: &Mapping| match m.rewrite {
+        Rewrite::Call(_) => 0,
+        Rewrite::Replace(_) if !m.pattern.starts_with(is_ident_char) => 1,
+        Rewrite::Replace(_) if m.pattern.chars().all(is_ident_char) => 2,
+        Rewrite::Replace(_) => 3,
+        Rewrite::Manual => 4,
+    };
+    let mut mappings: Vec<&'static Mapping> = MAPPINGS.iter().collect();
+    mappings.sort_by_key(|m| order(m));
+
+    let present = |ident: &str, text: &str| !find_all(text, ident).is_empty();
+    let original = text.clone();
+    let mut applied = Vec::new();
+    let mut todos: Vec<&'static Mapping> = Vec::new();
+    let mut line_todos: BTreeMap<usize, Vec<&'static str>> = BTreeMap::new();
+    let mut imports: Vec<&'static str> = Vec::new();
+    for mapping in mappings {
+        if !mapping.only_with.is_empty() && !mapping.only_with.iter().any(|i| present(i, &original))
+        {
+            continue;
+        }
+        let (rewritten, count, todo_lines) = apply(&text, mapping);
+        text = rewritten;
+        if count > 0 {
+            applied.push((mapping, count));
+            for import in mapping.imports {
+                if !imports.contains(import) {
+                    imports.push(import);
+                }
+            }
+        }
+        if !todo_lines.is_empty() {
+            todos.push(mapping);
+            let note = mapping
+                .note
+                .unwrap_or("no automatic rewrite; see the migration guide");
+            for line in todo_lines {
+                let notes = line_todos.entry(line).or_default();
+                if !notes.contains(&note) {
+                    notes.push(note);
+                }
+            }
+        }
+    }
+
+    let (rewritten, count) = await_pending(&text);
+    text = rewritten;
+    if count > 0 {
+        let mapping = MAPPINGS.iter().find(|m| m.pattern == AWAIT_PENDING);
+        match applied.iter_mut().find(|(m, _)| m.pattern == AWAIT_PENDING) {
+            Some((_, applied)) => *applied += count,
+            None => applied.extend(mapping.map(|m| (m, count))),
+        }
+    }
+
+    let mut code = String::new();
+    for (i, line) in text.lines().enumerate() {
+        code.push_str(line);
+        if let Some(notes) = line_todos.get(&i) {
+            for note in notes {
+                let _ = write!(code, " // TODO(alloy): {note}");
+            }
+        }
+        code.push('\n');
+    }
+    imports.sort_unstable();
+
+    Migration {
+        code: code.trim_matches('\n').to_string() + "\n",
+        imports,
+        applied,
+        todos,
+        removed_uses,
+        stripped_paths,
+    }
+}
+
+/// Markdown table cell text: inline code, with pipes escaped.
+fn code_cell(text: &str) -> String {
+    format!("`{}`", text.replace('|', "\\|"))
+}
+
+/// The `alloy://migration/ethers` guide, generated from [`MAPPINGS`].
+pub fn ethers_guide() -> String {
+    let mut out = String::from(
+        "# Migrating from ethers-rs to alloy\n\n\
+        ethers-rs is deprecated in favour of alloy. This guide maps ethers constructs to their \
+        alloy equivalents; the `migrate_from_ethers` tool applies the same table to code.\n\n\
+        ## Cargo.toml\n\n\
+        ```toml\n\
+        # before\n\
+        ethers = { version = \"2\", features = [\"ws\"] }\n\n\
+        # after\n\
+        alloy = { version = \"1\", features = [\"full\"] }\n\
+        ```\n\n\
+        `full` enables providers, signers, contracts and the RPC types. Add `provider-ws`, \
+        `provider-ipc`, `signer-mnemonic` or `node-bindings` for those features.\n",
+    );
+    for group in Group::ALL {
+        let _ = write!(
+            out,
+            "\n## {}\n\n| ethers-rs | alloy | Import | Notes |\n|-----------|-------|--------|-------|\n",
+            group.title()
+        );
+        for mapping in MAPPINGS.iter().filter(|m| m.group == group) {
+            let imports: Vec<String> = mapping.imports.iter().map(|i| code_cell(i)).collect();
+            let note = match (mapping.note, mapping.manual) {
+                (Some(note), true) => format!("**Manual:** {note}"),
+                (Some(note), false) => note.to_string(),
+                (None, _) => String::new(),
+            };
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} |",
+                code_cell(mapping.ethers),
+                code_cell(mapping.alloy),
+                imports.join(", "),
+                note
+            );
+        }
+    }
+    out.push_str(
+        "\n## Common Mistakes\n\n\
+        1. **Mixing ethers and alloy types** — `H160` and `Address` are different types even though both wrap 20 bytes; convert at the boundary with `Address::from(h160.0)` while both crates are in the tree\n\
+        2. **Keeping `U256` for gas** — alloy uses `u64` gas limits and `u128` fees; `U256` only for values\n\
+        3. **Wrapping providers in `Arc`** — alloy providers are cheap to clone and contract instances take any `P: Provider`; `Arc::new(client)` is unnecessary\n\
+        4. **Looking for middleware** — there is no middleware stack; signing, nonce and gas handling are fillers configured on `ProviderBuilder`\n\
+        5. **Awaiting a pending transaction directly** — ethers' `PendingTransaction` was a future; in alloy call `.get_receipt().await?` or `.watch().await?`\n",
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ETHERS: &str = r#"use ethers::prelude::*;
+
+abigen!(Erc20, "abi/erc20.json");
+
+async fn run(url: &str, wallet: LocalWallet, to: Address) -> eyre::Result<()> {
+    let provider = Provider::<Http>::try_from(url)?;
+    let block = provider.get_block_number().await?;
+    let client = SignerMiddleware::new(provider, wallet);
+    let amount = U256::from_dec_str("1000")?;
+    let tx = TransactionRequest::new().to(to).value(amount);
+    let pending = client.send_transaction(tx, None).await?;
+    let receipt = pending.await?;
+    Ok(())
+}
+"#;
+
+    #[test]
+    fn migrates_a_provider_signer_and_contract_snippet() {
+        let migration = migrate(ETHERS);
+        assert_eq!(migration.removed_uses, 1);
+        for import in [
+            "alloy::network::EthereumWallet",
+            "alloy::network::TransactionBuilder",
+            "alloy::primitives::U256",
+            "alloy::providers::Provider",
+            "alloy::providers::ProviderBuilder",
+            "alloy::signers::local::PrivateKeySigner",
+            "alloy::sol",
+        ] {
+            assert!(migration.imports.contains(&import), "missing {import}");
+        }
+
+        let code = &migration.code;
+        for line in [
+            "sol!(#[sol(rpc)] Erc20, \"abi/erc20.json\"); // TODO(alloy): Contract methods keep their Solidity names",
+            "let provider = ProviderBuilder::new().connect(url).await?;",
+            "let client = ProviderBuilder::new().wallet(EthereumWallet::from(wallet)).connect_provider(provider);",
+            "let amount = U256::from_str_radix(\"1000\", 10)?;",
+            "let tx = TransactionRequest::default().with_to(to).with_value(amount);",
+            "let pending = client.send_transaction(tx).await?;",
+            "let receipt = pending.get_receipt().await?;",
+        ] {
+            assert!(code.contains(line), "missing `{line}` in:\n{code}");
+        }
+        assert_eq!(code.matches("TODO(alloy)").count(), 1, "{code}");
+        assert_eq!(migration.todos.len(), 1);
+        assert_eq!(migration.todos[0].pattern, "abigen!(");
+    }
+
+    #[test]
+    fn awaits_pending_transactions_on_one_line() {
+        let migration = migrate("let receipt = client.send_transaction(tx, None).await?.await?;");
+        assert_eq!(
+            migration.code,
+            "let receipt = client.send_transaction(tx).await?.get_receipt().await?;\n"
+        );
+    }
+}
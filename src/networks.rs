@@ -0,0 +1,465 @@
+use std::fmt::Write;
+
+/// One associated type of alloy's `Network` trait as a network sets it.
+pub struct Assoc {
+    /// The associated type, as in `N::TxEnvelope`.
+    pub name: &'static str,
+    /// The concrete type, as written in the network's code.
+    pub ty: &'static str,
+    /// Path to import it by.
+    pub path: &'static str,
+    /// What the type holds and how it differs from its neighbours.
+    pub note: &'static str,
+}
+
+/// A network type: the container `Provider<N>`, `TransactionBuilder<N>` and
+/// `NetworkWallet<N>` read their types from. This table drives both the
+/// `network_types` tool and the `alloy://network/{network}` resources.
+pub struct Network {
+    /// The type implementing `Network`.
+    pub name: &'static str,
+    /// Last segment of the resource URI.
+    pub slug: &'static str,
+    /// Other names the network is looked up by, lowercase.
+    pub aliases: &'static [&'static str],
+    pub path: &'static str,
+    /// The dependency line that brings the network in.
+    pub dependency: &'static str,
+    pub summary: &'static str,
+    /// The associated types in the order `Network` declares them.
+    pub types: &'static [Assoc],
+    /// Code using the concrete types.
+    pub example: &'static str,
+    pub notes: &'static [&'static str],
+}
+
+/// The associated types `Network` declares, with what each is for.
+pub const ASSOCIATED: &[(&str, &str)] = &[
+    (
+        "TxType",
+        "Transaction type id enum (`Typed2718`, `TryFrom<u8>`)",
+    ),
+    (
+        "TxEnvelope",
+        "Signed transaction of any type, as broadcast and stored in blocks",
+    ),
+    (
+        "UnsignedTx",
+        "Unsigned transaction of any type; `From<TxEnvelope>` drops the signature",
+    ),
+    ("ReceiptEnvelope", "Consensus receipt, EIP-2718 encoded"),
+    ("Header", "Consensus block header"),
+    (
+        "TransactionRequest",
+        "JSON-RPC transaction request and `TransactionBuilder<N>`",
+    ),
+    (
+        "TransactionResponse",
+        "JSON-RPC transaction (`eth_getTransactionByHash`), `AsRef<TxEnvelope>`",
+    ),
+    (
+        "ReceiptResponse",
+        "JSON-RPC receipt (`eth_getTransactionReceipt`)",
+    ),
+    ("HeaderResponse", "JSON-RPC header, `AsRef<Header>`"),
+    (
+        "BlockResponse",
+        "JSON-RPC block of `HeaderResponse` and `TransactionResponse`s",
+    ),
+];
+
+pub const NETWORKS: &[Network] = &[
+    Network {
+        name: "Ethereum",
+        slug: "ethereum",
+        aliases: &["eth", "mainnet", "ethereum"],
+        path: "alloy::network::Ethereum",
+        dependency: "alloy = { version = \"1\", features = [\"full\"] }",
+        summary: "The default network of `ProviderBuilder::new()`: Ethereum L1 and any chain whose transactions, receipts and blocks match it exactly (most testnets, anvil).",
+        types: &[
+            Assoc {
+                name: "TxType",
+                ty: "TxType",
+                path: "alloy::consensus::TxType",
+                note: "`Legacy` (0), `Eip2930` (1), `Eip1559` (2), `Eip4844` (3), `Eip7702` (4).",
+            },
+            Assoc {
+                name: "TxEnvelope",
+                ty: "TxEnvelope",
+                path: "alloy::consensus::TxEnvelope",
+                note: "Alias of `EthereumTxEnvelope<TxEip4844Variant>`; each variant holds a `Signed<TxLegacy>`, `Signed<TxEip1559>`, ...",
+            },
+            Assoc {
+                name: "UnsignedTx",
+                ty: "TypedTransaction",
+                path: "alloy::consensus::TypedTransaction",
+                note: "Alias of `EthereumTypedTransaction<TxEip4844Variant>`; the same variants without signatures.",
+            },
+            Assoc {
+                name: "ReceiptEnvelope",
+                ty: "ReceiptEnvelope",
+                path: "alloy::consensus::ReceiptEnvelope",
+                note: "One variant per transaction type, each a `ReceiptWithBloom`.",
+            },
+            Assoc {
+                name: "Header",
+                ty: "Header",
+                path: "alloy::consensus::Header",
+                note: "The consensus header; `hash_slow()` gives the block hash. Not the RPC `Header` below.",
+            },
+            Assoc {
+                name: "TransactionRequest",
+                ty: "TransactionRequest",
+                path: "alloy::rpc::types::TransactionRequest",
+                note: "Every field optional; fillers complete it before signing.",
+            },
+            Assoc {
+                name: "TransactionResponse",
+                ty: "Transaction",
+                path: "alloy::rpc::types::Transaction",
+                note: "`Transaction<TxEnvelope>`: the recovered envelope in `inner` plus block hash, number and index. Not the `alloy::consensus::Transaction` trait.",
+            },
+            Assoc {
+                name: "ReceiptResponse",
+                ty: "TransactionReceipt",
+                path: "alloy::rpc::types::TransactionReceipt",
+                note: "`TransactionReceipt<ReceiptEnvelope<Log>>`: status, gas used, logs and the block it landed in.",
+            },
+            Assoc {
+                name: "HeaderResponse",
+                ty: "Header",
+                path: "alloy::rpc::types::Header",
+                note: "`rpc::types::Header<consensus::Header>`: the consensus header in `inner` plus `hash`, `size` and `total_difficulty`.",
+            },
+            Assoc {
+                name: "BlockResponse",
+                ty: "Block",
+                path: "alloy::rpc::types::Block",
+                note: "`Block<Transaction, Header>`; `transactions` holds hashes or full transactions depending on `.full()`.",
+            },
+        ],
+        example: "use alloy::{\n    consensus::TxEnvelope,\n    network::TransactionBuilder,\n    providers::{Provider, ProviderBuilder},\n    rpc::types::{TransactionReceipt, TransactionRequest},\n};\n\nlet provider = ProviderBuilder::new().wallet(signer).connect_http(url);\nlet tx = TransactionRequest::default().with_to(to).with_value(value);\nlet receipt: TransactionReceipt = provider.send_transaction(tx).await?.get_receipt().await?;\n\nlet rpc_tx = provider.get_transaction_by_hash(receipt.transaction_hash).await?.unwrap();\nlet envelope: &TxEnvelope = rpc_tx.as_ref();",
+        notes: &[
+            "`alloy::rpc::types::Header` (`N::HeaderResponse`) wraps `alloy::consensus::Header` (`N::Header`); import one of them under another name when both are needed.",
+            "`Provider` without a type parameter is `Provider<Ethereum>`.",
+        ],
+    },
+    Network {
+        name: "AnyNetwork",
+        slug: "any",
+        aliases: &["anynetwork", "any", "catch-all"],
+        path: "alloy::network::AnyNetwork",
+        dependency: "alloy = { version = \"1\", features = [\"full\"] }",
+        summary: "A catch-all network for chains alloy has no types for: Ethereum transaction types decode as usual, anything else is kept as an unknown envelope, and fields Ethereum does not define are kept in `other` instead of failing deserialization.",
+        types: &[
+            Assoc {
+                name: "TxType",
+                ty: "AnyTxType",
+                path: "alloy::network::AnyTxType",
+                note: "`AnyTxType(u8)`: any type byte.",
+            },
+            Assoc {
+                name: "TxEnvelope",
+                ty: "AnyTxEnvelope",
+                path: "alloy::network::AnyTxEnvelope",
+                note: "`Ethereum(TxEnvelope)` or `Unknown(UnknownTxEnvelope)`; `as_envelope()` and `try_into_envelope()` get the Ethereum one.",
+            },
+            Assoc {
+                name: "UnsignedTx",
+                ty: "AnyTypedTransaction",
+                path: "alloy::network::AnyTypedTransaction",
+                note: "`Ethereum(TypedTransaction)` or `Unknown(UnknownTypedTransaction)`.",
+            },
+            Assoc {
+                name: "ReceiptEnvelope",
+                ty: "AnyReceiptEnvelope",
+                path: "alloy::network::AnyReceiptEnvelope",
+                note: "A `ReceiptWithBloom` tagged with its type byte, for any type.",
+            },
+            Assoc {
+                name: "Header",
+                ty: "AnyHeader",
+                path: "alloy::network::AnyHeader",
+                note: "Ethereum's header fields with the post-merge ones optional.",
+            },
+            Assoc {
+                name: "TransactionRequest",
+                ty: "WithOtherFields<TransactionRequest>",
+                path: "alloy::serde::WithOtherFields",
+                note: "Derefs to `TransactionRequest`; build it with `WithOtherFields::new(request)` or `request.into()` and put chain-specific JSON fields in `other`.",
+            },
+            Assoc {
+                name: "TransactionResponse",
+                ty: "AnyRpcTransaction",
+                path: "alloy::network::AnyRpcTransaction",
+                note: "`WithOtherFields<Transaction<AnyTxEnvelope>>`; `try_into_envelope()` returns the Ethereum `TxEnvelope`.",
+            },
+            Assoc {
+                name: "ReceiptResponse",
+                ty: "AnyTransactionReceipt",
+                path: "alloy::network::AnyTransactionReceipt",
+                note: "`WithOtherFields<TransactionReceipt<AnyReceiptEnvelope<Log>>>`; L2 fee fields land in `other`.",
+            },
+            Assoc {
+                name: "HeaderResponse",
+                ty: "AnyRpcHeader",
+                path: "alloy::network::AnyRpcHeader",
+                note: "`rpc::types::Header<AnyHeader>`.",
+            },
+            Assoc {
+                name: "BlockResponse",
+                ty: "AnyRpcBlock",
+                path: "alloy::network::AnyRpcBlock",
+                note: "`WithOtherFields<Block<AnyRpcTransaction, AnyRpcHeader>>`; `into_inner()` unwraps it and `try_into_consensus()` converts it to a consensus block.",
+            },
+        ],
+        example: "use alloy::{\n    eips::BlockNumberOrTag,\n    network::{AnyNetwork, AnyRpcBlock, AnyTransactionReceipt, TransactionBuilder},\n    primitives::U256,\n    providers::{Provider, ProviderBuilder},\n    rpc::types::TransactionRequest,\n    serde::WithOtherFields,\n};\n\nlet provider = ProviderBuilder::new_with_network::<AnyNetwork>()\n    .wallet(signer)\n    .connect_http(url);\nlet tx = WithOtherFields::new(TransactionRequest::default().with_to(to).with_value(value));\nlet receipt: AnyTransactionReceipt = provider.send_transaction(tx).await?.get_receipt().await?;\nlet l1_fee: Option<U256> = receipt.other.get_deserialized(\"l1Fee\").transpose()?;\n\nlet block: Option<AnyRpcBlock> = provider.get_block_by_number(BlockNumberOrTag::Latest).await?;",
+        notes: &[
+            "Use it when `Provider<Ethereum>` fails to deserialize a chain's blocks or receipts (`unknown variant`, `missing field`), e.g. for an L2 with its own transaction types.",
+            "Unknown transaction types cannot be signed or built locally; only their JSON is kept.",
+            "`EthereumWallet` signs for `AnyNetwork` too.",
+        ],
+    },
+    Network {
+        name: "Optimism",
+        slug: "optimism",
+        aliases: &[
+            "optimism",
+            "op",
+            "op-stack",
+            "opstack",
+            "base",
+            "superchain",
+        ],
+        path: "op_alloy::network::Optimism",
+        dependency: "op-alloy = { version = \"0.24\", features = [\"consensus\", \"network\", \"rpc-types\"] }",
+        summary: "OP-stack chains (OP Mainnet, Base and the rest of the Superchain), from the `op-alloy` crates. Adds the deposit transaction type and L1 fee data on receipts; drops EIP-4844.",
+        types: &[
+            Assoc {
+                name: "TxType",
+                ty: "OpTxType",
+                path: "op_alloy::consensus::OpTxType",
+                note: "`Legacy` (0), `Eip2930` (1), `Eip1559` (2), `Eip7702` (4), `Deposit` (126, `0x7E`).",
+            },
+            Assoc {
+                name: "TxEnvelope",
+                ty: "OpTxEnvelope",
+                path: "op_alloy::consensus::OpTxEnvelope",
+                note: "`Signed<T>` variants for the Ethereum types plus `Deposit(Sealed<TxDeposit>)`, which carries no signature.",
+            },
+            Assoc {
+                name: "UnsignedTx",
+                ty: "OpTypedTransaction",
+                path: "op_alloy::consensus::OpTypedTransaction",
+                note: "The same variants unsigned, with `Deposit(TxDeposit)`.",
+            },
+            Assoc {
+                name: "ReceiptEnvelope",
+                ty: "OpReceiptEnvelope",
+                path: "op_alloy::consensus::OpReceiptEnvelope",
+                note: "One variant per type, each a `ReceiptWithBloom`; `Deposit` wraps an `OpDepositReceipt`, which adds the deposit nonce and receipt version.",
+            },
+            Assoc {
+                name: "Header",
+                ty: "Header",
+                path: "alloy::consensus::Header",
+                note: "The Ethereum consensus header.",
+            },
+            Assoc {
+                name: "TransactionRequest",
+                ty: "OpTransactionRequest",
+                path: "op_alloy::rpc_types::OpTransactionRequest",
+                note: "A newtype over `TransactionRequest`: `OpTransactionRequest::from(request)`, `.as_ref()`/`.as_mut()` for the inner one, and `TransactionRequest::from(op_request)` back.",
+            },
+            Assoc {
+                name: "TransactionResponse",
+                ty: "Transaction",
+                path: "op_alloy::rpc_types::Transaction",
+                note: "`op_alloy::rpc_types::Transaction`: `inner` is an `alloy::rpc::types::Transaction<OpTxEnvelope>`, plus `deposit_nonce` and `deposit_receipt_version`. Same name as the Ethereum RPC type; import the one you mean.",
+            },
+            Assoc {
+                name: "ReceiptResponse",
+                ty: "OpTransactionReceipt",
+                path: "op_alloy::rpc_types::OpTransactionReceipt",
+                note: "`inner` is a `TransactionReceipt<OpReceiptEnvelope<Log>>`; `l1_block_info` holds the L1 fee, gas used and fee scalars.",
+            },
+            Assoc {
+                name: "HeaderResponse",
+                ty: "Header",
+                path: "alloy::rpc::types::Header",
+                note: "The Ethereum RPC header.",
+            },
+            Assoc {
+                name: "BlockResponse",
+                ty: "Block<Transaction, Header>",
+                path: "alloy::rpc::types::Block",
+                note: "`alloy::rpc::types::Block` of `op_alloy::rpc_types::Transaction` and the Ethereum RPC header.",
+            },
+        ],
+        example: "use alloy::{\n    network::{EthereumWallet, TransactionBuilder},\n    providers::{Provider, ProviderBuilder},\n    rpc::types::TransactionRequest,\n};\nuse op_alloy::{\n    network::Optimism,\n    rpc_types::{OpTransactionReceipt, OpTransactionRequest},\n};\n\nlet provider = ProviderBuilder::new_with_network::<Optimism>()\n    .wallet(EthereumWallet::from(signer))\n    .connect_http(url);\nlet tx = OpTransactionRequest::from(TransactionRequest::default().with_to(to).with_value(value));\nlet receipt: OpTransactionReceipt = provider.send_transaction(tx).await?.get_receipt().await?;\nlet l1_fee: Option<u128> = receipt.l1_block_info.l1_fee;",
+        notes: &[
+            "op-alloy 0.24 works with alloy 1.x; op-alloy 2.x needs alloy 2.x. Keep the two in step or the `Network` traits will not match.",
+            "`.wallet(signer)` takes a bare `PrivateKeySigner` only for `Ethereum` and `AnyNetwork`; wrap it in `EthereumWallet::from(signer)` for `Optimism`.",
+            "Deposit transactions (`OpTxEnvelope::Deposit`) are created on L1 and cannot be signed or sent through a wallet.",
+            "`Provider<Ethereum>` can read OP-stack chains until it meets a deposit transaction or receipt; use `Optimism` (or `AnyNetwork`) for blocks and receipts.",
+        ],
+    },
+];
+
+/// `assoc`'s type with its module, e.g. `rpc::types::Header`, for tables
+/// where `Header` alone would be ambiguous.
+fn qualified(assoc: &Assoc) -> String {
+    let path = assoc.path.strip_prefix("alloy::").unwrap_or(assoc.path);
+    let base = assoc.ty.split('<').next().unwrap_or(assoc.ty);
+    match assoc.path.rsplit("::").next() {
+        Some(last) if last == base => format!("{path}{}", &assoc.ty[base.len()..]),
+        _ => assoc.ty.to_string(),
+    }
+}
+
+/// The network called `name`, by type name, slug or alias.
+pub fn find(name: &str) -> Option<&'static Network> {
+    let name = name
+        .trim()
+        .rsplit("::")
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    NETWORKS.iter().find(|n| {
+        n.name.to_lowercase() == name || n.slug == name || n.aliases.contains(&name.as_str())
+    })
+}
+
+/// How the associated types of `network` turn into each other, from the
+/// bounds `Network` puts on them.
+fn relations(network: &Network) -> String {
+    // Both headers are `Header`; name them by module where names collide.
+    let ty = |name: &'static str| {
+        let Some(assoc) = network.types.iter().find(|a| a.name == name) else {
+            return name;
+        };
+        let clashes = network
+            .types
+            .iter()
+            .any(|a| a.ty == assoc.ty && a.name != assoc.name);
+        if clashes {
+            assoc.path.strip_prefix("alloy::").unwrap_or(assoc.path)
+        } else {
+            assoc.ty
+        }
+    };
+    format!(
+        "```text\n\
+        {req} ──TransactionBuilder::build_unsigned()──▶ {unsigned}\n\
+        {req} ──TransactionBuilder::build(&wallet)──▶ {env}  (signed by a NetworkWallet<{name}>)\n\
+        {env} ──From──▶ {unsigned}  (drops the signature)\n\
+        {env} / {unsigned} ──From──▶ {req}\n\
+        {resp} ──AsRef──▶ {env}\n\
+        {header_resp} ──AsRef──▶ {header}\n\
+        {block} ──.header() / .transactions()──▶ {header_resp} / BlockTransactions<{resp}>\n\
+        provider.send_transaction({req}) ──.get_receipt()──▶ {receipt}\n\
+        ```\n",
+        name = network.name,
+        req = ty("TransactionRequest"),
+        unsigned = ty("UnsignedTx"),
+        env = ty("TxEnvelope"),
+        resp = ty("TransactionResponse"),
+        header_resp = ty("HeaderResponse"),
+        header = ty("Header"),
+        block = ty("BlockResponse"),
+        receipt = ty("ReceiptResponse"),
+    )
+}
+
+/// The associated types table of one network.
+pub fn table(types: &[&Assoc]) -> String {
+    let mut table = String::from("| `N::` | Concrete type | Import | Notes |\n|---|---|---|---|\n");
+    for assoc in types {
+        let _ = writeln!(
+            table,
+            "| `{}` | `{}` | `{}` | {} |",
+            assoc.name, assoc.ty, assoc.path, assoc.note
+        );
+    }
+    table
+}
+
+/// Markdown for `alloy://network/{slug}`.
+pub fn guide(network: &Network) -> String {
+    let mut result = format!(
+        "# `{}` Network Types\n\n{}\n\n```rust\nuse {};\n```\n\n```toml\n{}\n```\n\n\
+        ## Associated Types\n\n{}\n## How They Relate\n\n{}\n## Example\n\n```rust\n{}\n```\n\n## Notes\n\n",
+        network.name,
+        network.summary,
+        network.path,
+        network.dependency,
+        table(&network.types.iter().collect::<Vec<_>>()),
+        relations(network),
+        network.example,
+    );
+    for note in network.notes {
+        let _ = writeln!(result, "- {note}");
+    }
+    result
+}
+
+/// Markdown for `alloy://network`: every network's associated types side by
+/// side.
+pub fn index() -> String {
+    let mut result = String::from(
+        "# alloy Networks\n\n\
+        A `Network` is a zero-sized type that names the consensus and RPC types a provider, \
+        transaction builder and wallet work with. Generic code written against `N: Network` \
+        sees them as `N::TxEnvelope`, `N::TransactionRequest`, ...; this table shows what each \
+        resolves to (paths relative to `alloy::` unless they start with `op_alloy::`). Read `alloy://network/{network}` for one network, or call the \
+        `network_types` tool.\n\n",
+    );
+    let _ = write!(result, "| `N::` |");
+    for network in NETWORKS {
+        let _ = write!(
+            result,
+            " [`{}`](alloy://network/{}) |",
+            network.name, network.slug
+        );
+    }
+    result.push_str("\n|---|");
+    result.push_str(&"---|".repeat(NETWORKS.len()));
+    result.push('\n');
+    for (name, _) in ASSOCIATED {
+        let _ = write!(result, "| `{name}` |");
+        for network in NETWORKS {
+            let ty = network
+                .types
+                .iter()
+                .find(|a| a.name == *name)
+                .map(qualified);
+            let _ = write!(result, " `{}` |", ty.unwrap_or_default());
+        }
+        result.push('\n');
+    }
+    result.push_str("\n## Associated Types\n\n");
+    for (name, what) in ASSOCIATED {
+        let _ = writeln!(result, "- `{name}` — {what}");
+    }
+    result.push_str(
+        "\n## Generic Code\n\n\
+        ```rust\n\
+        use alloy::{\n    \
+            network::{Network, ReceiptResponse, TransactionBuilder},\n    \
+            providers::Provider,\n\
+        };\n\n\
+        async fn send<N: Network>(\n    \
+            provider: &impl Provider<N>,\n    \
+            tx: N::TransactionRequest,\n\
+        ) -> eyre::Result<N::ReceiptResponse> {\n    \
+            let receipt = provider.send_transaction(tx).await?.get_receipt().await?;\n    \
+            println!(\"status: {}\", receipt.status());\n    \
+            Ok(receipt)\n\
+        }\n\
+        ```\n\n\
+        `Network` already bounds `N::TransactionRequest: TransactionBuilder<N>`, \
+        `N::ReceiptResponse: ReceiptResponse` and `N::BlockResponse: BlockResponse`, so the \
+        accessor traits work without extra `where` clauses once they are imported.\n",
+    );
+    result
+}
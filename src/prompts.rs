@@ -1,212 +1,328 @@
+use std::{collections::HashMap, sync::Arc};
+
 use rmcp::{
-    model::{PromptMessage, PromptMessageRole},
-    prompt, prompt_router,
+    ErrorData,
+    handler::server::{
+        prompt::PromptContext,
+        router::prompt::{PromptRoute, PromptRouter},
+    },
+    model::{GetPromptResult, Prompt, PromptArgument, PromptMessage, PromptMessageRole},
 };
+use serde_json::{Map, Value};
 
-use crate::server::AlloyMcpServer;
+use crate::{resources::StaticResource, server::AlloyMcpServer};
 
-#[prompt_router]
-impl AlloyMcpServer {
-    pub fn create_prompt_router() -> rmcp::handler::server::router::prompt::PromptRouter<Self> {
-        Self::prompt_router()
+mod template;
+
+use template::{Resolved, Segment, Template};
+
+// `PROMPT_FILES`: every `prompts/**/*.md` as (relative path, contents),
+// generated by build.rs.
+include!(concat!(env!("OUT_DIR"), "/prompt_files.rs"));
+
+/// Prompt files under this directory are partials, included with
+/// `{{> name}}` rather than registered as prompts.
+const PARTIALS_DIR: &str = "partials/";
+
+/// One entry of a template's `[[arguments]]` frontmatter.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ArgumentSpec {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    pub default: Option<String>,
+    /// Accepted values; empty means free-form.
+    #[serde(default)]
+    pub values: Vec<String>,
+    /// Display names for values, rendered by `{{arg.label}}`.
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+    /// Alternative spellings mapped onto one of `values`.
+    #[serde(default)]
+    pub aliases: HashMap<String, String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Frontmatter {
+    name: String,
+    description: String,
+    #[serde(default)]
+    arguments: Vec<ArgumentSpec>,
+}
+
+/// A prompt loaded from a markdown template in `prompts/`.
+#[derive(Debug)]
+pub struct PromptTemplate {
+    pub name: String,
+    pub description: String,
+    pub arguments: Vec<ArgumentSpec>,
+    messages: Vec<(PromptMessageRole, Template)>,
+    /// Text of every `{{embed}}` target, resolved when the template loads.
+    sections: HashMap<String, String>,
+}
+
+impl PromptTemplate {
+    /// Parse a template: `+++`-delimited TOML frontmatter, then message
+    /// bodies introduced by `<!-- user -->` / `<!-- assistant -->` lines.
+    /// `{{embed}}` targets must name an existing resource section.
+    fn parse(
+        source: &str,
+        partials: &HashMap<String, &str>,
+        resources: &HashMap<String, StaticResource>,
+    ) -> Result<Self, String> {
+        let rest = source
+            .strip_prefix("+++")
+            .ok_or("template must start with `+++` TOML frontmatter")?;
+        let (frontmatter, body) = rest
+            .split_once("\n+++")
+            .ok_or("frontmatter is not closed with `+++`")?;
+        let frontmatter: Frontmatter =
+            toml::from_str(frontmatter).map_err(|e| format!("invalid frontmatter: {e}"))?;
+
+        let mut messages = Vec::new();
+        let mut current: Option<(PromptMessageRole, String)> = None;
+        for line in body.lines() {
+            let role = match line.trim() {
+                "<!-- user -->" => Some(PromptMessageRole::User),
+                "<!-- assistant -->" => Some(PromptMessageRole::Assistant),
+                _ => None,
+            };
+            match (role, &mut current) {
+                (Some(role), _) => {
+                    messages.extend(current.take());
+                    current = Some((role, String::new()));
+                }
+                (None, Some((_, text))) => {
+                    text.push_str(line);
+                    text.push('\n');
+                }
+                (None, None) if line.trim().is_empty() => {}
+                (None, None) => {
+                    return Err(
+                        "text before the first `<!-- user -->`/`<!-- assistant -->` marker".into(),
+                    );
+                }
+            }
+        }
+        messages.extend(current);
+        if messages.is_empty() {
+            return Err("template has no messages".into());
+        }
+
+        let declared: HashMap<&str, &[String]> = frontmatter
+            .arguments
+            .iter()
+            .map(|a| (a.name.as_str(), a.values.as_slice()))
+            .collect();
+        let messages: Vec<(PromptMessageRole, Template)> = messages
+            .into_iter()
+            .map(|(role, text)| {
+                let template = Template::parse(&text, partials)?;
+                template.validate(&declared)?;
+                Ok((role, template))
+            })
+            .collect::<Result<_, String>>()?;
+
+        let mut sections = HashMap::new();
+        for uri in messages.iter().flat_map(|(_, t)| t.embeds()) {
+            let text = crate::resources::lookup(resources, uri)
+                .ok_or_else(|| format!("`{{{{embed {uri}}}}}` does not name a resource section"))?;
+            sections.insert(uri.to_string(), text.to_string());
+        }
+
+        Ok(Self {
+            name: frontmatter.name,
+            description: frontmatter.description,
+            arguments: frontmatter.arguments,
+            messages,
+            sections,
+        })
     }
 
-    /// Step-by-step guide for building and sending an Ethereum transaction with alloy.
-    #[prompt(
-        name = "build_transaction",
-        description = "Step-by-step guide: provider setup, TransactionRequest, send, receipt"
-    )]
-    fn build_transaction(&self) -> Vec<PromptMessage> {
-        vec![
-            PromptMessage::new_text(
-                PromptMessageRole::User,
-                "Help me build and send an Ethereum transaction using alloy.",
-            ),
-            PromptMessage::new_text(
-                PromptMessageRole::Assistant,
-                "Here's a step-by-step guide for building and sending a transaction with alloy:\n\n\
-                ## Step 1: Set Up Provider\n\n\
-                ```rust\n\
-                use alloy::providers::ProviderBuilder;\n\
-                use alloy::signers::local::PrivateKeySigner;\n\
-                use alloy::network::EthereumWallet;\n\n\
-                let signer: PrivateKeySigner = \"0xYOUR_PRIVATE_KEY\".parse()?;\n\
-                let wallet = EthereumWallet::from(signer);\n\n\
-                let provider = ProviderBuilder::new()\n\
-                    .wallet(wallet)\n\
-                    .connect(\"https://your-rpc-url\")\n\
-                    .await?;\n\
-                ```\n\n\
-                ## Step 2: Build TransactionRequest\n\n\
-                ```rust\n\
-                use alloy::rpc::types::TransactionRequest;\n\
-                use alloy::primitives::U256;\n\n\
-                let tx = TransactionRequest::default()\n\
-                    .with_to(recipient_address)\n\
-                    .with_value(U256::from(1_000_000_000_000_000_000u64)); // 1 ETH\n\
-                ```\n\n\
-                Gas, nonce, and chain_id are filled automatically by default fillers.\n\n\
-                ## Step 3: Send Transaction\n\n\
-                ```rust\n\
-                let pending = provider.send_transaction(tx).await?;\n\
-                let tx_hash = pending.tx_hash();\n\
-                println!(\"Transaction hash: {tx_hash}\");\n\
-                ```\n\n\
-                ## Step 4: Wait for Receipt\n\n\
-                ```rust\n\
-                let receipt = pending.get_receipt().await?;\n\
-                if receipt.status() {\n\
-                    println!(\"Transaction succeeded!\");\n\
-                } else {\n\
-                    println!(\"Transaction reverted\");\n\
-                }\n\
-                ```\n\n\
-                **Key resources:**\n\
-                - `alloy://rpc/transaction-request` — TransactionRequest builder methods\n\
-                - `alloy://provider/setup` — Provider configuration\n\
-                - `alloy://provider/fillers` — How gas/nonce/chain_id are auto-filled\n\
-                - `alloy://signers/signing-guide` — Signer setup",
-            ),
-        ]
+    /// Accepted values for one of this prompt's arguments.
+    pub fn argument_values(&self, argument: &str) -> &[String] {
+        self.arguments
+            .iter()
+            .find(|a| a.name == argument)
+            .map_or(&[], |a| a.values.as_slice())
     }
 
-    /// Step-by-step guide for setting up sol! contract bindings with alloy.
-    #[prompt(
-        name = "setup_contract_bindings",
-        description = "Guide: sol! macro, ContractInstance, call/send pattern"
-    )]
-    fn setup_contract_bindings(&self) -> Vec<PromptMessage> {
-        vec![
-            PromptMessage::new_text(
-                PromptMessageRole::User,
-                "Help me set up contract bindings using alloy's sol! macro.",
-            ),
-            PromptMessage::new_text(
-                PromptMessageRole::Assistant,
-                "Here's how to set up contract bindings with alloy's sol! macro:\n\n\
-                ## Step 1: Define the Contract Interface\n\n\
-                ```rust\n\
-                use alloy::sol;\n\n\
-                sol! {\n\
-                    #[sol(rpc)]\n\
-                    contract MyContract {\n\
-                        event Transfer(address indexed from, address indexed to, uint256 value);\n\n\
-                        function balanceOf(address owner) external view returns (uint256);\n\
-                        function transfer(address to, uint256 amount) external returns (bool);\n\
-                    }\n\
-                }\n\
-                ```\n\n\
-                **Important:** `#[sol(rpc)]` is required to generate the contract instance with \
-                `.call()`/`.send()` methods.\n\n\
-                ## Step 2: Create Contract Instance\n\n\
-                ```rust\n\
-                let contract = MyContract::new(contract_address, &provider);\n\
-                ```\n\n\
-                ## Step 3: Read Data (call)\n\n\
-                ```rust\n\
-                let result = contract.balanceOf(owner_address).call().await?;\n\
-                let balance: U256 = result._0;\n\
-                ```\n\n\
-                `.call()` simulates the call (free, no state change).\n\n\
-                ## Step 4: Write Data (send)\n\n\
-                ```rust\n\
-                let pending = contract.transfer(recipient, amount).send().await?;\n\
-                let receipt = pending.get_receipt().await?;\n\
-                ```\n\n\
-                `.send()` submits a transaction (costs gas, changes state). Requires wallet on provider.\n\n\
-                ## Step 5: Decode Events\n\n\
-                ```rust\n\
-                use alloy::sol_types::SolEvent;\n\n\
-                for log in receipt.inner.logs() {\n\
-                    if let Ok(transfer) = MyContract::Transfer::decode_log(log, true) {\n\
-                        println!(\"{} -> {}: {}\", transfer.from, transfer.to, transfer.value);\n\
-                    }\n\
-                }\n\
-                ```\n\n\
-                **Key resources:**\n\
-                - `alloy://sol-macro/contract-bindings` — Full sol! macro reference\n\
-                - `alloy://sol-macro/sol-types` — ABI encoding/decoding\n\
-                - `alloy://consensus/events` — Event decoding patterns",
-            ),
-        ]
+    fn prompt(&self) -> Prompt {
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|a| PromptArgument {
+                name: a.name.clone(),
+                title: None,
+                description: a.description.clone(),
+                required: Some(a.required),
+            })
+            .collect::<Vec<_>>();
+        Prompt::new(
+            &self.name,
+            Some(&self.description),
+            (!arguments.is_empty()).then_some(arguments),
+        )
     }
 
-    /// Step-by-step guide for setting up signing with alloy, including EIP-712.
-    #[prompt(
-        name = "setup_signing",
-        description = "Guide: signer creation, wallet, signing flow including EIP-712"
-    )]
-    fn setup_signing(&self) -> Vec<PromptMessage> {
-        vec![
-            PromptMessage::new_text(
-                PromptMessageRole::User,
-                "Help me set up signing with alloy, including EIP-712 typed data.",
-            ),
-            PromptMessage::new_text(
-                PromptMessageRole::Assistant,
-                "Here's how to set up signing with alloy:\n\n\
-                ## Step 1: Create a Signer\n\n\
-                ```rust\n\
-                use alloy::signers::local::PrivateKeySigner;\n\n\
-                // From hex string\n\
-                let signer: PrivateKeySigner = \"0xac0974...\".parse()?;\n\n\
-                // Random (for testing)\n\
-                let signer = PrivateKeySigner::random();\n\n\
-                let address = signer.address();\n\
-                ```\n\n\
-                ## Step 2: Wrap in Wallet (for provider)\n\n\
-                ```rust\n\
-                use alloy::network::EthereumWallet;\n\n\
-                let wallet = EthereumWallet::from(signer.clone());\n\
-                let provider = ProviderBuilder::new()\n\
-                    .wallet(wallet)\n\
-                    .connect(url)\n\
-                    .await?;\n\
-                ```\n\n\
-                ## Step 3: Sign a Message (EIP-191)\n\n\
-                ```rust\n\
-                use alloy::signers::Signer;\n\n\
-                let signature = signer.sign_message(b\"Hello, world!\").await?;\n\
-                ```\n\n\
-                ## Step 4: Sign Typed Data (EIP-712)\n\n\
-                ### 4a. Define the struct in sol!\n\n\
-                ```rust\n\
-                use alloy::sol;\n\n\
-                sol! {\n\
-                    struct MyMessage {\n\
-                        address sender;\n\
-                        uint256 amount;\n\
-                        uint256 nonce;\n\
-                    }\n\
-                }\n\
-                ```\n\n\
-                ### 4b. Create the EIP-712 domain\n\n\
-                ```rust\n\
-                use alloy::sol_types::eip712_domain;\n\n\
-                let domain = eip712_domain! {\n\
-                    name: \"MyProtocol\",\n\
-                    version: \"1\",\n\
-                    chain_id: 1,\n\
-                    verifying_contract: contract_address,\n\
-                };\n\
-                ```\n\n\
-                ### 4c. Compute signing hash and sign\n\n\
-                ```rust\n\
-                use alloy::sol_types::SolStruct;\n\
-                use alloy::signers::Signer;\n\n\
-                let message = MyMessage {\n\
-                    sender: signer.address(),\n\
-                    amount: U256::from(1000),\n\
-                    nonce: U256::ZERO,\n\
-                };\n\n\
-                let hash = message.eip712_signing_hash(&domain);\n\
-                let signature = signer.sign_hash(&hash).await?;\n\
-                ```\n\n\
-                **Key resources:**\n\
-                - `alloy://signers/signing-guide` — Full signer reference\n\
-                - `alloy://sol-macro/sol-types` — SolStruct for EIP-712\n\
-                - `alloy://primitives/core-types` — Address, B256, U256 types",
-            ),
-        ]
+    /// Resolve request arguments against the frontmatter: apply aliases and
+    /// defaults, and reject unknown values.
+    fn resolve(
+        &self,
+        arguments: Option<&Map<String, Value>>,
+    ) -> Result<HashMap<String, Resolved>, String> {
+        let mut resolved = HashMap::new();
+        for spec in &self.arguments {
+            let given = arguments
+                .and_then(|args| args.get(&spec.name))
+                .map(|v| match v {
+                    Value::String(s) => s.trim().to_string(),
+                    other => other.to_string(),
+                })
+                .filter(|s| !s.is_empty());
+            let value = match given.or_else(|| spec.default.clone()) {
+                Some(v) => spec.aliases.get(&v).cloned().unwrap_or(v),
+                None if spec.required => {
+                    return Err(format!("missing required argument `{}`", spec.name));
+                }
+                None => String::new(),
+            };
+            if !spec.values.is_empty() && !value.is_empty() && !spec.values.contains(&value) {
+                return Err(format!(
+                    "`{}` must be one of {}, got `{value}`",
+                    spec.name,
+                    spec.values.join(", ")
+                ));
+            }
+            let label = spec
+                .labels
+                .get(&value)
+                .cloned()
+                .unwrap_or_else(|| value.clone());
+            resolved.insert(spec.name.clone(), Resolved { value, label });
+        }
+        Ok(resolved)
+    }
+
+    fn render(&self, arguments: Option<&Map<String, Value>>) -> Result<GetPromptResult, ErrorData> {
+        let resolved = self
+            .resolve(arguments)
+            .map_err(|e| ErrorData::invalid_params(e, None))?;
+        let mut messages = Vec::new();
+        for (role, template) in &self.messages {
+            for segment in template.render(&resolved) {
+                match segment {
+                    Segment::Text(text) => {
+                        let text = collapse_blank_lines(&text);
+                        if !text.is_empty() {
+                            messages.push(PromptMessage::new_text(role.clone(), text));
+                        }
+                    }
+                    Segment::Embed(uri) => messages.push(PromptMessage::new_resource(
+                        role.clone(),
+                        uri.clone(),
+                        Some("text/markdown".into()),
+                        self.sections.get(&uri).cloned(),
+                        None,
+                        None,
+                        None,
+                    )),
+                }
+            }
+        }
+        Ok(GetPromptResult {
+            description: Some(self.description.clone()),
+            messages,
+        })
+    }
+}
+
+/// Trim a rendered message and squeeze runs of blank lines, which
+/// conditionals whose blocks rendered empty tend to leave behind.
+fn collapse_blank_lines(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut blank = false;
+    for line in text.trim().lines() {
+        let is_blank = line.trim().is_empty();
+        if !(blank && is_blank) {
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        blank = is_blank;
+    }
+    out.pop();
+    out
+}
+
+/// Returns all prompt templates indexed by name. Templates that fail to
+/// parse are logged and skipped.
+pub fn all(resources: &HashMap<String, StaticResource>) -> HashMap<String, Arc<PromptTemplate>> {
+    let partials = partials();
+    PROMPT_FILES
+        .iter()
+        .filter(|(path, _)| !path.starts_with(PARTIALS_DIR))
+        .filter_map(
+            |(path, source)| match PromptTemplate::parse(source, &partials, resources) {
+                Ok(template) => Some((template.name.clone(), Arc::new(template))),
+                Err(e) => {
+                    tracing::error!("skipping prompt template prompts/{path}: {e}");
+                    None
+                }
+            },
+        )
+        .collect()
+}
+
+/// Partials by name, without the directory and `.md` suffix.
+fn partials() -> HashMap<String, &'static str> {
+    PROMPT_FILES
+        .iter()
+        .filter_map(|(path, source)| {
+            let name = path.strip_prefix(PARTIALS_DIR)?.strip_suffix(".md")?;
+            Some((name.to_string(), *source))
+        })
+        .collect()
+}
+
+impl AlloyMcpServer {
+    pub fn create_prompt_router(
+        prompts: &HashMap<String, Arc<PromptTemplate>>,
+    ) -> PromptRouter<Self> {
+        let mut router = PromptRouter::new();
+        for template in prompts.values() {
+            let template = Arc::clone(template);
+            router.add_route(PromptRoute::new_dyn(
+                template.prompt(),
+                move |context: PromptContext<'_, Self>| {
+                    let result = template.render(context.arguments.as_ref());
+                    Box::pin(std::future::ready(result))
+                },
+            ));
+        }
+        router
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::LATEST;
+
+    /// `all` logs and skips templates that fail to parse, so a broken
+    /// template would otherwise only show up as a missing prompt.
+    #[test]
+    fn every_embedded_template_parses() {
+        let partials = partials();
+        let resources = LATEST.resources();
+        for (path, source) in PROMPT_FILES {
+            if path.starts_with(PARTIALS_DIR) {
+                continue;
+            }
+            if let Err(e) = PromptTemplate::parse(source, &partials, &resources) {
+                panic!("prompts/{path}: {e}");
+            }
+        }
+        assert_eq!(all(&resources).len(), 7);
     }
 }
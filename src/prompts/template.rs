@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+
+/// How deeply `{{> partial}}` includes may nest before parsing gives up.
+const MAX_PARTIAL_DEPTH: usize = 8;
+
+/// An argument value as seen by a template: `{{arg}}` renders `value`,
+/// `{{arg.label}}` renders `label`.
+pub(super) struct Resolved {
+    pub(super) value: String,
+    pub(super) label: String,
+}
+
+/// One `arg == a|b` / `arg != a|b` test.
+#[derive(Debug)]
+struct Clause {
+    argument: String,
+    negated: bool,
+    values: Vec<String>,
+}
+
+/// `or`-separated groups of `and`-joined clauses.
+#[derive(Debug)]
+struct Condition(Vec<Vec<Clause>>);
+
+impl Condition {
+    fn parse(source: &str) -> Result<Self, String> {
+        let parse_clause = |clause: &str| {
+            let (argument, negated, values) = if let Some((a, v)) = clause.split_once("!=") {
+                (a, true, v)
+            } else if let Some((a, v)) = clause.split_once("==") {
+                (a, false, v)
+            } else {
+                return Err(format!(
+                    "condition `{clause}` must be `arg == value` or `arg != value`"
+                ));
+            };
+            Ok(Clause {
+                argument: argument.trim().to_string(),
+                negated,
+                values: values.split('|').map(|v| v.trim().to_string()).collect(),
+            })
+        };
+        source
+            .split(" or ")
+            .map(|group| group.split(" and ").map(parse_clause).collect())
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn eval(&self, args: &HashMap<String, Resolved>) -> bool {
+        self.0.iter().any(|group| {
+            group.iter().all(|clause| {
+                let value = args.get(&clause.argument).map_or("", |r| r.value.as_str());
+                clause.values.iter().any(|v| v == value) != clause.negated
+            })
+        })
+    }
+
+    fn clauses(&self) -> impl Iterator<Item = &Clause> {
+        self.0.iter().flatten()
+    }
+}
+
+/// A piece of rendered output: message text, or a resource section to be
+/// sent as its own embedded-resource message.
+#[derive(Debug)]
+pub(super) enum Segment {
+    Text(String),
+    Embed(String),
+}
+
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    Embed(String),
+    Var {
+        argument: String,
+        label: bool,
+    },
+    If {
+        condition: Condition,
+        then: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+}
+
+#[derive(Debug)]
+enum Token {
+    Text(String),
+    Var(String),
+    If(String),
+    Else,
+    EndIf,
+    Partial(String),
+    Embed(String),
+}
+
+/// Split a template into text and `{{...}}` tags. Block tags (`#if`,
+/// `else`, `/if`, `>`, `embed`) that sit alone on a line take the line with them,
+/// so they leave no blank lines behind.
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .map(|e| start + e)
+            .ok_or_else(|| "unclosed `{{`".to_string())?;
+        let tag = rest[start + 2..end].trim();
+        let token = if let Some(cond) = tag.strip_prefix("#if ") {
+            Token::If(cond.trim().to_string())
+        } else if tag == "else" {
+            Token::Else
+        } else if tag == "/if" {
+            Token::EndIf
+        } else if let Some(name) = tag.strip_prefix('>') {
+            Token::Partial(name.trim().to_string())
+        } else if let Some(uri) = tag.strip_prefix("embed ") {
+            Token::Embed(uri.trim().to_string())
+        } else {
+            Token::Var(tag.to_string())
+        };
+
+        let mut text = &rest[..start];
+        let mut after = &rest[end + 2..];
+        if !matches!(token, Token::Var(_)) {
+            let line_start = text.rfind('\n').map_or(0, |i| i + 1);
+            let before_blank = text[line_start..].chars().all(|c| c == ' ' || c == '\t');
+            let line_end = after.find('\n');
+            let after_blank = after[..line_end.unwrap_or(after.len())]
+                .chars()
+                .all(|c| c == ' ' || c == '\t' || c == '\r');
+            if before_blank && after_blank {
+                text = &text[..line_start];
+                after = &after[line_end.map_or(after.len(), |i| i + 1)..];
+            }
+        }
+        if !text.is_empty() {
+            tokens.push(Token::Text(text.to_string()));
+        }
+        tokens.push(token);
+        rest = after;
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest.to_string()));
+    }
+    Ok(tokens)
+}
+
+/// A parsed template body: text with `{{arg}}` placeholders,
+/// `{{#if ...}}` blocks and `{{embed uri#anchor}}` resource sections.
+#[derive(Debug)]
+pub(super) struct Template(Vec<Node>);
+
+impl Template {
+    /// Parse `source`, inlining `{{> name}}` from `partials`.
+    pub(super) fn parse(source: &str, partials: &HashMap<String, &str>) -> Result<Self, String> {
+        let mut tokens = Vec::new();
+        expand(source, partials, 0, &mut tokens)?;
+        let mut tokens = tokens.into_iter();
+        let (nodes, end) = parse_nodes(&mut tokens)?;
+        match end {
+            None => Ok(Self(nodes)),
+            Some(Token::Else) => Err("`{{else}}` outside `{{#if}}`".into()),
+            Some(_) => Err("`{{/if}}` without `{{#if}}`".into()),
+        }
+    }
+
+    /// Check every placeholder and condition against the declared arguments
+    /// and, where an argument has a fixed value set, against those values.
+    pub(super) fn validate(&self, arguments: &HashMap<&str, &[String]>) -> Result<(), String> {
+        fn walk(nodes: &[Node], arguments: &HashMap<&str, &[String]>) -> Result<(), String> {
+            for node in nodes {
+                match node {
+                    Node::Text(_) | Node::Embed(_) => {}
+                    Node::Var { argument, .. } => {
+                        if !arguments.contains_key(argument.as_str()) {
+                            return Err(format!("`{{{{{argument}}}}}` is not a declared argument"));
+                        }
+                    }
+                    Node::If {
+                        condition,
+                        then,
+                        otherwise,
+                    } => {
+                        for clause in condition.clauses() {
+                            let Some(values) = arguments.get(clause.argument.as_str()) else {
+                                return Err(format!(
+                                    "condition uses undeclared argument `{}`",
+                                    clause.argument
+                                ));
+                            };
+                            if let Some(bad) = clause
+                                .values
+                                .iter()
+                                .find(|v| !values.is_empty() && !values.contains(v))
+                            {
+                                return Err(format!(
+                                    "condition compares `{}` with `{bad}`, which is not one of its values",
+                                    clause.argument
+                                ));
+                            }
+                        }
+                        walk(then, arguments)?;
+                        walk(otherwise, arguments)?;
+                    }
+                }
+            }
+            Ok(())
+        }
+        walk(&self.0, arguments)
+    }
+
+    /// Every URI passed to `{{embed}}`, in either branch of any conditional.
+    pub(super) fn embeds(&self) -> Vec<&str> {
+        fn walk<'a>(nodes: &'a [Node], out: &mut Vec<&'a str>) {
+            for node in nodes {
+                match node {
+                    Node::Embed(uri) => out.push(uri),
+                    Node::If {
+                        then, otherwise, ..
+                    } => {
+                        walk(then, out);
+                        walk(otherwise, out);
+                    }
+                    Node::Text(_) | Node::Var { .. } => {}
+                }
+            }
+        }
+        let mut out = Vec::new();
+        walk(&self.0, &mut out);
+        out
+    }
+
+    pub(super) fn render(&self, args: &HashMap<String, Resolved>) -> Vec<Segment> {
+        fn push_text(out: &mut Vec<Segment>, text: &str) {
+            match out.last_mut() {
+                Some(Segment::Text(last)) => last.push_str(text),
+                _ => out.push(Segment::Text(text.to_string())),
+            }
+        }
+        fn walk(nodes: &[Node], args: &HashMap<String, Resolved>, out: &mut Vec<Segment>) {
+            for node in nodes {
+                match node {
+                    Node::Text(text) => push_text(out, text),
+                    Node::Embed(uri) => out.push(Segment::Embed(uri.clone())),
+                    Node::Var { argument, label } => {
+                        if let Some(resolved) = args.get(argument) {
+                            push_text(
+                                out,
+                                if *label {
+                                    &resolved.label
+                                } else {
+                                    &resolved.value
+                                },
+                            );
+                        }
+                    }
+                    Node::If {
+                        condition,
+                        then,
+                        otherwise,
+                    } => walk(
+                        if condition.eval(args) {
+                            then
+                        } else {
+                            otherwise
+                        },
+                        args,
+                        out,
+                    ),
+                }
+            }
+        }
+        let mut out = Vec::new();
+        walk(&self.0, args, &mut out);
+        out
+    }
+}
+
+fn expand(
+    source: &str,
+    partials: &HashMap<String, &str>,
+    depth: usize,
+    out: &mut Vec<Token>,
+) -> Result<(), String> {
+    for token in tokenize(source)? {
+        match token {
+            Token::Partial(name) => {
+                if depth >= MAX_PARTIAL_DEPTH {
+                    return Err(format!(
+                        "partials nest more than {MAX_PARTIAL_DEPTH} deep at `{name}`"
+                    ));
+                }
+                let partial = partials
+                    .get(&name)
+                    .ok_or_else(|| format!("unknown partial `{{{{> {name}}}}}`"))?;
+                expand(partial, partials, depth + 1, out)
+                    .map_err(|e| format!("in partial `{name}`: {e}"))?;
+            }
+            token => out.push(token),
+        }
+    }
+    Ok(())
+}
+
+/// Parse nodes up to the next `{{else}}`/`{{/if}}` (returned) or the end.
+fn parse_nodes(
+    tokens: &mut impl Iterator<Item = Token>,
+) -> Result<(Vec<Node>, Option<Token>), String> {
+    let mut nodes = Vec::new();
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Text(text) => nodes.push(Node::Text(text)),
+            Token::Embed(uri) => nodes.push(Node::Embed(uri)),
+            Token::Var(var) => {
+                let (argument, label) = match var.strip_suffix(".label") {
+                    Some(argument) => (argument.to_string(), true),
+                    None => (var, false),
+                };
+                nodes.push(Node::Var { argument, label });
+            }
+            Token::If(source) => {
+                let condition = Condition::parse(&source)?;
+                let (then, end) = parse_nodes(tokens)?;
+                let otherwise = match end {
+                    Some(Token::EndIf) => Vec::new(),
+                    Some(Token::Else) => match parse_nodes(tokens)? {
+                        (otherwise, Some(Token::EndIf)) => otherwise,
+                        _ => {
+                            return Err(format!("`{{{{#if {source}}}}}` is missing `{{{{/if}}}}`"));
+                        }
+                    },
+                    _ => return Err(format!("`{{{{#if {source}}}}}` is missing `{{{{/if}}}}`")),
+                };
+                nodes.push(Node::If {
+                    condition,
+                    then,
+                    otherwise,
+                });
+            }
+            end @ (Token::Else | Token::EndIf) => return Ok((nodes, Some(end))),
+            Token::Partial(_) => unreachable!("partials are expanded before parsing"),
+        }
+    }
+    Ok((nodes, None))
+}
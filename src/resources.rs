@@ -23,6 +23,117 @@ const TRANSACTION_REQUEST: &str = include_str!("../resources/rpc/transaction-req
 const RLP_EIP2718: &str = include_str!("../resources/encoding/rlp-eip2718.md");
 const BLOBS: &str = include_str!("../resources/encoding/blobs.md");
 const RECOVERED: &str = include_str!("../resources/consensus/recovered.md");
+const IMPORT_PATHS: &str = include_str!("../resources/imports/paths.md");
+const METHODS: &str = include_str!("../resources/reference/methods.md");
+
+/// A documentation set for one range of alloy releases. The latest set is the
+/// whole corpus; older sets replace the pages whose advice changed since and
+/// share the rest.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DocSet {
+    /// URI segment, as in `alloy://v0/provider/setup`.
+    pub id: &'static str,
+    /// Major alloy version the set covers.
+    pub major: u64,
+    /// Releases covered, e.g. `0.x`.
+    pub releases: &'static str,
+    /// What to keep in mind when using code generated for the latest set
+    /// with these releases. Empty for the latest set.
+    pub caveat: &'static str,
+    /// Pages that differ from the latest set, by unversioned URI.
+    overrides: &'static [(&'static str, &'static str)],
+}
+
+/// Every doc set, oldest first.
+pub const DOC_SETS: [DocSet; 2] = [
+    DocSet {
+        id: "v0",
+        major: 0,
+        releases: "0.x",
+        caveat: "This code targets alloy 1.x. Before 0.15, `ProviderBuilder` connects with \
+            `on_http`/`on_ws` instead of `connect_http`/`connect_ws` (and before 0.12 with \
+            `on_builtin` instead of `connect`); before 1.0, decoders take a `validate` flag \
+            (`abi_decode(&data, true)`), and calls return the `<name>Return` struct even for \
+            a single value (`.call().await?._0`).",
+        overrides: &[
+            (
+                "alloy://provider/setup",
+                include_str!("../resources/v0/provider/setup.md"),
+            ),
+            (
+                "alloy://sol-macro/contract-bindings",
+                include_str!("../resources/v0/sol-macro/contract-bindings.md"),
+            ),
+            (
+                "alloy://sol-macro/sol-types",
+                include_str!("../resources/v0/sol-macro/sol-types.md"),
+            ),
+            (
+                "alloy://consensus/events",
+                include_str!("../resources/v0/consensus/events.md"),
+            ),
+        ],
+    },
+    DocSet {
+        id: "v1",
+        major: 1,
+        releases: "1.x",
+        caveat: "",
+        overrides: &[],
+    },
+];
+
+/// The doc set for the newest alloy release, used when no version is given.
+pub const LATEST: &DocSet = &DOC_SETS[DOC_SETS.len() - 1];
+
+impl DocSet {
+    /// The set for an alloy version: a release (`0.15.3`), a requirement
+    /// (`^0.12`), a major version (`1`, `v0`) or `latest`. Unknown and newer
+    /// versions get the latest set.
+    pub fn for_version(version: &str) -> &'static Self {
+        let major = version
+            .trim()
+            .trim_start_matches(['^', '=', '~', '>', '<', 'v', 'V', ' '])
+            .split('.')
+            .next()
+            .and_then(|major| major.parse::<u64>().ok());
+        DOC_SETS
+            .iter()
+            .find(|set| Some(set.major) == major)
+            .unwrap_or(LATEST)
+    }
+
+    /// Unversioned URIs of the pages this set replaces.
+    pub fn overridden(&self) -> impl Iterator<Item = &'static str> {
+        self.overrides.iter().map(|(uri, _)| *uri)
+    }
+
+    /// The resources of this set indexed by unversioned URI.
+    pub fn resources(&self) -> HashMap<String, StaticResource> {
+        let mut resources = all();
+        for (uri, content) in self.overrides {
+            if let Some(resource) = resources.get_mut(*uri) {
+                resource.description =
+                    format!("{} (alloy {})", resource.description, self.releases);
+                resource.content = content.to_string();
+            }
+        }
+        resources
+    }
+}
+
+/// Split a version-qualified URI such as `alloy://v0/provider/setup#anchor`
+/// into its doc set and the unversioned `alloy://provider/setup#anchor`.
+/// `None` when the URI names no version.
+pub fn versioned(uri: &str) -> Option<(&'static DocSet, String)> {
+    let rest = uri.strip_prefix("alloy://")?;
+    let (segment, path) = rest.split_once('/')?;
+    let digits = segment.strip_prefix('v')?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((DocSet::for_version(segment), format!("alloy://{path}")))
+}
 
 fn resource(uri: &str, name: &str, description: &str, content: &str) -> StaticResource {
     StaticResource {
@@ -34,7 +145,7 @@ fn resource(uri: &str, name: &str, description: &str, content: &str) -> StaticRe
     }
 }
 
-/// Returns all static resources indexed by URI.
+/// Returns all static resources of the latest doc set indexed by URI.
 pub fn all() -> HashMap<String, StaticResource> {
     let resources = [
         resource(
@@ -115,7 +226,108 @@ pub fn all() -> HashMap<String, StaticResource> {
             "Guide to Recovered<T>, sender recovery, custom transaction type aliases, DataCompat.",
             RECOVERED,
         ),
+        resource(
+            "alloy://imports/paths",
+            "Import Paths",
+            "Where every alloy item lives: umbrella alloy:: paths, alloy-* crate paths, re-exports and the feature each module needs.",
+            IMPORT_PATHS,
+        ),
+        resource(
+            "alloy://reference/methods",
+            "Method Reference",
+            "Inherent and trait methods of TransactionRequest, Filter, ProviderBuilder, Provider, U256, Address, Bytes and PrivateKeySigner, with signatures.",
+            METHODS,
+        ),
+        resource(
+            "alloy://migration/ethers",
+            "Migrating from ethers-rs",
+            "ethers-rs to alloy mapping: types, providers, signers, transactions, contracts and utilities, with imports.",
+            &crate::migration::ethers_guide(),
+        ),
+        resource(
+            "alloy://changelog",
+            "Breaking Changes",
+            "Renamed, removed and re-signatured APIs across alloy releases, with code before and after. Read alloy://changelog/{from}..{to} for one upgrade.",
+            &crate::changelog::guide(),
+        ),
+        resource(
+            "alloy://reference/conversions",
+            "Type Conversions",
+            "From/TryFrom impls, methods, signing and encoding steps between alloy types: TransactionRequest, TypedTransaction, Signed<T>, TxEnvelope, Recovered<T>, RPC Transaction, primitives and block ids.",
+            &crate::conversions::guide(),
+        ),
+        resource(
+            "alloy://network",
+            "Network Types",
+            "What N::TxEnvelope, N::TransactionRequest, N::ReceiptResponse and the other Network associated types are for Ethereum, AnyNetwork and Optimism. Read alloy://network/{network} for one network.",
+            &crate::networks::index(),
+        ),
     ];
+    let networks = crate::networks::NETWORKS.iter().map(|network| {
+        resource(
+            &format!("alloy://network/{}", network.slug),
+            &format!("{} Network Types", network.name),
+            &format!(
+                "Concrete associated types of {} (TxEnvelope, UnsignedTx, TransactionRequest, ReceiptResponse, HeaderResponse, BlockResponse, ...) and how they relate.",
+                network.path
+            ),
+            &crate::networks::guide(network),
+        )
+    });
 
-    resources.into_iter().map(|r| (r.uri.clone(), r)).collect()
+    resources
+        .into_iter()
+        .chain(networks)
+        .map(|r| (r.uri.clone(), r))
+        .collect()
+}
+
+/// GitHub-style anchor for a markdown heading: `### Reading (call)` becomes
+/// `reading-call`.
+pub fn anchor(heading: &str) -> String {
+    heading
+        .trim_start_matches('#')
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The section of `content` under the heading whose anchor is `anchor`, up
+/// to the next heading of the same or a higher level. Includes the heading.
+pub fn section<'a>(content: &'a str, anchor: &str) -> Option<&'a str> {
+    let mut in_fence = false;
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+        } else if !in_fence && trimmed.starts_with('#') {
+            let level = trimmed.chars().take_while(|&c| c == '#').count();
+            match start {
+                Some((begin, open)) if level <= open => {
+                    return Some(content[begin..offset].trim_end());
+                }
+                None if self::anchor(trimmed) == anchor => start = Some((offset, level)),
+                _ => {}
+            }
+        }
+        offset += line.len();
+    }
+    start.map(|(begin, _)| content[begin..].trim_end())
+}
+
+/// Resolve `alloy://...` or `alloy://...#anchor` to the resource text or
+/// the named section of it.
+pub fn lookup<'a>(resources: &'a HashMap<String, StaticResource>, uri: &str) -> Option<&'a str> {
+    match uri.split_once('#') {
+        Some((base, anchor)) => section(&resources.get(base)?.content, anchor),
+        None => resources.get(uri).map(|r| r.content.as_str()),
+    }
 }
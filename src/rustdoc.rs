@@ -0,0 +1,1143 @@
+use std::{collections::HashMap, fmt::Write, fs, path::Path};
+
+use serde_json::{Map, Value};
+
+use crate::resources::StaticResource;
+
+/// Item kinds that get a page of their own.
+const PAGE_KINDS: [&str; 5] = ["struct", "enum", "union", "trait", "type_alias"];
+
+/// Reference pages generated from the rustdoc JSON of one crate.
+pub struct ApiCrate {
+    /// Crate name as published, e.g. `alloy-consensus`.
+    pub name: String,
+    /// Crate version, when the JSON records it.
+    pub version: Option<String>,
+    /// One page per public type, trait and type alias.
+    pub pages: Vec<StaticResource>,
+}
+
+/// Import the JSON files that `cargo rustdoc -- -Z unstable-options
+/// --output-format json` wrote to `dir` (usually `target/doc`). Files of
+/// crates other than alloy's are skipped; unreadable ones are logged.
+pub fn load(dir: &Path) -> Vec<ApiCrate> {
+    let mut files: Vec<_> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|e| Some(e.ok()?.path()))
+            .filter(|p| p.extension().is_some_and(|e| e == "json"))
+            .collect(),
+        Err(e) => {
+            tracing::warn!("ignoring rustdoc directory {}: {e}", dir.display());
+            return Vec::new();
+        }
+    };
+    files.sort();
+
+    let mut crates = Vec::new();
+    for path in files {
+        let doc: Value = match fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| serde_json::from_str(&text).map_err(|e| e.to_string()))
+        {
+            Ok(doc) => doc,
+            Err(e) => {
+                tracing::warn!("ignoring {}: {e}", path.display());
+                continue;
+            }
+        };
+        if !crate_name(&doc).is_some_and(|name| name == "alloy" || name.starts_with("alloy_")) {
+            continue;
+        }
+        match import(&doc) {
+            Ok(krate) => crates.push(krate),
+            Err(e) => tracing::warn!("ignoring {}: {e}", path.display()),
+        }
+    }
+    crates
+}
+
+/// Generate the pages for the crate documented by one rustdoc JSON file.
+pub fn import(doc: &Value) -> Result<ApiCrate, String> {
+    let krate = Crate::new(doc)?;
+    let mut pages = Vec::new();
+    for item in krate.index.values() {
+        let Some(kind) = PAGE_KINDS.iter().find(|k| item["inner"].get(**k).is_some()) else {
+            continue;
+        };
+        if item["crate_id"] != 0 || item["visibility"] != "public" {
+            continue;
+        }
+        // Items without a path entry are not reachable from outside.
+        let Some(path) = krate.path(&item["id"]) else {
+            continue;
+        };
+        pages.push(krate.page(item, kind, &path));
+    }
+    pages.sort_by(|a, b| a.uri.cmp(&b.uri));
+    Ok(ApiCrate {
+        name: krate.name.replace('_', "-"),
+        version: krate.version.clone(),
+        pages,
+    })
+}
+
+/// The `alloy://api` page listing every imported item by crate.
+pub fn index(crates: &[&ApiCrate]) -> StaticResource {
+    let mut content = String::from(
+        "# API Reference\n\n\
+        Generated from rustdoc JSON. Each page shows an item's definition, methods, trait \
+        implementations and the features that gate them.\n",
+    );
+    for krate in crates {
+        let _ = write!(content, "\n## {}", krate.name);
+        if let Some(version) = &krate.version {
+            let _ = write!(content, " {version}");
+        }
+        content.push_str("\n\n");
+        for page in &krate.pages {
+            let _ = writeln!(content, "- `{}` — {}", page.uri, page.description);
+        }
+    }
+    let names: Vec<&str> = crates.iter().map(|c| c.name.as_str()).collect();
+    StaticResource {
+        uri: "alloy://api".into(),
+        name: "API Reference".into(),
+        description: format!(
+            "Index of the API pages generated from rustdoc JSON: {}",
+            names.join(", ")
+        ),
+        mime_type: "text/markdown".into(),
+        content,
+    }
+}
+
+/// Name of the crate a rustdoc JSON file documents, e.g. `alloy_consensus`.
+fn crate_name(doc: &Value) -> Option<&str> {
+    doc["index"].get(key(&doc["root"]))?["name"].as_str()
+}
+
+/// Index key of an item id: a number in current formats, a string in old ones.
+fn key(id: &Value) -> String {
+    match id {
+        Value::String(id) => id.clone(),
+        id => id.to_string(),
+    }
+}
+
+fn list(value: &Value) -> impl Iterator<Item = &Value> {
+    value.as_array().into_iter().flatten()
+}
+
+/// The single `kind: {..}` entry of an externally tagged value.
+fn tagged(value: &Value) -> Option<(&str, &Value)> {
+    let (kind, inner) = value.as_object()?.iter().next()?;
+    Some((kind, inner))
+}
+
+/// A path as written in the source, minus macro-internal and
+/// crate-relative prefixes: `$crate::fmt::Result` becomes `fmt::Result`,
+/// and `_serde::__private::Result` and `crate::private::Encodable2718` keep
+/// only their last segment.
+fn path_name(path: &Value) -> String {
+    let path = path["path"]
+        .as_str()
+        .or_else(|| path["name"].as_str())
+        .unwrap_or("_");
+    let path = path.strip_prefix("$crate::").unwrap_or(path);
+    let relative = ["crate::", "super::", "self::"]
+        .iter()
+        .any(|prefix| path.starts_with(prefix));
+    if relative || path.split("::").any(|s| s.starts_with("__")) {
+        path.rsplit("::").next().unwrap_or(path).to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+/// Render a type.
+fn ty(value: &Value) -> String {
+    let Some((kind, inner)) = tagged(value) else {
+        return "_".into();
+    };
+    let mutable = inner["is_mutable"] == true;
+    match kind {
+        "generic" | "primitive" => inner.as_str().unwrap_or("_").to_string(),
+        "resolved_path" => format!("{}{}", path_name(inner), args(&inner["args"])),
+        "borrowed_ref" => {
+            let lifetime = inner["lifetime"]
+                .as_str()
+                .map(|l| format!("{l} "))
+                .unwrap_or_default();
+            let mutability = if mutable { "mut " } else { "" };
+            format!("&{lifetime}{mutability}{}", ty(&inner["type"]))
+        }
+        "raw_pointer" => format!(
+            "*{} {}",
+            if mutable { "mut" } else { "const" },
+            ty(&inner["type"])
+        ),
+        "tuple" => {
+            let items: Vec<String> = list(inner).map(ty).collect();
+            match items.as_slice() {
+                [one] => format!("({one},)"),
+                items => format!("({})", items.join(", ")),
+            }
+        }
+        "slice" => format!("[{}]", ty(inner)),
+        "array" => format!(
+            "[{}; {}]",
+            ty(&inner["type"]),
+            inner["len"].as_str().unwrap_or("_")
+        ),
+        "impl_trait" => format!("impl {}", bounds(inner)),
+        "dyn_trait" => {
+            let mut traits: Vec<String> = list(&inner["traits"])
+                .map(|t| format!("{}{}", hrtb(&t["generic_params"]), trait_path(&t["trait"])))
+                .collect();
+            traits.extend(inner["lifetime"].as_str().map(String::from));
+            format!("dyn {}", traits.join(" + "))
+        }
+        "qualified_path" => {
+            let name = inner["name"].as_str().unwrap_or("_");
+            let self_type = ty(&inner["self_type"]);
+            // The trait's path is left empty where the source wrote `I::Item`.
+            let path = match inner["trait"].is_null() {
+                true => String::new(),
+                false => trait_path(&inner["trait"]),
+            };
+            match path.is_empty() {
+                true => format!("{self_type}::{name}{}", args(&inner["args"])),
+                false => format!("<{self_type} as {path}>::{name}{}", args(&inner["args"])),
+            }
+        }
+        "function_pointer" => {
+            let sig = signature(inner);
+            let inputs: Vec<String> = list(&sig["inputs"]).map(|i| ty(&i[1])).collect();
+            format!("fn({}){}", inputs.join(", "), output(&sig["output"]))
+        }
+        "pat" => ty(&inner["type"]),
+        _ => "_".into(),
+    }
+}
+
+/// A trait path with its generic arguments.
+fn trait_path(path: &Value) -> String {
+    format!("{}{}", path_name(path), args(&path["args"]))
+}
+
+/// The `sig` of a function (`decl` before format version 35).
+fn signature(function: &Value) -> &Value {
+    function.get("sig").unwrap_or(&function["decl"])
+}
+
+fn output(output: &Value) -> String {
+    match output {
+        Value::Null => String::new(),
+        output => format!(" -> {}", ty(output)),
+    }
+}
+
+/// `<A, B, Item = C>` or `(A, B) -> C`; empty without arguments.
+fn args(args: &Value) -> String {
+    let Some((kind, inner)) = tagged(args) else {
+        return String::new();
+    };
+    match kind {
+        "angle_bracketed" => {
+            let mut items: Vec<String> = list(&inner["args"])
+                .map(|arg| match tagged(arg) {
+                    Some(("type", t)) => ty(t),
+                    Some(("lifetime", l)) => l.as_str().unwrap_or("_").to_string(),
+                    Some(("const", c)) => c["expr"].as_str().unwrap_or("_").to_string(),
+                    _ => "_".into(),
+                })
+                .collect();
+            let constraints = inner.get("constraints").unwrap_or(&inner["bindings"]);
+            items.extend(list(constraints).map(|c| {
+                let name = format!(
+                    "{}{}",
+                    c["name"].as_str().unwrap_or("_"),
+                    self::args(&c["args"])
+                );
+                match tagged(&c["binding"]) {
+                    Some(("equality", value)) => match tagged(value) {
+                        Some(("type", t)) => format!("{name} = {}", ty(t)),
+                        _ => format!("{name} = _"),
+                    },
+                    Some(("constraint", b)) => format!("{name}: {}", bounds(b)),
+                    _ => name,
+                }
+            }));
+            match items.is_empty() {
+                true => String::new(),
+                false => format!("<{}>", items.join(", ")),
+            }
+        }
+        "parenthesized" => {
+            let inputs: Vec<String> = list(&inner["inputs"]).map(ty).collect();
+            format!("({}){}", inputs.join(", "), output(&inner["output"]))
+        }
+        _ => String::new(),
+    }
+}
+
+/// `for<'a> ` for higher-ranked bounds.
+fn hrtb(params: &Value) -> String {
+    let names: Vec<&str> = list(params).filter_map(|p| p["name"].as_str()).collect();
+    match names.is_empty() {
+        true => String::new(),
+        false => format!("for<{}> ", names.join(", ")),
+    }
+}
+
+/// Trait and lifetime bounds joined with `+`.
+fn bounds(bounds: &Value) -> String {
+    list(bounds)
+        .map(|bound| match tagged(bound) {
+            Some(("trait_bound", b)) => {
+                let modifier = match b["modifier"].as_str() {
+                    Some("maybe") => "?",
+                    _ => "",
+                };
+                format!(
+                    "{}{modifier}{}",
+                    hrtb(&b["generic_params"]),
+                    trait_path(&b["trait"])
+                )
+            }
+            Some(("outlives", l)) => l.as_str().unwrap_or("_").to_string(),
+            _ => "_".into(),
+        })
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+/// Generic parameters (`<T: Bound>`, skipping the synthetic ones behind
+/// `impl Trait` arguments) and the where clause (` where T: Bound`).
+fn generics(generics: &Value) -> (String, String) {
+    let params: Vec<String> = list(&generics["params"])
+        .filter_map(|param| {
+            let name = param["name"].as_str().unwrap_or("_");
+            match tagged(&param["kind"])? {
+                ("type", t) if t["is_synthetic"] == true || t["synthetic"] == true => None,
+                ("type", t) => {
+                    let mut text = name.to_string();
+                    if list(&t["bounds"]).next().is_some() {
+                        let _ = write!(text, ": {}", bounds(&t["bounds"]));
+                    }
+                    if !t["default"].is_null() {
+                        let _ = write!(text, " = {}", ty(&t["default"]));
+                    }
+                    Some(text)
+                }
+                ("lifetime", l) => {
+                    let outlives: Vec<&str> =
+                        list(&l["outlives"]).filter_map(Value::as_str).collect();
+                    Some(match outlives.is_empty() {
+                        true => name.to_string(),
+                        false => format!("{name}: {}", outlives.join(" + ")),
+                    })
+                }
+                ("const", c) => Some(format!("const {name}: {}", ty(&c["type"]))),
+                _ => None,
+            }
+        })
+        .collect();
+    let predicates: Vec<String> = list(&generics["where_predicates"])
+        .filter_map(|predicate| match tagged(predicate)? {
+            ("bound_predicate", p) => Some(format!(
+                "{}{}: {}",
+                hrtb(&p["generic_params"]),
+                ty(&p["type"]),
+                bounds(&p["bounds"])
+            )),
+            ("lifetime_predicate", p) => {
+                let outlives: Vec<&str> = list(&p["outlives"]).filter_map(Value::as_str).collect();
+                Some(format!(
+                    "{}: {}",
+                    p["lifetime"].as_str().unwrap_or("_"),
+                    outlives.join(" + ")
+                ))
+            }
+            ("eq_predicate", p) => Some(format!(
+                "{} = {}",
+                ty(&p["lhs"]),
+                tagged(&p["rhs"]).map_or("_".into(), |(_, t)| ty(t))
+            )),
+            _ => None,
+        })
+        .collect();
+    (
+        match params.is_empty() {
+            true => String::new(),
+            false => format!("<{}>", params.join(", ")),
+        },
+        match predicates.is_empty() {
+            true => String::new(),
+            false => format!(" where {}", predicates.join(", ")),
+        },
+    )
+}
+
+/// A function signature: `pub fn name<T>(&self, value: T) -> Self`.
+fn function(item: &Value, public: bool) -> String {
+    let mut function = item["inner"]["function"].clone();
+    let is_async = desugar_async_trait(&mut function);
+    let header = &function["header"];
+    let mut text = String::from(if public { "pub " } else { "" });
+    for qualifier in ["const", "async", "unsafe"] {
+        if header[format!("is_{qualifier}")] == true || (qualifier == "async" && is_async) {
+            let _ = write!(text, "{qualifier} ");
+        }
+    }
+    let (params, predicates) = generics(&function["generics"]);
+    let sig = signature(&function);
+    let inputs: Vec<String> = list(&sig["inputs"])
+        .map(|input| {
+            let name = input[0].as_str().unwrap_or("_");
+            let t = &input[1];
+            if name != "self" {
+                return format!("{name}: {}", ty(t));
+            }
+            match tagged(t) {
+                Some(("generic", s)) if s == "Self" => "self".into(),
+                Some(("borrowed_ref", r)) if r["type"]["generic"] == "Self" => {
+                    ty(t).replace("Self", "self")
+                }
+                _ => format!("self: {}", ty(t)),
+            }
+        })
+        .collect();
+    let _ = write!(
+        text,
+        "fn {}{params}({}){}{predicates}",
+        item["name"].as_str().unwrap_or("_"),
+        inputs.join(", "),
+        output(&sig["output"])
+    );
+    text
+}
+
+/// Whether a lifetime is one `#[async_trait]` introduces.
+fn is_async_trait_lifetime(lifetime: &str) -> bool {
+    lifetime == "'async_trait" || lifetime.starts_with("'life")
+}
+
+/// Turn a method `#[async_trait]` expanded back into the `async fn` that
+/// was written: drop its `'life0`/`'async_trait` lifetimes and bounds and
+/// unwrap the `Pin<Box<dyn Future<Output = T> + Send>>` it returns.
+fn desugar_async_trait(function: &mut Value) -> bool {
+    let generics = &mut function["generics"];
+    if !list(&generics["params"]).any(|p| p["name"] == "'async_trait") {
+        return false;
+    }
+    if let Some(params) = generics["params"].as_array_mut() {
+        params.retain(|p| !p["name"].as_str().is_some_and(is_async_trait_lifetime));
+    }
+    if let Some(predicates) = generics["where_predicates"].as_array_mut() {
+        predicates.retain(|p| {
+            let text = p.to_string();
+            !text.contains("'async_trait") && !text.contains("\"'life")
+        });
+    }
+
+    fn strip_lifetimes(value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                if map
+                    .get("lifetime")
+                    .and_then(Value::as_str)
+                    .is_some_and(is_async_trait_lifetime)
+                {
+                    map.insert("lifetime".into(), Value::Null);
+                }
+                map.values_mut().for_each(strip_lifetimes);
+            }
+            Value::Array(items) => items.iter_mut().for_each(strip_lifetimes),
+            _ => {}
+        }
+    }
+    let sig = match function.get("sig") {
+        Some(_) => &mut function["sig"],
+        None => &mut function["decl"],
+    };
+    strip_lifetimes(&mut sig["inputs"]);
+
+    let boxed = &sig["output"]["resolved_path"]["args"]["angle_bracketed"]["args"][0]["type"];
+    let future = &boxed["resolved_path"]["args"]["angle_bracketed"]["args"][0]["type"];
+    let output = list(&future["dyn_trait"]["traits"])
+        .flat_map(|t| list(&t["trait"]["args"]["angle_bracketed"]["constraints"]))
+        .find(|c| c["name"] == "Output")
+        .map(|c| c["binding"]["equality"]["type"].clone());
+    if let Some(output) = output {
+        sig["output"] = output;
+    }
+    true
+}
+
+/// First paragraph of an item's docs on one line, with intra-doc link
+/// brackets and targets dropped: ``[`Filter`](crate::Filter)`` reads
+/// `` `Filter` ``.
+fn summary(docs: &Value) -> String {
+    let paragraph = docs
+        .as_str()
+        .unwrap_or_default()
+        .split("\n\n")
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut text = String::with_capacity(paragraph.len());
+    let mut in_code = false;
+    let mut chars = paragraph.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '`' => {
+                in_code = !in_code;
+                text.push(c);
+            }
+            '[' if !in_code => {}
+            ']' if !in_code => {
+                let close = match chars.peek() {
+                    Some('(') => ')',
+                    Some('[') => ']',
+                    _ => continue,
+                };
+                for c in chars.by_ref() {
+                    if c == close {
+                        break;
+                    }
+                }
+            }
+            c => text.push(c),
+        }
+    }
+    // A colon introduces an example the summary leaves out.
+    text.trim_end_matches(':').to_string()
+}
+
+/// Features named in an item's `cfg` attributes.
+fn features(item: &Value) -> Vec<String> {
+    let mut found = Vec::new();
+    for attr in list(&item["attrs"]) {
+        let Some(text) = attr.as_str().or_else(|| attr["other"].as_str()) else {
+            continue;
+        };
+        // `#[cfg(..)]` as written, or as traced by newer rustdoc versions.
+        // Negated conditions and `cfg_attr`s do not gate the item.
+        let gates = text.starts_with("#[cfg(")
+            || text.starts_with("#[doc(cfg(")
+            || text.contains("CfgTrace(");
+        if !gates || text.contains("not(") || text.contains("Not(") {
+            continue;
+        }
+        for marker in ["feature = \"", "name: \"feature\", value: Some(\""] {
+            for (pos, _) in text.match_indices(marker) {
+                let rest = &text[pos + marker.len()..];
+                if let Some(end) = rest.find('"') {
+                    let feature = rest[..end].to_string();
+                    if !found.contains(&feature) {
+                        found.push(feature);
+                    }
+                }
+            }
+        }
+    }
+    found
+}
+
+/// ` (feature `serde`)` and ` (deprecated)` suffixes for a list entry.
+fn remarks(item: &Value, inherited: &[String]) -> String {
+    let mut text = String::new();
+    let mut gates = inherited.to_vec();
+    gates.extend(
+        features(item)
+            .into_iter()
+            .filter(|f| !inherited.contains(f)),
+    );
+    if !gates.is_empty() {
+        let gates: Vec<String> = gates.iter().map(|f| format!("`{f}`")).collect();
+        let _ = write!(text, " (feature {})", gates.join(", "));
+    }
+    if !item["deprecation"].is_null() {
+        match item["deprecation"]["note"].as_str() {
+            Some(note) => {
+                let _ = write!(text, " (deprecated: {note})");
+            }
+            None => text.push_str(" (deprecated)"),
+        }
+    }
+    text
+}
+
+/// A `- `signature` — summary` list entry.
+fn entry(signature: &str, docs: &Value, remarks: &str) -> String {
+    let summary = summary(docs);
+    match summary.is_empty() {
+        true => format!("- `{signature}`{remarks}\n"),
+        false => format!("- `{signature}` — {summary}{remarks}\n"),
+    }
+}
+
+/// URI of the page for the item at `path`, e.g.
+/// `alloy://api/alloy-consensus/transaction/envelope/TxEnvelope`.
+fn page_uri(path: &[String]) -> String {
+    format!(
+        "alloy://api/{}/{}",
+        path[0].replace('_', "-"),
+        path[1..].join("/")
+    )
+}
+
+/// Kind names as shown on pages.
+fn kind_title(kind: &str) -> &'static str {
+    match kind {
+        "struct" => "Struct",
+        "enum" => "Enum",
+        "union" => "Union",
+        "trait" => "Trait",
+        _ => "Type alias",
+    }
+}
+
+/// One rustdoc JSON file, read through its item index and path table.
+struct Crate<'a> {
+    index: &'a Map<String, Value>,
+    paths: &'a Map<String, Value>,
+    /// Crate name as used in paths, e.g. `alloy_consensus`.
+    name: String,
+    version: Option<String>,
+}
+
+impl<'a> Crate<'a> {
+    fn new(doc: &'a Value) -> Result<Self, String> {
+        let index = doc["index"].as_object().ok_or("no item index")?;
+        let paths = doc["paths"].as_object().ok_or("no path table")?;
+        let name = crate_name(doc).ok_or("no crate root")?.to_string();
+        Ok(Self {
+            index,
+            paths,
+            name,
+            version: doc["crate_version"].as_str().map(String::from),
+        })
+    }
+
+    fn item(&self, id: &Value) -> Option<&'a Value> {
+        self.index.get(&key(id))
+    }
+
+    fn items(&self, ids: &Value) -> impl Iterator<Item = &'a Value> {
+        list(ids).filter_map(|id| self.item(id))
+    }
+
+    /// Public path of an item, e.g. `["alloy_consensus", "TxEnvelope"]`.
+    fn path(&self, id: &Value) -> Option<Vec<String>> {
+        let path = list(&self.paths.get(&key(id))?["path"])
+            .filter_map(|s| s.as_str().map(String::from))
+            .collect::<Vec<_>>();
+        (!path.is_empty()).then_some(path)
+    }
+
+    /// Whether a trait is defined in an alloy crate, so its methods are
+    /// worth listing on implementors' pages.
+    fn is_alloy_trait(&self, id: &Value) -> bool {
+        self.path(id)
+            .and_then(|p| p.into_iter().next())
+            .is_some_and(|root| root == "alloy" || root.starts_with("alloy_"))
+    }
+
+    fn page(&self, item: &Value, kind: &str, path: &[String]) -> StaticResource {
+        let name = item["name"].as_str().unwrap_or("_");
+        let full_path = path.join("::");
+        let krate = self.name.replace('_', "-");
+        let inner = &item["inner"][kind];
+
+        let mut content = format!(
+            "# `{name}`\n\n{} `{full_path}` in {krate}",
+            kind_title(kind)
+        );
+        if let Some(version) = &self.version {
+            let _ = write!(content, " {version}");
+        }
+        content.push('.');
+        let gates = features(item);
+        if !gates.is_empty() {
+            let gates: Vec<String> = gates.iter().map(|f| format!("`{f}`")).collect();
+            let _ = write!(content, " Requires feature {}.", gates.join(", "));
+        }
+        if !item["deprecation"].is_null() {
+            content.push_str(" Deprecated");
+            if let Some(note) = item["deprecation"]["note"].as_str() {
+                let _ = write!(content, ": {note}");
+            }
+            content.push('.');
+        }
+        let summary = summary(&item["docs"]);
+        if !summary.is_empty() {
+            let _ = write!(content, "\n\n{summary}");
+        }
+
+        let _ = write!(
+            content,
+            "\n\n## Definition\n\n```rust\n{}```\n",
+            self.definition(item, kind)
+        );
+        match kind {
+            "trait" => content.push_str(&self.trait_methods(item)),
+            "type_alias" => {
+                // Methods and impls are documented on the aliased type.
+                let target = &inner["type"]["resolved_path"]["id"];
+                if let Some(target) = self.path(target).filter(|p| p[0].starts_with("alloy")) {
+                    let _ = write!(
+                        content,
+                        "\nMethods and trait implementations: `{}`.\n",
+                        page_uri(&target)
+                    );
+                }
+            }
+            _ => content.push_str(&self.impls(inner)),
+        }
+
+        let description = match summary.split_inclusive(". ").next() {
+            Some(first) if !first.is_empty() => {
+                format!("{} `{full_path}`: {}", kind_title(kind), first.trim_end())
+            }
+            _ => format!("{} `{full_path}`", kind_title(kind)),
+        };
+        StaticResource {
+            uri: page_uri(path),
+            name: format!("{name} ({krate} API)"),
+            description,
+            mime_type: "text/markdown".into(),
+            content,
+        }
+    }
+
+    /// The item as it would be declared, with public fields and variants
+    /// and a `#[derive]` line for derived traits.
+    fn definition(&self, item: &Value, kind: &str) -> String {
+        let name = item["name"].as_str().unwrap_or("_");
+        let inner = &item["inner"][kind];
+        let (params, predicates) = generics(&inner["generics"]);
+        let mut text = String::new();
+
+        let mut derives: Vec<String> = Vec::new();
+        for imp in self.items(&inner["impls"]) {
+            if !list(&imp["attrs"]).any(|a| a.to_string().contains("automatically_derived")) {
+                continue;
+            }
+            let name = path_name(&imp["inner"]["impl"]["trait"]);
+            let name = name.rsplit("::").next().unwrap_or(&name).to_string();
+            // derive_more emits one impl per conversion.
+            if !name.starts_with("Structural") && !derives.contains(&name) {
+                derives.push(name);
+            }
+        }
+        if !derives.is_empty() {
+            let _ = writeln!(text, "#[derive({})]", derives.join(", "));
+        }
+
+        match kind {
+            "struct" => {
+                let _ = write!(text, "pub struct {name}{params}");
+                match tagged(&inner["kind"]) {
+                    Some(("plain", plain)) => {
+                        let _ = write!(text, "{predicates} {{\n{}}}\n", self.fields(plain));
+                    }
+                    Some(("tuple", fields)) => {
+                        let _ = writeln!(text, "({}){predicates};", self.tuple_fields(fields));
+                    }
+                    _ => {
+                        let _ = writeln!(text, "{predicates};");
+                    }
+                }
+            }
+            "union" => {
+                let _ = write!(
+                    text,
+                    "pub union {name}{params}{predicates} {{\n{}}}\n",
+                    self.fields(inner)
+                );
+            }
+            "enum" => {
+                let _ = writeln!(text, "pub enum {name}{params}{predicates} {{");
+                for variant in self.items(&inner["variants"]) {
+                    text.push_str(&self.variant(variant));
+                }
+                if inner["has_stripped_variants"] == true {
+                    text.push_str("    // some variants omitted\n");
+                }
+                text.push_str("}\n");
+            }
+            "trait" => {
+                let supertraits = match list(&inner["bounds"]).next() {
+                    Some(_) => format!(": {}", bounds(&inner["bounds"])),
+                    None => String::new(),
+                };
+                let unsafety = if inner["is_unsafe"] == true {
+                    "unsafe "
+                } else {
+                    ""
+                };
+                let _ = writeln!(
+                    text,
+                    "pub {unsafety}trait {name}{params}{supertraits}{predicates} {{"
+                );
+                let mut methods = 0;
+                for member in self.items(&inner["items"]) {
+                    let member_name = member["name"].as_str().unwrap_or("_");
+                    match tagged(&member["inner"]) {
+                        Some(("assoc_type", t)) => {
+                            let _ = write!(text, "    type {member_name}");
+                            if list(&t["bounds"]).next().is_some() {
+                                let _ = write!(text, ": {}", bounds(&t["bounds"]));
+                            }
+                            if !t["type"].is_null() {
+                                let _ = write!(text, " = {}", ty(&t["type"]));
+                            }
+                            text.push_str(";\n");
+                        }
+                        Some(("assoc_const", c)) => {
+                            let _ = writeln!(text, "    const {member_name}: {};", ty(&c["type"]));
+                        }
+                        Some(("function", _)) => methods += 1,
+                        _ => {}
+                    }
+                }
+                if methods > 0 {
+                    let _ = writeln!(text, "    // {methods} method(s), listed below");
+                }
+                text.push_str("}\n");
+            }
+            _ => {
+                let _ = writeln!(
+                    text,
+                    "pub type {name}{params}{predicates} = {};",
+                    ty(&inner["type"])
+                );
+            }
+        }
+        text
+    }
+
+    /// Named fields, one per line with their first doc line.
+    fn fields(&self, plain: &Value) -> String {
+        let mut text = String::new();
+        for field in self.items(&plain["fields"]) {
+            let summary = summary(&field["docs"]);
+            if !summary.is_empty() {
+                let _ = writeln!(text, "    /// {summary}");
+            }
+            let visibility = if field["visibility"] == "public" {
+                "pub "
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                text,
+                "    {visibility}{}: {},",
+                field["name"].as_str().unwrap_or("_"),
+                ty(&field["inner"]["struct_field"])
+            );
+        }
+        if plain["has_stripped_fields"] == true {
+            text.push_str("    // some fields omitted\n");
+        }
+        text
+    }
+
+    /// Tuple fields; stripped ones show as `_`.
+    fn tuple_fields(&self, fields: &Value) -> String {
+        list(fields)
+            .map(|id| match self.item(id) {
+                Some(field) => {
+                    let visibility = if field["visibility"] == "public" {
+                        "pub "
+                    } else {
+                        ""
+                    };
+                    format!("{visibility}{}", ty(&field["inner"]["struct_field"]))
+                }
+                None => "_".into(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn variant(&self, variant: &Value) -> String {
+        let mut text = String::new();
+        let summary = summary(&variant["docs"]);
+        if !summary.is_empty() {
+            let _ = writeln!(text, "    /// {summary}");
+        }
+        let name = variant["name"].as_str().unwrap_or("_");
+        let inner = &variant["inner"]["variant"];
+        match tagged(&inner["kind"]) {
+            Some(("tuple", fields)) => {
+                let _ = write!(text, "    {name}({})", self.tuple_fields(fields));
+            }
+            Some(("struct", fields)) => {
+                let body = self.fields(fields).replace("\n    ", "\n        ");
+                let _ = write!(text, "    {name} {{\n    {}    }}", body);
+            }
+            _ => {
+                let _ = write!(text, "    {name}");
+            }
+        }
+        if let Some(value) = inner["discriminant"]["expr"].as_str() {
+            let _ = write!(text, " = {value}");
+        }
+        text.push_str(",\n");
+        text
+    }
+
+    /// `impl<T: Bound> Trait<A> for Type<T> where ..`.
+    fn impl_header(&self, imp: &Value) -> String {
+        let (params, predicates) = generics(&imp["generics"]);
+        let negative = if imp["is_negative"] == true { "!" } else { "" };
+        match imp["trait"].is_null() {
+            true => format!("impl{params} {}{predicates}", ty(&imp["for"])),
+            false => format!(
+                "impl{params} {negative}{} for {}{predicates}",
+                trait_path(&imp["trait"]),
+                ty(&imp["for"])
+            ),
+        }
+    }
+
+    /// `## Methods` from inherent impls and `## Trait Implementations`,
+    /// with the methods of alloy traits listed under each impl.
+    fn impls(&self, inner: &Value) -> String {
+        let impls: Vec<(&Value, &Value)> = self
+            .items(&inner["impls"])
+            .filter_map(|item| Some((item, item["inner"].get("impl")?)))
+            .filter(|(_, imp)| imp["is_synthetic"] != true && imp["blanket_impl"].is_null())
+            .collect();
+
+        let mut blocks = Vec::new();
+        for (item, imp) in impls.iter().filter(|(_, imp)| imp["trait"].is_null()) {
+            let gates = features(item);
+            let mut entries = String::new();
+            for member in self.items(&imp["items"]) {
+                if member["visibility"] != "public" {
+                    continue;
+                }
+                let signature = match tagged(&member["inner"]) {
+                    Some(("function", _)) => function(member, true),
+                    Some(("assoc_const", c)) => format!(
+                        "pub const {}: {}",
+                        member["name"].as_str().unwrap_or("_"),
+                        ty(&c["type"])
+                    ),
+                    _ => continue,
+                };
+                entries.push_str(&entry(
+                    &signature,
+                    &member["docs"],
+                    &remarks(member, &gates),
+                ));
+            }
+            if !entries.is_empty() {
+                let (params, predicates) = generics(&imp["generics"]);
+                let bounded = params.contains(": ") || !predicates.is_empty();
+                blocks.push((self.impl_header(imp), bounded, entries));
+            }
+        }
+        // Say which impl methods come from once any impl adds bounds.
+        let labeled = blocks.iter().any(|(_, bounded, _)| *bounded);
+        let mut methods = String::new();
+        for (header, _, entries) in blocks {
+            if labeled {
+                let _ = write!(methods, "\nFrom `{header}`:\n\n");
+            }
+            methods.push_str(&entries);
+        }
+
+        let mut listed = String::new();
+        let mut details = String::new();
+        for (item, imp) in impls.iter().filter(|(_, imp)| !imp["trait"].is_null()) {
+            if list(&item["attrs"]).any(|a| a.to_string().contains("automatically_derived")) {
+                continue;
+            }
+            let header = self.impl_header(imp);
+            let gates = features(item);
+            let assoc: Vec<String> = self
+                .items(&imp["items"])
+                .filter_map(|member| {
+                    let t = &member["inner"].get("assoc_type")?["type"];
+                    (!t.is_null()).then(|| {
+                        format!(
+                            "`type {} = {}`",
+                            member["name"].as_str().unwrap_or("_"),
+                            ty(t)
+                        )
+                    })
+                })
+                .collect();
+            let _ = write!(listed, "- `{header}`");
+            if !assoc.is_empty() {
+                let _ = write!(listed, " — {}", assoc.join(", "));
+            }
+            let _ = writeln!(listed, "{}", remarks(item, &[]));
+
+            if !self.is_alloy_trait(&imp["trait"]["id"]) {
+                continue;
+            }
+            let docs = self.trait_docs(&imp["trait"]["id"]);
+            let mut entries = String::new();
+            for member in self.items(&imp["items"]) {
+                if member["inner"].get("function").is_none() {
+                    continue;
+                }
+                let name = member["name"].as_str().unwrap_or("_");
+                let own = &member["docs"];
+                let docs = match own.is_null() {
+                    true => docs.get(name).copied().unwrap_or(own),
+                    false => own,
+                };
+                entries.push_str(&entry(
+                    &function(member, false),
+                    docs,
+                    &remarks(member, &gates),
+                ));
+            }
+            if !entries.is_empty() {
+                let _ = write!(details, "\n### `{header}`\n\n{entries}");
+            }
+        }
+
+        let mut text = String::new();
+        if !methods.is_empty() {
+            let _ = write!(text, "\n## Methods\n\n{}", methods.trim_start());
+        }
+        if !listed.is_empty() || !details.is_empty() {
+            let _ = write!(text, "\n## Trait Implementations\n\n{listed}{details}");
+        }
+        text
+    }
+
+    /// Docs of a local trait's methods by name, for impls that do not
+    /// repeat them.
+    fn trait_docs(&self, id: &Value) -> HashMap<&'a str, &'a Value> {
+        self.item(id)
+            .map(|t| self.items(&t["inner"]["trait"]["items"]))
+            .into_iter()
+            .flatten()
+            .filter_map(|member| Some((member["name"].as_str()?, &member["docs"])))
+            .collect()
+    }
+
+    /// `## Required Methods`, `## Provided Methods` and `## Implementors`
+    /// of a trait.
+    fn trait_methods(&self, item: &Value) -> String {
+        let inner = &item["inner"]["trait"];
+        let gates = features(item);
+        let (mut required, mut provided) = (String::new(), String::new());
+        for member in self.items(&inner["items"]) {
+            let Some(function) = member["inner"].get("function") else {
+                continue;
+            };
+            let line = entry(
+                &self::function(member, false),
+                &member["docs"],
+                &remarks(member, &gates),
+            );
+            match function["has_body"] == true {
+                true => provided.push_str(&line),
+                false => required.push_str(&line),
+            }
+        }
+        let implementors: String = self
+            .items(&inner["implementations"])
+            .filter_map(|i| {
+                let imp = i["inner"].get("impl")?;
+                Some(format!(
+                    "- `{}`{}\n",
+                    self.impl_header(imp),
+                    remarks(i, &[])
+                ))
+            })
+            .collect();
+
+        let mut text = String::new();
+        for (title, body) in [
+            ("Required Methods", required),
+            ("Provided Methods", provided),
+            ("Implementors", implementors),
+        ] {
+            if !body.is_empty() {
+                let _ = write!(text, "\n## {title}\n\n{body}");
+            }
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `cargo rustdoc` JSON of alloy-rpc-types-eth 1.8.3 (format 57), trimmed
+    /// to the filter types.
+    const FIXTURE: &str = include_str!("../tests/fixtures/alloy_rpc_types_eth.json");
+
+    fn fixture() -> ApiCrate {
+        import(&serde_json::from_str(FIXTURE).unwrap()).unwrap()
+    }
+
+    fn page<'a>(krate: &'a ApiCrate, uri: &str) -> &'a str {
+        let page = krate.pages.iter().find(|p| p.uri == uri);
+        &page.unwrap_or_else(|| panic!("no page {uri}")).content
+    }
+
+    #[test]
+    fn imports_a_page_per_public_type() {
+        let krate = fixture();
+        assert_eq!(krate.name, "alloy-rpc-types-eth");
+        assert_eq!(krate.version.as_deref(), Some("1.8.3"));
+        let uris: Vec<&str> = krate.pages.iter().map(|p| p.uri.as_str()).collect();
+        assert_eq!(
+            uris,
+            [
+                "alloy://api/alloy-rpc-types-eth/filter/Filter",
+                "alloy://api/alloy-rpc-types-eth/filter/FilterBlockOption",
+                "alloy://api/alloy-rpc-types-eth/filter/FilterSet",
+                "alloy://api/alloy-rpc-types-eth/filter/Topic",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_definitions_and_method_signatures() {
+        let krate = fixture();
+        let filter = page(&krate, "alloy://api/alloy-rpc-types-eth/filter/Filter");
+        assert!(filter.contains("pub struct Filter {\n"));
+        assert!(filter.contains("    pub block_option: FilterBlockOption,\n"));
+        assert!(filter.contains(
+            "- `pub fn from_block<T: Into<BlockNumberOrTag>>(self, block: T) -> Self` — Sets the from block number\n"
+        ));
+        let topic = page(&krate, "alloy://api/alloy-rpc-types-eth/filter/Topic");
+        assert!(topic.contains("pub type Topic = FilterSet<alloy_primitives::B256>;"));
+        assert!(topic.contains("`alloy://api/alloy-rpc-types-eth/filter/FilterSet`"));
+    }
+
+    #[test]
+    fn marks_feature_gates() {
+        let krate = fixture();
+        let filter = page(&krate, "alloy://api/alloy-rpc-types-eth/filter/Filter");
+        assert!(filter.contains("- `impl Serialize for Filter` (feature `serde`)\n"));
+        let set = page(&krate, "alloy://api/alloy-rpc-types-eth/filter/FilterSet");
+        assert!(set.contains("- `pub fn bloom_filter_ref(&self) -> &BloomFilter` — "));
+        assert!(set.contains("(feature `std`)\n"));
+    }
+
+    #[test]
+    fn load_reads_alloy_crates_from_a_directory() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let crates = load(&dir);
+        assert_eq!(crates.len(), 1);
+        let index = index(&crates.iter().collect::<Vec<_>>());
+        assert!(index.content.contains("## alloy-rpc-types-eth 1.8.3\n"));
+    }
+}
@@ -1,33 +1,92 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, RwLock},
+};
 
 use rmcp::{
     ErrorData, RoleServer, ServerHandler,
     handler::server::{
         prompt::PromptContext,
         router::{prompt::PromptRouter, tool::ToolRouter},
+        tool::ToolCallContext,
     },
     model::{
-        Annotated, GetPromptRequestParams, GetPromptResult, ListPromptsResult,
-        ListResourceTemplatesResult, ListResourcesResult, PaginatedRequestParams,
-        ReadResourceRequestParams, ReadResourceResult, ResourceContents, ServerCapabilities,
-        ServerInfo,
+        Annotated, CallToolRequestParams, CallToolResult, CompleteRequestParams, CompleteResult,
+        CompletionInfo, GetPromptRequestParams, GetPromptResult, ListPromptsResult,
+        ListResourceTemplatesResult, ListResourcesResult, ListToolsResult, PaginatedRequestParams,
+        RawContent, ReadResourceRequestParams, ReadResourceResult, Reference, ResourceContents,
+        ServerCapabilities, ServerInfo,
     },
-    service::RequestContext,
+    service::{NotificationContext, RequestContext},
+};
+
+use crate::{
+    changelog::{self, Version},
+    networks,
+    prompts::PromptTemplate,
+    resources::{self, DocSet, StaticResource},
+    rustdoc,
+    workspace::Workspace,
 };
 
-use crate::resources::StaticResource;
+/// URI template for per-type documentation lookups.
+const TYPE_TEMPLATE: &str = "alloy://type/{type_name}";
+
+/// URI template for the breaking changes between two alloy versions.
+const CHANGELOG_TEMPLATE: &str = "alloy://changelog/{from}..{to}";
+
+/// URI template for one network's associated types.
+const NETWORK_TEMPLATE: &str = "alloy://network/{network}";
+
+/// Tool argument, accepted by every tool, that picks the doc set.
+const VERSION_ARGUMENT: &str = "alloy_version";
+
+/// Tools that answer from the documentation or the changelog, so their
+/// output already matches the chosen doc set or spans several releases.
+const DOC_TOOLS: [&str; 4] = [
+    "lookup_type",
+    "search_resources",
+    "get_resource",
+    "alloy_changes",
+];
+
+/// Rank completion candidates against what the user has typed so far:
+/// case-insensitive prefix matches first, then substring matches.
+fn completion(candidates: impl IntoIterator<Item = String>, typed: &str) -> CompletionInfo {
+    let typed = typed.to_lowercase();
+    let (mut prefix, mut substring): (Vec<String>, Vec<String>) = candidates
+        .into_iter()
+        .filter(|c| c.to_lowercase().contains(&typed))
+        .partition(|c| c.to_lowercase().starts_with(&typed));
+    prefix.append(&mut substring);
+
+    let total = prefix.len();
+    prefix.truncate(CompletionInfo::MAX_VALUES);
+    CompletionInfo {
+        total: Some(total as u32),
+        has_more: Some(total > prefix.len()),
+        values: prefix,
+    }
+}
 
 /// The alloy MCP server handler.
 #[derive(Clone)]
 pub struct AlloyMcpServer {
-    /// Static resources indexed by URI.
-    pub(crate) resources: HashMap<String, StaticResource>,
-    /// Tool router for handling tool calls (read by generated macro code).
-    #[allow(dead_code)]
-    tool_router: ToolRouter<Self>,
+    /// Static resources of the doc set in use, indexed by unversioned URI.
+    pub(crate) resources: Arc<HashMap<String, StaticResource>>,
+    /// Resources of every doc set, by set id.
+    doc_sets: Arc<HashMap<&'static str, Arc<HashMap<String, StaticResource>>>>,
+    /// Tool router for handling tool calls.
+    tool_router: Arc<ToolRouter<Self>>,
+    /// Prompt templates indexed by name.
+    pub(crate) prompts: Arc<HashMap<String, Arc<PromptTemplate>>>,
     /// Prompt router for handling prompt requests.
-    #[allow(dead_code)]
-    prompt_router: PromptRouter<Self>,
+    prompt_router: Arc<PromptRouter<Self>>,
+    /// The client's project, when found through `--workspace` or MCP roots.
+    workspace: Arc<RwLock<Option<Workspace>>>,
+    /// Whether `--workspace` set the project, which MCP roots then leave alone.
+    workspace_pinned: bool,
 }
 
 impl Default for AlloyMcpServer {
@@ -38,18 +97,144 @@ impl Default for AlloyMcpServer {
 
 impl AlloyMcpServer {
     pub fn new() -> Self {
+        let doc_sets: HashMap<_, _> = resources::DOC_SETS
+            .iter()
+            .map(|set| (set.id, Arc::new(set.resources())))
+            .collect();
+        let resources = doc_sets[resources::LATEST.id].clone();
+        let prompts = crate::prompts::all(&resources);
+        Self {
+            resources,
+            doc_sets: Arc::new(doc_sets),
+            tool_router: Arc::new(Self::create_tool_router()),
+            prompt_router: Arc::new(Self::create_prompt_router(&prompts)),
+            prompts: Arc::new(prompts),
+            workspace: Arc::default(),
+            workspace_pinned: false,
+        }
+    }
+
+    /// A server that tailors its answers to the project at `path`, a
+    /// directory or its `Cargo.toml`.
+    pub fn with_workspace(path: &Path) -> Self {
+        let workspace = Workspace::load(path);
+        match &workspace {
+            Some(w) => tracing::info!("workspace {}: {}", w.root.display(), w.summary()),
+            None => tracing::warn!("no alloy dependency found in {}", path.display()),
+        }
         Self {
-            resources: crate::resources::all(),
-            tool_router: Self::create_tool_router(),
-            prompt_router: Self::create_prompt_router(),
+            workspace: Arc::new(RwLock::new(workspace)),
+            workspace_pinned: true,
+            ..Self::new()
         }
     }
+
+    /// Add API reference pages generated from the rustdoc JSON files in
+    /// `dir`. Each crate's pages join the doc set of its version, along with
+    /// an `alloy://api` index.
+    pub fn with_rustdoc(self, dir: &Path) -> Self {
+        let crates = rustdoc::load(dir);
+        let pages: usize = crates.iter().map(|c| c.pages.len()).sum();
+        tracing::info!(
+            "{pages} API pages from {} crate(s) in {}",
+            crates.len(),
+            dir.display()
+        );
+        let mut doc_sets: HashMap<_, _> = self
+            .doc_sets
+            .iter()
+            .map(|(id, resources)| (*id, (**resources).clone()))
+            .collect();
+        for set in &resources::DOC_SETS {
+            let members: Vec<&rustdoc::ApiCrate> = crates
+                .iter()
+                .filter(|c| {
+                    let doc_set = c
+                        .version
+                        .as_deref()
+                        .map_or(resources::LATEST, DocSet::for_version);
+                    doc_set.id == set.id
+                })
+                .collect();
+            if members.is_empty() {
+                continue;
+            }
+            let resources = doc_sets.entry(set.id).or_default();
+            let index = rustdoc::index(&members);
+            resources.insert(index.uri.clone(), index);
+            for page in members.iter().flat_map(|c| &c.pages) {
+                resources.insert(page.uri.clone(), page.clone());
+            }
+        }
+        let doc_sets: HashMap<_, _> = doc_sets
+            .into_iter()
+            .map(|(id, resources)| (id, Arc::new(resources)))
+            .collect();
+        Self {
+            resources: doc_sets[resources::LATEST.id].clone(),
+            doc_sets: Arc::new(doc_sets),
+            ..self
+        }
+    }
+
+    /// The client's project, if known.
+    pub(crate) fn workspace(&self) -> Option<Workspace> {
+        self.workspace.read().ok()?.clone()
+    }
+
+    /// The doc set for requests that name no alloy version: the one for
+    /// the client's project, else the latest.
+    fn default_doc_set(&self) -> &'static DocSet {
+        self.workspace()
+            .and_then(|w| w.version)
+            .map_or(resources::LATEST, |v| DocSet::for_version(&v))
+    }
+
+    /// This server answering from the resources of `doc_set`. Every field
+    /// is shared, so this is cheap enough to do per tool call.
+    fn with_doc_set(&self, doc_set: &'static DocSet) -> Self {
+        Self {
+            resources: self.doc_sets[doc_set.id].clone(),
+            ..self.clone()
+        }
+    }
+
+    /// Look for the project in the client's MCP roots. Runs in the
+    /// background: the roots request needs the service loop this
+    /// notification handler runs on.
+    fn refresh_roots(&self, context: NotificationContext<RoleServer>) {
+        let supports_roots = context
+            .peer
+            .peer_info()
+            .is_some_and(|info| info.capabilities.roots.is_some());
+        if self.workspace_pinned || !supports_roots {
+            return;
+        }
+        let workspace = self.workspace.clone();
+        tokio::spawn(async move {
+            let roots = match context.peer.list_roots().await {
+                Ok(result) => result.roots,
+                Err(e) => {
+                    tracing::warn!("roots/list failed: {e}");
+                    return;
+                }
+            };
+            let found = Workspace::from_roots(roots.iter().map(|r| r.uri.as_str()));
+            if let Some(w) = &found {
+                tracing::info!("workspace {}: {}", w.root.display(), w.summary());
+            }
+            if let Ok(mut current) = workspace.write() {
+                *current = found;
+            }
+        });
+    }
 }
 
 impl ServerHandler for AlloyMcpServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             capabilities: ServerCapabilities::builder()
+                .enable_completions()
                 .enable_resources()
                 .enable_tools()
                 .enable_prompts()
@@ -66,12 +251,21 @@ impl ServerHandler for AlloyMcpServer {
         _request: Option<PaginatedRequestParams>,
         _context: RequestContext<RoleServer>,
     ) -> impl Future<Output = Result<ListResourcesResult, ErrorData>> + Send + '_ {
+        let versioned = resources::DOC_SETS.iter().flat_map(|set| {
+            set.overridden().filter_map(move |uri| {
+                let resource = self.doc_sets[set.id].get(uri)?;
+                let uri = uri.replacen("alloy://", &format!("alloy://{}/", set.id), 1);
+                Some((uri, resource))
+            })
+        });
         let resources = self
             .resources
             .values()
-            .map(|r| Annotated {
+            .map(|r| (r.uri.clone(), r))
+            .chain(versioned)
+            .map(|(uri, r)| Annotated {
                 raw: rmcp::model::RawResource {
-                    uri: r.uri.clone(),
+                    uri,
                     name: r.name.clone(),
                     title: None,
                     description: Some(r.description.clone()),
@@ -95,12 +289,51 @@ impl ServerHandler for AlloyMcpServer {
         request: ReadResourceRequestParams,
         _context: RequestContext<RoleServer>,
     ) -> impl Future<Output = Result<ReadResourceResult, ErrorData>> + Send + '_ {
-        let result = match self.resources.get(&request.uri) {
-            Some(resource) => Ok(ReadResourceResult {
+        let (doc_set, uri) = resources::versioned(&request.uri)
+            .unwrap_or_else(|| (self.default_doc_set(), request.uri.clone()));
+        let server = self.with_doc_set(doc_set);
+        let type_name = uri.strip_prefix("alloy://type/");
+        let range = uri
+            .strip_prefix("alloy://changelog/")
+            .and_then(|range| range.split_once(".."))
+            .and_then(|(from, to)| Some((Version::parse(from)?, Version::parse(to)?)));
+        // `alloy://network/Optimism` and `alloy://network/base` read the
+        // network's page under its slug.
+        let network = uri
+            .strip_prefix("alloy://network/")
+            .and_then(networks::find);
+        let result = match resources::lookup(&server.resources, &uri) {
+            Some(text) => Ok(ReadResourceResult {
+                contents: vec![ResourceContents::TextResourceContents {
+                    uri: request.uri.clone(),
+                    mime_type: Some("text/markdown".into()),
+                    text: text.to_string(),
+                    meta: None,
+                }],
+            }),
+            None if type_name.is_some_and(|t| !t.is_empty()) => Ok(ReadResourceResult {
                 contents: vec![ResourceContents::TextResourceContents {
-                    uri: resource.uri.clone(),
-                    mime_type: Some(resource.mime_type.clone()),
-                    text: resource.content.clone(),
+                    uri: request.uri.clone(),
+                    mime_type: Some("text/markdown".into()),
+                    text: server.type_lookup(type_name.unwrap_or_default()),
+                    meta: None,
+                }],
+            }),
+            None if network.is_some() => Ok(ReadResourceResult {
+                contents: vec![ResourceContents::TextResourceContents {
+                    uri: request.uri.clone(),
+                    mime_type: Some("text/markdown".into()),
+                    text: network.map(networks::guide).unwrap_or_default(),
+                    meta: None,
+                }],
+            }),
+            None if range.is_some() => Ok(ReadResourceResult {
+                contents: vec![ResourceContents::TextResourceContents {
+                    uri: request.uri.clone(),
+                    mime_type: Some("text/markdown".into()),
+                    text: range
+                        .map(|(from, to)| changelog::range_guide(from, to))
+                        .unwrap_or_default(),
                     meta: None,
                 }],
             }),
@@ -119,21 +352,196 @@ impl ServerHandler for AlloyMcpServer {
         _context: RequestContext<RoleServer>,
     ) -> impl Future<Output = Result<ListResourceTemplatesResult, ErrorData>> + Send + '_ {
         std::future::ready(Ok(ListResourceTemplatesResult {
-            resource_templates: vec![Annotated {
-                raw: rmcp::model::RawResourceTemplate {
-                    uri_template: "alloy://type/{type_name}".to_string(),
-                    name: "Type Lookup".to_string(),
-                    title: None,
-                    description: Some("Look up a specific alloy type by name".to_string()),
-                    mime_type: None,
-                    icons: None,
+            resource_templates: vec![
+                Annotated {
+                    raw: rmcp::model::RawResourceTemplate {
+                        uri_template: TYPE_TEMPLATE.to_string(),
+                        name: "Type Lookup".to_string(),
+                        title: None,
+                        description: Some("Look up a specific alloy type by name".to_string()),
+                        mime_type: Some("text/markdown".to_string()),
+                        icons: None,
+                    },
+                    annotations: None,
                 },
-                annotations: None,
-            }],
+                Annotated {
+                    raw: rmcp::model::RawResourceTemplate {
+                        uri_template: CHANGELOG_TEMPLATE.to_string(),
+                        name: "Breaking Changes Between Versions".to_string(),
+                        title: None,
+                        description: Some(
+                            "Renamed, removed and re-signatured alloy APIs between two versions, e.g. alloy://changelog/0.11..1.0"
+                                .to_string(),
+                        ),
+                        mime_type: Some("text/markdown".to_string()),
+                        icons: None,
+                    },
+                    annotations: None,
+                },
+                Annotated {
+                    raw: rmcp::model::RawResourceTemplate {
+                        uri_template: NETWORK_TEMPLATE.to_string(),
+                        name: "Network Types".to_string(),
+                        title: None,
+                        description: Some(
+                            "Concrete associated types of an alloy network, e.g. alloy://network/optimism or alloy://network/any"
+                                .to_string(),
+                        ),
+                        mime_type: Some("text/markdown".to_string()),
+                        icons: None,
+                    },
+                    annotations: None,
+                },
+            ],
             ..Default::default()
         }))
     }
 
+    fn complete(
+        &self,
+        request: CompleteRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> impl Future<Output = Result<CompleteResult, ErrorData>> + Send + '_ {
+        let argument = &request.argument;
+        let candidates: Vec<String> = match &request.r#ref {
+            Reference::Prompt(prompt) => self
+                .prompts
+                .get(&prompt.name)
+                .map(|t| t.argument_values(&argument.name).to_vec())
+                .unwrap_or_default(),
+            Reference::Resource(resource)
+                if resource.uri == TYPE_TEMPLATE && argument.name == "type_name" =>
+            {
+                self.type_symbols().into_iter().collect()
+            }
+            Reference::Resource(resource)
+                if resource.uri == CHANGELOG_TEMPLATE
+                    && (argument.name == "from" || argument.name == "to") =>
+            {
+                changelog::releases()
+                    .into_iter()
+                    .map(String::from)
+                    .chain(std::iter::once("latest".to_string()))
+                    .collect()
+            }
+            Reference::Resource(resource)
+                if resource.uri == NETWORK_TEMPLATE && argument.name == "network" =>
+            {
+                networks::NETWORKS
+                    .iter()
+                    .map(|n| n.slug.to_string())
+                    .collect()
+            }
+            Reference::Resource(_) => Vec::new(),
+        };
+
+        std::future::ready(Ok(CompleteResult {
+            completion: completion(candidates, &argument.value),
+        }))
+    }
+
+    fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> impl Future<Output = Result<ListToolsResult, ErrorData>> + Send + '_ {
+        let mut tools = self.tool_router.list_all();
+        let versions: Vec<String> = resources::DOC_SETS
+            .iter()
+            .map(|set| format!("{} ({})", set.id, set.releases))
+            .collect();
+        let property = serde_json::json!({
+            "type": ["string", "number"],
+            "description": format!(
+                "alloy release the answer should target, e.g. \"0.15\" or \"1.0.9\". Doc sets: {}. \
+                Defaults to the client's project version, else the latest.",
+                versions.join(", ")
+            ),
+        });
+        for tool in &mut tools {
+            let schema = Arc::make_mut(&mut tool.input_schema);
+            if let Some(properties) = schema
+                .entry("properties")
+                .or_insert_with(|| serde_json::json!({}))
+                .as_object_mut()
+            {
+                properties.insert(VERSION_ARGUMENT.into(), property.clone());
+            }
+        }
+        std::future::ready(Ok(ListToolsResult {
+            tools,
+            ..Default::default()
+        }))
+    }
+
+    fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> impl Future<Output = Result<CallToolResult, ErrorData>> + Send + '_ {
+        let mut request = request;
+        let version = request
+            .arguments
+            .as_mut()
+            .and_then(|arguments| arguments.remove(VERSION_ARGUMENT));
+        async move {
+            // A bare JSON number such as `0` or `1.0` names a version too.
+            let doc_set = match version {
+                None | Some(serde_json::Value::Null) => self.default_doc_set(),
+                Some(serde_json::Value::String(version)) => DocSet::for_version(&version),
+                Some(serde_json::Value::Number(version)) => {
+                    DocSet::for_version(&version.to_string())
+                }
+                Some(other) => {
+                    return Err(ErrorData::invalid_params(
+                        format!(
+                            "`{VERSION_ARGUMENT}` must be a version string or number, got {other}"
+                        ),
+                        None,
+                    ));
+                }
+            };
+            let server = self.with_doc_set(doc_set);
+            let caveat = !doc_set.caveat.is_empty() && !DOC_TOOLS.contains(&&*request.name);
+            let tool_context = ToolCallContext::new(&server, request, context);
+            let mut result = server.tool_router.call(tool_context).await?;
+            let workspace = server.workspace();
+            for content in &mut result.content {
+                let RawContent::Text(text) = &mut content.raw else {
+                    continue;
+                };
+                if let Some(workspace) = &workspace {
+                    text.text = server.tailor(workspace, std::mem::take(&mut text.text));
+                }
+                if caveat && text.text.contains("```rust") {
+                    text.text = format!(
+                        "{}\n\n> **alloy {}:** {}\n",
+                        text.text.trim_end(),
+                        doc_set.releases,
+                        doc_set.caveat
+                    );
+                }
+            }
+            Ok(result)
+        }
+    }
+
+    fn on_initialized(
+        &self,
+        context: NotificationContext<RoleServer>,
+    ) -> impl Future<Output = ()> + Send + '_ {
+        self.refresh_roots(context);
+        std::future::ready(())
+    }
+
+    fn on_roots_list_changed(
+        &self,
+        context: NotificationContext<RoleServer>,
+    ) -> impl Future<Output = ()> + Send + '_ {
+        self.refresh_roots(context);
+        std::future::ready(())
+    }
+
     fn list_prompts(
         &self,
         _request: Option<PaginatedRequestParams>,
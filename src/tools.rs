@@ -1,6 +1,28 @@
+use std::collections::BTreeSet;
+
 use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
 
-use crate::server::AlloyMcpServer;
+use crate::{resources::StaticResource, server::AlloyMcpServer};
+
+mod abi;
+mod address;
+mod changes;
+mod codegen;
+mod conversions;
+mod eip712;
+mod features;
+mod imports;
+mod lint;
+mod methods;
+mod migrate;
+mod networks;
+mod project;
+mod scaffold;
+mod signature;
+mod sol;
+mod sol_type;
+mod transaction_code;
+mod units;
 
 /// A section extracted from a resource markdown file.
 struct Section {
@@ -109,6 +131,101 @@ impl AlloyMcpServer {
         }
         sections
     }
+
+    /// The most relevant documentation sections for a type name, as served by
+    /// `lookup_type` and the `alloy://type/{type_name}` resource template.
+    pub(crate) fn type_lookup(&self, type_name: &str) -> String {
+        // An API page named exactly after the type comes first, whole.
+        let mut pages: Vec<&StaticResource> = self
+            .resources
+            .values()
+            .filter(|r| {
+                r.uri.starts_with("alloy://api/") && r.uri.rsplit('/').next() == Some(type_name)
+            })
+            .collect();
+        pages.sort_by(|a, b| a.uri.cmp(&b.uri));
+
+        let sections = self.all_sections();
+        let mut scored: Vec<(u32, &Section)> = sections
+            .iter()
+            // The API index lists every name; the pages themselves are better.
+            .filter(|s| s.uri != "alloy://api")
+            .filter(|s| !pages.iter().any(|p| p.uri == s.uri))
+            .filter_map(|s| {
+                let score = score_section(s, type_name);
+                if score > 0 { Some((score, s)) } else { None }
+            })
+            .collect();
+
+        scored.sort_by_key(|s| std::cmp::Reverse(s.0));
+        scored.truncate(3_usize.saturating_sub(pages.len()).max(1));
+
+        if scored.is_empty() && pages.is_empty() {
+            let uris: Vec<String> = self
+                .resources
+                .values()
+                .map(|r| format!("  - {} ({})", r.uri, r.name))
+                .collect();
+            format!(
+                "No documentation found for '{}'. Available resources:\n{}",
+                type_name,
+                uris.join("\n")
+            )
+        } else {
+            let mut result = format!("# Results for '{}'\n\n", type_name);
+            for page in pages {
+                result.push_str(&format!(
+                    "---\n**{}** (API page)\nURI: {}\n\n{}\n\n",
+                    page.name, page.uri, page.content
+                ));
+            }
+            for (score, section) in scored {
+                result.push_str(&format!(
+                    "---\n**{}** — {} (relevance: {})\nURI: {}\n\n{}\n\n",
+                    section.heading.trim_start_matches('#').trim(),
+                    section.resource_name,
+                    score,
+                    section.uri,
+                    section.content
+                ));
+            }
+            result
+        }
+    }
+
+    /// Type names `alloy://type/{type_name}` can resolve, for completion:
+    /// the types in the imports map's `Items:` and `Re-exports:` lists, plus
+    /// the names of any imported API pages. Functions and macros in those
+    /// lists start lowercase or end in `!` and are left out.
+    pub(crate) fn type_symbols(&self) -> BTreeSet<String> {
+        let is_type = |name: &str| {
+            name.starts_with(|c: char| c.is_ascii_uppercase())
+                && name.chars().all(|c| c.is_ascii_alphanumeric())
+        };
+        let mut symbols = BTreeSet::new();
+        if let Some(imports) = self.resources.get("alloy://imports/paths") {
+            let lists = imports.content.lines().filter_map(|line| {
+                line.strip_prefix("Items: ")
+                    .or_else(|| line.strip_prefix("Re-exports: "))
+            });
+            for list in lists {
+                for name in list.split('`').skip(1).step_by(2) {
+                    if is_type(name) {
+                        symbols.insert(name.to_string());
+                    }
+                }
+            }
+        }
+        for resource in self.resources.values() {
+            if let Some(page) = resource.uri.strip_prefix("alloy://api/") {
+                let name = page.rsplit('/').next().unwrap_or(page);
+                if is_type(name) {
+                    symbols.insert(name.to_string());
+                }
+            }
+        }
+        symbols
+    }
 }
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
@@ -148,6 +265,23 @@ pub struct GetResourceRequest {
 impl AlloyMcpServer {
     pub fn create_tool_router() -> rmcp::handler::server::router::tool::ToolRouter<Self> {
         Self::tool_router()
+            + Self::transaction_code_router()
+            + Self::eip712_router()
+            + Self::signature_router()
+            + Self::units_router()
+            + Self::address_router()
+            + Self::migrate_router()
+            + Self::lint_router()
+            + Self::features_router()
+            + Self::imports_router()
+            + Self::changes_router()
+            + Self::methods_router()
+            + Self::conversions_router()
+            + Self::networks_router()
+            + Self::scaffold_router()
+            + Self::abi_router()
+            + Self::sol_router()
+            + Self::sol_type_router()
     }
 
     /// Look up information about an alloy type by name.
@@ -159,43 +293,7 @@ impl AlloyMcpServer {
         &self,
         Parameters(LookupTypeRequest { type_name }): Parameters<LookupTypeRequest>,
     ) -> String {
-        let sections = self.all_sections();
-        let mut scored: Vec<(u32, &Section)> = sections
-            .iter()
-            .filter_map(|s| {
-                let score = score_section(s, &type_name);
-                if score > 0 { Some((score, s)) } else { None }
-            })
-            .collect();
-
-        scored.sort_by(|a, b| b.0.cmp(&a.0));
-        scored.truncate(3);
-
-        if scored.is_empty() {
-            let uris: Vec<String> = self
-                .resources
-                .values()
-                .map(|r| format!("  - {} ({})", r.uri, r.name))
-                .collect();
-            format!(
-                "No documentation found for '{}'. Available resources:\n{}",
-                type_name,
-                uris.join("\n")
-            )
-        } else {
-            let mut result = format!("# Results for '{}'\n\n", type_name);
-            for (score, section) in scored {
-                result.push_str(&format!(
-                    "---\n**{}** — {} (relevance: {})\nURI: {}\n\n{}\n\n",
-                    section.heading.trim_start_matches('#').trim(),
-                    section.resource_name,
-                    score,
-                    section.uri,
-                    section.content
-                ));
-            }
-            result
-        }
+        self.type_lookup(&type_name)
     }
 
     /// Search across all alloy documentation resources.
@@ -243,7 +341,7 @@ impl AlloyMcpServer {
             })
             .collect();
 
-        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        scored.sort_by_key(|s| std::cmp::Reverse(s.0));
         scored.truncate(max);
 
         if scored.is_empty() {
@@ -321,3 +419,52 @@ impl AlloyMcpServer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    fn server() -> AlloyMcpServer {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        AlloyMcpServer::new().with_rustdoc(&fixtures)
+    }
+
+    #[test]
+    fn api_pages_appear_in_search() {
+        let result = server().search_resources(Parameters(SearchResourcesRequest {
+            query: "FilterBlockOption".into(),
+            max_results: Some(10),
+        }));
+        assert!(result.contains("alloy://api/alloy-rpc-types-eth/filter/FilterBlockOption"));
+    }
+
+    #[test]
+    fn lookup_type_prefers_the_exact_api_page() {
+        let result = server().type_lookup("FilterBlockOption");
+        let first = result.lines().find(|l| l.starts_with("URI: "));
+        assert_eq!(
+            first,
+            Some("URI: alloy://api/alloy-rpc-types-eth/filter/FilterBlockOption")
+        );
+        assert!(!result.contains("URI: alloy://api\n"));
+    }
+
+    #[test]
+    fn type_completions_come_from_the_imports_map_and_api_pages() {
+        let symbols = server().type_symbols();
+        for name in [
+            "TxEnvelope",
+            "U256",
+            "SolValue",
+            "ReceiptResponse",
+            "FilterSet",
+        ] {
+            assert!(symbols.contains(name), "missing {name}");
+        }
+        for name in ["Arc", "AsRef", "BYTES", "MyTxType", "Vec", "keccak256"] {
+            assert!(!symbols.contains(name), "unexpected {name}");
+        }
+    }
+}
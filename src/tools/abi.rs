@@ -0,0 +1,536 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use alloy_json_abi::{ContractObject, InternalType, JsonAbi, Param, StateMutability, ToSolConfig};
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+use serde_json::Value;
+
+use super::codegen::{Imports, is_ident, snake, sol_rust_type};
+use crate::server::AlloyMcpServer;
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct AbiToSolRequest {
+    #[schemars(
+        description = "Contract ABI: a JSON array, a Foundry/Hardhat artifact with an `abi` field, or either as a JSON string"
+    )]
+    abi: Value,
+    #[schemars(
+        description = "Name for the generated interface; defaults to the artifact's contract name"
+    )]
+    name: Option<String>,
+}
+
+/// Contract name recorded in an artifact: Hardhat's `contractName` or the
+/// compilation target in Foundry's `metadata`.
+fn artifact_name(artifact: &Value) -> Option<String> {
+    if let Some(name) = artifact.get("contractName").and_then(Value::as_str) {
+        return Some(name.to_string());
+    }
+    artifact
+        .pointer("/metadata/settings/compilationTarget")?
+        .as_object()?
+        .values()
+        .next()?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Rust type of an ABI parameter in `sol!` bindings of `contract`. Named
+/// structs live in the module of the contract that declares them.
+fn rust_type(
+    ty: &str,
+    internal: Option<&InternalType>,
+    components: &[Param],
+    contract: &str,
+    imports: &mut Imports,
+) -> String {
+    if let Some((inner, size)) = ty.strip_suffix(']').and_then(|t| t.rsplit_once('[')) {
+        let inner = rust_type(inner, internal, components, contract, imports);
+        return match size {
+            "" => format!("Vec<{inner}>"),
+            size => format!("[{inner}; {size}]"),
+        };
+    }
+    match internal {
+        Some(InternalType::Struct {
+            contract: owner,
+            ty,
+        }) if !components.is_empty() => {
+            let name = ty.split('[').next().unwrap_or(ty);
+            return format!("{}::{name}", owner.as_deref().unwrap_or(contract));
+        }
+        Some(InternalType::Enum { .. }) if sol_rust_type(ty, imports).is_none() => {
+            return "u8".into();
+        }
+        _ => {}
+    }
+    if ty == "tuple" {
+        let items: Vec<String> = components
+            .iter()
+            .map(|c| {
+                rust_type(
+                    &c.ty,
+                    c.internal_type.as_ref(),
+                    &c.components,
+                    contract,
+                    imports,
+                )
+            })
+            .collect();
+        return match items.as_slice() {
+            [single] => format!("({single},)"),
+            _ => format!("({})", items.join(", ")),
+        };
+    }
+    sol_rust_type(ty, imports).unwrap_or_else(|| ty.to_string())
+}
+
+/// Whether a parameter is a tuple the ABI gives no struct name for.
+fn is_unnamed_tuple(ty: &str, internal: Option<&InternalType>) -> bool {
+    ty.starts_with("tuple") && !matches!(internal, Some(InternalType::Struct { .. }))
+}
+
+/// Rust variable for a parameter: its name in snake case, `arg{i}` when
+/// it has none.
+fn binding(name: &str, i: usize) -> String {
+    let name = snake(name);
+    match name.as_str() {
+        "" => format!("arg{i}"),
+        _ if is_ident(&name) => name,
+        _ => format!("{name}_"),
+    }
+}
+
+/// `sol!` numbers errors, events and functions that share a name with a
+/// `_N` suffix, in the order `to_sol` declares them: errors, events, then
+/// functions. Maps `(name, signature)` to the suffixed name.
+fn overloads(abi: &JsonAbi) -> BTreeMap<(String, String), String> {
+    let mut by_name: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for error in abi.errors() {
+        by_name
+            .entry(&error.name)
+            .or_default()
+            .push(error.signature());
+    }
+    for event in abi.events() {
+        by_name
+            .entry(&event.name)
+            .or_default()
+            .push(event.signature());
+    }
+    for function in abi.functions() {
+        by_name
+            .entry(&function.name)
+            .or_default()
+            .push(function.signature());
+    }
+    let mut names = BTreeMap::new();
+    for (name, signatures) in by_name.into_iter().filter(|(_, s)| s.len() > 1) {
+        for (i, signature) in signatures.into_iter().enumerate() {
+            names.insert((name.to_string(), signature), format!("{name}_{i}"));
+        }
+    }
+    names
+}
+
+/// The `sol!` block for `solidity`, the output of `JsonAbi::to_sol`: the
+/// interface gets `#[sol(rpc)]`, every top-level item the derives.
+fn sol_block(solidity: &str, name: &str) -> String {
+    let mut block = String::from("sol! {\n");
+    for line in solidity.lines() {
+        if line.starts_with(&format!("interface {name} ")) {
+            block.push_str("    #[sol(rpc)]\n");
+        }
+        if line.starts_with("interface ") || line.starts_with("library ") {
+            block.push_str("    #[derive(Debug, PartialEq, Eq)]\n");
+        }
+        if line.is_empty() {
+            block.push('\n');
+        } else {
+            let _ = writeln!(block, "    {line}");
+        }
+    }
+    block.push_str("}\n");
+    block
+}
+
+#[tool_router(router = abi_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Translate a JSON ABI into `sol!` bindings, with a usage line per
+    /// function and event.
+    #[tool(
+        description = "Turn a contract ABI (raw JSON array or Foundry/Hardhat artifact) into an equivalent sol! block with #[sol(rpc)] and derives, a usage example for each function and event, and the _0/_1 names sol! gives overloaded functions, events and errors."
+    )]
+    fn abi_to_sol(
+        &self,
+        Parameters(AbiToSolRequest { abi, name }): Parameters<AbiToSolRequest>,
+    ) -> String {
+        // Clients sometimes pass the JSON as a string.
+        let abi = match abi {
+            Value::String(s) => match serde_json::from_str(&s) {
+                Ok(v) => v,
+                Err(e) => return format!("Invalid ABI JSON: {e}"),
+            },
+            v => v,
+        };
+        let name = name
+            .map(|n| n.trim().to_string())
+            .or_else(|| artifact_name(&abi))
+            .unwrap_or_else(|| "Contract".into());
+        if !is_ident(&name) {
+            return format!("`{name}` is not a usable contract name: use e.g. \"ERC20\".");
+        }
+        // `ContractObject` only deserializes from borrowed strings.
+        let object: ContractObject = match serde_json::from_str(&abi.to_string()) {
+            Ok(object) => object,
+            Err(e) => {
+                return format!(
+                    "Could not read the ABI: {e}\n\nPass the ABI array itself or an artifact \
+                    object with an `abi` field."
+                );
+            }
+        };
+        let Some(abi) = object.abi else {
+            return "The artifact has no `abi` field.".into();
+        };
+        if abi.is_empty() {
+            return "The ABI is empty: there is nothing to bind.".into();
+        }
+
+        let solidity = abi.to_sol(&name, Some(ToSolConfig::new().for_sol_macro(true)));
+        let overloaded = overloads(&abi);
+        let rust_name = |item: &str, signature: String| {
+            overloaded
+                .get(&(item.to_string(), signature))
+                .cloned()
+                .unwrap_or_else(|| item.to_string())
+        };
+
+        let functions: Vec<_> = abi.functions().collect();
+        let events: Vec<_> = abi.events().collect();
+        let errors: Vec<_> = abi.errors().collect();
+        let mut result = format!(
+            "# `{name}` bindings\n\n{} function(s), {} event(s), {} error(s).\n\n\
+            ## Rust\n\n```rust\nuse alloy::sol;\n\n{}```\n\n",
+            functions.len(),
+            events.len(),
+            errors.len(),
+            sol_block(&solidity, &name)
+        );
+
+        // Usage: one call per function, one query per event.
+        let mut imports = Imports::default();
+        imports.add("alloy::providers", "ProviderBuilder");
+        // Types that only show up in comments need no import.
+        let mut shown = Imports::default();
+        let instance = match snake(&name) {
+            n if is_ident(&n) => n,
+            _ => "contract".into(),
+        };
+        let writes = functions.iter().any(|f| {
+            !matches!(
+                f.state_mutability,
+                StateMutability::View | StateMutability::Pure
+            )
+        });
+        let mut usage = String::new();
+        if writes {
+            let _ = writeln!(
+                usage,
+                "// `send()` needs a wallet on the provider\n\
+                let provider = ProviderBuilder::new()\n    .wallet(signer)\n    \
+                .connect_http(\"http://localhost:8545\".parse()?);"
+            );
+        } else {
+            let _ = writeln!(
+                usage,
+                "let provider = ProviderBuilder::new().connect_http(\"http://localhost:8545\".parse()?);"
+            );
+        }
+        let _ = writeln!(usage, "let {instance} = {name}::new(address, &provider);");
+
+        let mut unnamed_tuples = Vec::new();
+        for function in &functions {
+            let method = rust_name(&function.name, function.signature());
+            let mut params = Vec::new();
+            let mut args = Vec::new();
+            for (i, input) in function.inputs.iter().enumerate() {
+                let ty = rust_type(
+                    &input.ty,
+                    input.internal_type.as_ref(),
+                    &input.components,
+                    &name,
+                    &mut shown,
+                );
+                if is_unnamed_tuple(&input.ty, input.internal_type.as_ref()) {
+                    unnamed_tuples.push(format!(
+                        "`{}` of `{method}` (`{ty}`)",
+                        binding(&input.name, i)
+                    ));
+                }
+                let arg = binding(&input.name, i);
+                params.push(format!("{arg}: {ty}"));
+                args.push(arg);
+            }
+            let outputs: Vec<String> = function
+                .outputs
+                .iter()
+                .map(|o| {
+                    rust_type(
+                        &o.ty,
+                        o.internal_type.as_ref(),
+                        &o.components,
+                        &name,
+                        &mut shown,
+                    )
+                })
+                .collect();
+            let call = format!("{instance}.{method}({})", args.join(", "));
+            let mutability = function.state_mutability.as_str().unwrap_or("nonpayable");
+            let _ = write!(usage, "\n// {method}({}) {mutability}", params.join(", "));
+            match function.state_mutability {
+                StateMutability::View | StateMutability::Pure => match outputs.as_slice() {
+                    [] => {
+                        let _ = writeln!(usage, "\n{call}.call().await?;");
+                    }
+                    [_] => {
+                        let output = &function.outputs[0];
+                        let ty = rust_type(
+                            &output.ty,
+                            output.internal_type.as_ref(),
+                            &output.components,
+                            &name,
+                            &mut imports,
+                        );
+                        let var = match function.outputs[0].name.as_str() {
+                            "" => binding(&function.name, 0),
+                            n => binding(n, 0),
+                        };
+                        let _ = writeln!(usage, "\nlet {var}: {ty} = {call}.call().await?;");
+                    }
+                    _ => {
+                        let fields: Vec<String> = function
+                            .outputs
+                            .iter()
+                            .enumerate()
+                            .map(|(i, o)| match o.name.as_str() {
+                                "" => format!("_{i}"),
+                                n if binding(n, i) == n => n.to_string(),
+                                n => format!("{n}: {}", binding(n, i)),
+                            })
+                            .collect();
+                        let types: Vec<String> = function
+                            .outputs
+                            .iter()
+                            .zip(&outputs)
+                            .enumerate()
+                            .map(|(i, (o, ty))| match o.name.as_str() {
+                                "" => format!("_{i}: {ty}"),
+                                n => format!("{n}: {ty}"),
+                            })
+                            .collect();
+                        let _ = writeln!(
+                            usage,
+                            " -> {method}Return {{ {} }}\nlet {name}::{method}Return {{ {} }} = \
+                            {call}.call().await?;",
+                            types.join(", "),
+                            fields.join(", ")
+                        );
+                    }
+                },
+                StateMutability::Payable => {
+                    imports.add("alloy::primitives", "U256");
+                    let _ = writeln!(
+                        usage,
+                        "\nlet receipt = {call}.value(U256::from(1)).send().await?.get_receipt().await?;"
+                    );
+                }
+                StateMutability::NonPayable => {
+                    let _ = writeln!(
+                        usage,
+                        "\nlet receipt = {call}.send().await?.get_receipt().await?;"
+                    );
+                }
+            }
+        }
+
+        let mut anonymous = Vec::new();
+        for event in &events {
+            let event_name = rust_name(&event.name, event.signature());
+            let fields: Vec<String> = event
+                .inputs
+                .iter()
+                .enumerate()
+                .map(|(i, input)| {
+                    let ty = rust_type(
+                        &input.ty,
+                        input.internal_type.as_ref(),
+                        &input.components,
+                        &name,
+                        &mut shown,
+                    );
+                    let field = match input.name.as_str() {
+                        "" => format!("_{i}"),
+                        n => n.to_string(),
+                    };
+                    match input.indexed {
+                        true => format!("{field}: {ty} (indexed)"),
+                        false => format!("{field}: {ty}"),
+                    }
+                })
+                .collect();
+            if event.anonymous {
+                anonymous.push(format!("`{event_name}_filter()`"));
+            }
+            let var = format!("{}_logs", binding(&event.name, 0));
+            let _ = writeln!(
+                usage,
+                "\n// event {event_name} {{ {} }}\n\
+                let {var} = {instance}.{event_name}_filter().from_block(0).query().await?;\n\
+                // or from any log: {name}::{event_name}::decode_log(&log.inner)?",
+                fields.join(", ")
+            );
+        }
+        let _ = write!(
+            result,
+            "## Usage\n\n```rust\n{}\n{usage}```\n",
+            imports.render()
+        );
+
+        if !overloaded.is_empty() {
+            result.push_str(
+                "\n## Overloads\n\n`sol!` gives items that share a name a `_N` suffix in \
+                declaration order. Use these names for the `*Call` structs, instance methods, \
+                event filters and `*Errors` variants:\n\n\
+                | Solidity | Rust name |\n|----------|-----------|\n",
+            );
+            for ((_, signature), rust) in &overloaded {
+                let _ = writeln!(result, "| `{signature}` | `{rust}` |");
+            }
+        }
+
+        let mut notes = Vec::new();
+        if !overloaded.is_empty() {
+            notes.push(
+                "The `_N` numbering follows the declaration order in the `sol!` block: keep the \
+                declarations in this order, since reordering them swaps which signature `_0` and \
+                `_1` call. The selector still comes from the Solidity name."
+                    .to_string(),
+            );
+        }
+        if !unnamed_tuples.is_empty() {
+            notes.push(format!(
+                "The ABI names no struct (`internalType`) for {}, so these are Rust tuples. For \
+                named fields, declare a `struct` in the `sol!` block and use it in the \
+                signature: the encoding is the same.",
+                unnamed_tuples.join(", ")
+            ));
+        }
+        let enums: Vec<String> = functions
+            .iter()
+            .flat_map(|f| f.inputs.iter().chain(&f.outputs))
+            .filter_map(|p| match &p.internal_type {
+                Some(InternalType::Enum { ty, .. }) => Some(format!("`{ty}`")),
+                _ => None,
+            })
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if !enums.is_empty() {
+            notes.push(format!(
+                "The ABI records enum {} only as `uint8`: `sol!` declares a user-defined value \
+                type over `uint8`, and the Rust side passes and gets back a `u8`.",
+                enums.join(", ")
+            ));
+        }
+        if !anonymous.is_empty() {
+            notes.push(format!(
+                "Anonymous events have no signature topic, so {} matches every log of the \
+                contract: decoding the other logs fails or yields garbage.",
+                anonymous.join(", ")
+            ));
+        }
+        if !errors.is_empty() {
+            let variants: Vec<String> = errors
+                .iter()
+                .map(|e| format!("`{}`", rust_name(&e.name, e.signature())))
+                .collect();
+            notes.push(format!(
+                "Reverts decode with `err.as_decoded_interface_error::<{name}::{name}Errors>()` \
+                on the error from `call()`/`send()`; variants: {}.",
+                variants.join(", ")
+            ));
+        }
+        if abi.receive.is_some() || abi.fallback.is_some() {
+            notes.push(
+                "`receive`/`fallback` get no binding: send value or raw calldata with a plain \
+                `TransactionRequest` to the contract address."
+                    .into(),
+            );
+        }
+        if object.bytecode.as_ref().is_some_and(|b| !b.is_empty()) {
+            notes.push(format!(
+                "The artifact has bytecode. Point `sol!` at the file instead \
+                (`sol!(#[sol(rpc)] {name}, \"path/to/{name}.json\");`, `json` feature) to also \
+                get `{name}::deploy(&provider, ...)` with the constructor arguments."
+            ));
+        }
+        if !notes.is_empty() {
+            result.push_str("\n## Notes\n\n");
+            for note in &notes {
+                let _ = writeln!(result, "- {note}");
+            }
+        }
+
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://sol-macro/contract-bindings` — Overloaded Functions and contract instances\n\
+            - `alloy://sol-macro/sol-types` — SolCall and SolEvent\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_a_sol_interface() {
+        let abi = serde_json::json!([
+            {
+                "type": "function",
+                "name": "transfer",
+                "inputs": [
+                    { "name": "to", "type": "address" },
+                    { "name": "amount", "type": "uint256" }
+                ],
+                "outputs": [{ "name": "", "type": "bool" }],
+                "stateMutability": "nonpayable"
+            },
+            {
+                "type": "event",
+                "name": "Transfer",
+                "anonymous": false,
+                "inputs": [
+                    { "name": "from", "type": "address", "indexed": true },
+                    { "name": "to", "type": "address", "indexed": true },
+                    { "name": "value", "type": "uint256", "indexed": false }
+                ]
+            }
+        ]);
+        let result = AlloyMcpServer::new().abi_to_sol(Parameters(AbiToSolRequest {
+            abi,
+            name: Some("ERC20".into()),
+        }));
+        assert!(result.contains("interface ERC20 {"));
+        assert!(
+            result.contains(
+                "event Transfer(address indexed from, address indexed to, uint256 value);"
+            )
+        );
+        assert!(
+            result
+                .contains("function transfer(address to, uint256 amount) external returns (bool);")
+        );
+    }
+}
@@ -0,0 +1,374 @@
+use std::fmt::Write;
+
+use alloy_primitives::{Address, B256, U160, U256, address, hex, keccak256};
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+
+use super::codegen::{Imports, address_lit, b256_lit};
+use crate::server::AlloyMcpServer;
+
+/// Offset added to an L1 contract address when its message is relayed to
+/// an OP-stack (or Arbitrum) L2.
+const ALIAS_OFFSET: Address = address!("0x1111000000000000000000000000000000001111");
+
+/// CREATE2 factories whose address is deterministic across chains.
+const KNOWN_FACTORIES: [(Address, &str); 2] = [
+    (
+        address!("0x4e59b44847b379578588920cA78FbF26c0B4956C"),
+        "the deterministic deployment proxy used by `forge script` for `new Contract{salt: ..}()`; \
+        the salt and init code are passed through unchanged",
+    ),
+    (
+        address!("0xba5Ed099633D3B313e4D5F7bdc1305d3c28ba5Ed"),
+        "CreateX, which guards salts (it hashes the salt with `msg.sender`/chain id depending on \
+        its first 21 bytes); pass the guarded salt here, not the one given to CreateX",
+    ),
+];
+
+enum Operation {
+    Create(u64),
+    Create2 { salt: B256, init_code_hash: B256 },
+    Alias,
+    Unalias,
+}
+
+fn parse_address(name: &str, s: &str, notes: &mut Vec<String>) -> Result<Address, String> {
+    let s = s.trim();
+    let address: Address = s
+        .parse()
+        .map_err(|e| format!("`{name}` is not a valid address ({s:?}): {e}"))?;
+    let hex_part = s.trim_start_matches("0x");
+    let mixed_case = hex_part.chars().any(|c| c.is_ascii_uppercase())
+        && hex_part.chars().any(|c| c.is_ascii_lowercase());
+    if mixed_case && Address::parse_checksummed(s, None).is_err() {
+        notes.push(format!(
+            "`{name}` is mixed-case but its EIP-55 checksum is wrong (expected `{}`): check it \
+            was not mistyped (`Address::parse_checksummed` rejects it).",
+            address.to_checksum(None)
+        ));
+    }
+    Ok(address)
+}
+
+/// Parse a CREATE2 salt: 32 bytes of hex, shorter hex left-padded like
+/// `bytes32(uint256(x))`, or a decimal integer.
+fn parse_salt(s: &str, notes: &mut Vec<String>) -> Result<B256, String> {
+    let s = s.trim();
+    if !s.starts_with("0x") && !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) {
+        let n: U256 = s
+            .parse()
+            .map_err(|e| format!("`salt` is not a valid integer: {e}"))?;
+        notes.push(format!(
+            "Decimal salt `{s}` was converted with `B256::from(U256::from({s}))` (big-endian, \
+            same as `bytes32(uint256({s}))` in Solidity)."
+        ));
+        return Ok(B256::from(n));
+    }
+    let bytes = hex::decode(s).map_err(|e| format!("`salt` is not valid hex: {e}"))?;
+    match bytes.len() {
+        32 => Ok(B256::from_slice(&bytes)),
+        len @ 0..32 => {
+            notes.push(format!(
+                "`salt` was {len} bytes and was left-padded to 32, like `bytes32(uint256(x))`. \
+                `bytes32(\"text\")` right-pads instead and gives a different address."
+            ));
+            Ok(B256::left_padding_from(&bytes))
+        }
+        len => Err(format!("`salt` must be at most 32 bytes, got {len}")),
+    }
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ComputeAddressRequest {
+    #[schemars(
+        description = "One of 'create', 'create2', 'alias' (L1 → L2) or 'unalias' (L2 → L1); inferred from `nonce`/`salt` when omitted"
+    )]
+    operation: Option<String>,
+    #[schemars(
+        description = "Sender (CREATE), deployer/factory (CREATE2) or the address to alias/unalias"
+    )]
+    address: String,
+    #[schemars(description = "Sender nonce for CREATE")]
+    nonce: Option<u64>,
+    #[schemars(
+        description = "CREATE2 salt: 32-byte hex (shorter hex is left-padded) or a decimal integer"
+    )]
+    salt: Option<String>,
+    #[schemars(description = "CREATE2 keccak256 hash of the init code")]
+    init_code_hash: Option<String>,
+    #[schemars(
+        description = "CREATE2 init code hex (creation bytecode followed by ABI-encoded constructor arguments); hashed if `init_code_hash` is not given"
+    )]
+    init_code: Option<String>,
+}
+
+impl ComputeAddressRequest {
+    fn operation(&self, notes: &mut Vec<String>) -> Result<Operation, String> {
+        let name = match (&self.operation, self.nonce, &self.salt) {
+            (Some(op), ..) => op.trim().to_ascii_lowercase(),
+            (None, Some(_), None) => "create".into(),
+            (None, None, Some(_)) => "create2".into(),
+            _ => {
+                return Err(
+                    "Set `operation` to 'create', 'create2', 'alias' or 'unalias' \
+                    (or pass only `nonce` or only `salt`)."
+                        .into(),
+                );
+            }
+        };
+        match name.as_str() {
+            "create" => self
+                .nonce
+                .map(Operation::Create)
+                .ok_or_else(|| "CREATE needs `nonce`.".into()),
+            "create2" => {
+                let salt = parse_salt(self.salt.as_deref().ok_or("CREATE2 needs `salt`.")?, notes)?;
+                let init_code_hash = match (&self.init_code_hash, &self.init_code) {
+                    (Some(hash), _) => hash
+                        .trim()
+                        .parse()
+                        .map_err(|e| format!("`init_code_hash` is not a 32-byte hex value: {e}"))?,
+                    (None, Some(code)) => keccak256(
+                        hex::decode(code.trim())
+                            .map_err(|e| format!("`init_code` is not valid hex: {e}"))?,
+                    ),
+                    (None, None) => {
+                        return Err("CREATE2 needs `init_code_hash` or `init_code`.".into());
+                    }
+                };
+                if init_code_hash == keccak256([]) {
+                    notes.push(
+                        "The init code hash is `keccak256(\"\")`: the init code is empty, so \
+                        nothing would be deployed."
+                            .into(),
+                    );
+                }
+                Ok(Operation::Create2 {
+                    salt,
+                    init_code_hash,
+                })
+            }
+            "alias" => Ok(Operation::Alias),
+            "unalias" => Ok(Operation::Unalias),
+            other => Err(format!(
+                "Unknown `operation` '{other}'. Use 'create', 'create2', 'alias' or 'unalias'."
+            )),
+        }
+    }
+}
+
+/// Add (`alias`) or remove the L1 → L2 alias offset, wrapping mod 2^160.
+fn apply_alias(address: Address, alias: bool) -> Address {
+    let value = U160::from_be_bytes(address.into_array());
+    let offset = U160::from_be_bytes(ALIAS_OFFSET.into_array());
+    let shifted = if alias {
+        value.wrapping_add(offset)
+    } else {
+        value.wrapping_sub(offset)
+    };
+    Address::from(shifted.to_be_bytes())
+}
+
+#[tool_router(router = address_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Derive CREATE/CREATE2 addresses and apply or undo L1 → L2 aliasing.
+    #[tool(
+        description = "Compute contract addresses: CREATE from sender + nonce, CREATE2 from deployer + salt + init code (hash), or apply/undo the L1 → L2 address alias offset. Returns the EIP-55 checksummed address and the Address::create/create2 Rust call."
+    )]
+    fn compute_address(&self, Parameters(request): Parameters<ComputeAddressRequest>) -> String {
+        let mut notes = Vec::new();
+        let input = match parse_address("address", &request.address, &mut notes) {
+            Ok(a) => a,
+            Err(e) => return e,
+        };
+        let operation = match request.operation(&mut notes) {
+            Ok(op) => op,
+            Err(e) => return e,
+        };
+
+        let mut imports = Imports::default();
+        imports.add("alloy::primitives", "address");
+        let (title, rows, computed, code) = match &operation {
+            Operation::Create(nonce) => {
+                let computed = input.create(*nonce);
+                let code = format!(
+                    "let sender = {};\nlet contract = sender.create({nonce});\n",
+                    address_lit(&input)
+                );
+                if *nonce == 0 {
+                    notes.push(
+                        "Contracts start at nonce 1 (EIP-161): for a factory contract, its first \
+                        CREATE uses nonce 1, not 0."
+                            .into(),
+                    );
+                }
+                notes.push(
+                    "For an EOA, the nonce is the deployment transaction's nonce \
+                    (`provider.get_transaction_count(sender)` before sending)."
+                        .into(),
+                );
+                (
+                    "CREATE address",
+                    vec![
+                        ("Sender", format!("`{}`", input.to_checksum(None))),
+                        ("Nonce", nonce.to_string()),
+                    ],
+                    computed,
+                    code,
+                )
+            }
+            Operation::Create2 {
+                salt,
+                init_code_hash,
+            } => {
+                imports.add("alloy::primitives", "b256");
+                let computed = input.create2(salt, init_code_hash);
+                let mut code = format!(
+                    "let deployer = {};\nlet salt = {};\nlet init_code_hash = {};\n\
+                    let contract = deployer.create2(salt, init_code_hash);\n",
+                    address_lit(&input),
+                    b256_lit(salt),
+                    b256_lit(init_code_hash)
+                );
+                if request.init_code.is_some() && request.init_code_hash.is_none() {
+                    code.push_str(
+                        "// or, from the init code itself:\n\
+                        // let contract = deployer.create2_from_code(salt, &init_code);\n",
+                    );
+                }
+                if let Some((_, about)) = KNOWN_FACTORIES.iter().find(|(a, _)| *a == input) {
+                    notes.push(format!("`{}` is {about}.", input.to_checksum(None)));
+                }
+                notes.push(
+                    "The init code includes the ABI-encoded constructor arguments: different \
+                    arguments give a different address."
+                        .into(),
+                );
+                (
+                    "CREATE2 address",
+                    vec![
+                        ("Deployer", format!("`{}`", input.to_checksum(None))),
+                        ("Salt", format!("`{salt}`")),
+                        ("Init code hash", format!("`{init_code_hash}`")),
+                    ],
+                    computed,
+                    code,
+                )
+            }
+            Operation::Alias | Operation::Unalias => {
+                let alias = matches!(operation, Operation::Alias);
+                imports.add("alloy::primitives", "Address");
+                imports.add("alloy::primitives", "U160");
+                imports.add("alloy::primitives", "uint");
+                let computed = apply_alias(input, alias);
+                let (func, op) = if alias {
+                    ("address_to_l2_alias", "wrapping_add")
+                } else {
+                    ("address_from_l2_alias", "wrapping_sub")
+                };
+                let code = format!(
+                    "const OFFSET: U160 = uint!(0x1111000000000000000000000000000000001111_U160);\n\n\
+                    fn {func}(addr: Address) -> Address {{\n    \
+                    let u160 = U160::from_be_bytes(addr.into_array());\n    \
+                    Address::from(u160.{op}(OFFSET))\n}}\n\n\
+                    let shifted = {func}({});\n",
+                    address_lit(&input)
+                );
+                notes.push(
+                    "Only messages sent by L1 *contracts* are aliased; deposits from EOAs keep \
+                    the sender address."
+                        .into(),
+                );
+                notes.push(format!(
+                    "The offset is added mod 2^160; undo it with `{}`.",
+                    if alias {
+                        "address_from_l2_alias"
+                    } else {
+                        "address_to_l2_alias"
+                    }
+                ));
+                (
+                    if alias {
+                        "L1 → L2 alias"
+                    } else {
+                        "L2 → L1 unalias"
+                    },
+                    vec![
+                        (
+                            if alias { "L1 address" } else { "L2 alias" },
+                            format!("`{}`", input.to_checksum(None)),
+                        ),
+                        ("Offset", format!("`{}`", ALIAS_OFFSET.to_checksum(None))),
+                    ],
+                    computed,
+                    code,
+                )
+            }
+        };
+
+        let binding = match operation {
+            Operation::Alias | Operation::Unalias => "shifted",
+            _ => "contract",
+        };
+        let mut result = format!("# {title}\n\n| Input | Value |\n|-------|-------|\n");
+        for (name, value) in &rows {
+            let _ = writeln!(result, "| {name} | {value} |");
+        }
+        let _ = write!(
+            result,
+            "\n**Result:** `{}` (EIP-55)\n\n## Rust\n\n```rust\n{}\n{code}\
+            assert_eq!({binding}, {});\n```\n",
+            computed.to_checksum(None),
+            imports.render(),
+            address_lit(&computed)
+        );
+
+        result.push_str("\n## Notes\n\n");
+        for note in &notes {
+            let _ = writeln!(result, "- {note}");
+        }
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://primitives/core-types` — Address, Address Aliasing (L1 ↔ L2)\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compute(request: serde_json::Value) -> String {
+        let request = serde_json::from_value(request).unwrap();
+        AlloyMcpServer::new().compute_address(Parameters(request))
+    }
+
+    #[test]
+    fn computes_create_addresses() {
+        let result = compute(serde_json::json!({
+            "address": "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0",
+            "nonce": 0,
+        }));
+        assert!(result.contains("**Result:** `0xcd234A471b72ba2F1Ccf0A70FCABA648a5eeCD8d`"));
+    }
+
+    /// Example 0 of EIP-1014.
+    #[test]
+    fn computes_create2_addresses() {
+        let result = compute(serde_json::json!({
+            "address": "0x0000000000000000000000000000000000000000",
+            "salt": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "init_code": "0x00",
+        }));
+        assert!(result.contains("**Result:** `0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38`"));
+    }
+
+    #[test]
+    fn applies_the_l1_to_l2_alias_offset() {
+        let result = compute(serde_json::json!({
+            "operation": "alias",
+            "address": "0x0000000000000000000000000000000000000000",
+        }));
+        assert!(result.contains("**Result:** `0x1111000000000000000000000000000000001111`"));
+    }
+}
@@ -0,0 +1,131 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+
+use crate::{
+    changelog::{self, Change, Version},
+    server::AlloyMcpServer,
+};
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct AlloyChangesRequest {
+    #[schemars(
+        description = "alloy version the code targets now, e.g. \"0.11\" or \"0.15.2\". Defaults to the project's version when the server knows the project"
+    )]
+    from: Option<String>,
+    #[schemars(description = "alloy version to move to, e.g. \"1.0\". Defaults to the latest")]
+    to: Option<String>,
+    #[schemars(
+        description = "Only list changes that mention this API, crate or text, e.g. \"on_http\" or \"abi_decode\""
+    )]
+    query: Option<String>,
+}
+
+#[tool_router(router = changes_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// List the breaking changes between two alloy releases from the table
+    /// behind `alloy://changelog`.
+    #[tool(
+        description = "List the APIs that were renamed, removed or changed signature or behavior between two alloy versions, with code before and after each change and the upstream PR. Use it when bumping alloy to see exactly what breaks: e.g. on_http → connect_http (0.15), validate flags removed from abi_decode/decode_log and ._0 dropped from call results (1.0). `from` defaults to the project's alloy version."
+    )]
+    fn alloy_changes(
+        &self,
+        Parameters(AlloyChangesRequest { from, to, query }): Parameters<AlloyChangesRequest>,
+    ) -> String {
+        let project = self.workspace().and_then(|w| w.version);
+        let Some(from_text) = from.or(project) else {
+            return format!(
+                "No `from` version given and no project detected. Pass the alloy version the \
+                code targets now, e.g. `from: \"0.11\"`. Releases with recorded changes: {}.",
+                changelog::releases().join(", ")
+            );
+        };
+        let Some(from) = Version::parse(&from_text) else {
+            return format!("Could not read `{from_text}` as an alloy version (e.g. `0.15.2`).");
+        };
+        let to_text = to.unwrap_or_else(|| "latest".into());
+        let Some(to) = Version::parse(&to_text) else {
+            return format!("Could not read `{to_text}` as an alloy version (e.g. `1.0`).");
+        };
+
+        let mut changes = changelog::between(from, to);
+        let total = changes.len();
+        if let Some(query) = query.as_deref().filter(|q| !q.trim().is_empty()) {
+            changes.retain(|c| changelog::mentions(c, query.trim()));
+        }
+
+        let mut result = format!("# alloy changes: {from} → {to}\n\n");
+        if changes.is_empty() {
+            let _ = writeln!(
+                result,
+                "{}",
+                match (&query, total) {
+                    (Some(query), 1..) => format!(
+                        "None of the {total} recorded change(s) between {from} and {to} mention \
+                        `{query}`. Drop `query` to list them all."
+                    ),
+                    _ => format!("No breaking changes are recorded between {from} and {to}."),
+                }
+            );
+        } else {
+            result.push_str(&changelog::render_range(from, to, &changes));
+        }
+
+        let mut notes = Vec::new();
+        if changes.len() < total {
+            notes.push(format!(
+                "Showing {} of {total} changes matching `{}`.",
+                changes.len(),
+                query.as_deref().unwrap_or_default().trim()
+            ));
+        }
+        if let Some(summary) = summary(&changes) {
+            notes.push(summary);
+        }
+        notes.push(
+            "The table records breaking API changes that affect typical application code; \
+            see each crate's CHANGELOG.md for everything else."
+                .into(),
+        );
+        result.push_str("\n## Notes\n\n");
+        for note in &notes {
+            let _ = writeln!(result, "- {note}");
+        }
+        let _ = write!(
+            result,
+            "\n**Key resources:**\n\
+            - `alloy://changelog/{}..{}` — These changes as a resource\n\
+            - `alloy://changelog` — Every recorded change\n\
+            - `alloy://v0/provider/setup` — Provider setup on alloy 0.x\n",
+            short(from),
+            short(to)
+        );
+        result
+    }
+}
+
+/// A version as written in changelog URIs: `0.11` for `0.11.0`.
+fn short(version: Version) -> String {
+    version
+        .to_string()
+        .strip_suffix(".0")
+        .map(String::from)
+        .unwrap_or_else(|| version.to_string())
+}
+
+/// How many changes are listed and which crates they touch.
+fn summary(changes: &[&Change]) -> Option<String> {
+    if changes.is_empty() {
+        return None;
+    }
+    let crates: BTreeSet<&str> = changes.iter().map(|c| c.krate).collect();
+    Some(format!(
+        "{} change(s) across {}.",
+        changes.len(),
+        crates
+            .iter()
+            .map(|c| format!("`{c}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
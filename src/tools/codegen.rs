@@ -0,0 +1,298 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use alloy_dyn_abi::DynSolValue;
+use alloy_primitives::{Address, B256, Bytes, U256, utils::format_units};
+
+/// Format an integer literal with `_` digit grouping (e.g. `30_000_000_000`).
+pub(super) fn int_lit(v: impl ToString) -> String {
+    let s = v.to_string();
+    let (sign, digits) = s.split_at(usize::from(s.starts_with('-')));
+    if digits.len() <= 4 {
+        return s;
+    }
+    let mut out = String::from(sign);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push('_');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// The most natural `U256` expression for a value.
+pub(super) fn u256_expr(v: U256) -> String {
+    if v.is_zero() {
+        "U256::ZERO".into()
+    } else if let Ok(small) = u64::try_from(v) {
+        format!("U256::from({}u64)", int_lit(small))
+    } else if let Ok(medium) = u128::try_from(v) {
+        format!("U256::from({}u128)", int_lit(medium))
+    } else {
+        format!("uint!({}_U256)", int_lit(v))
+    }
+}
+
+/// Render an amount in a display unit as a trailing comment, trimming
+/// trailing zeros (`1.500000000000000000` becomes `1.5`). Dust amounts that
+/// would need more than six decimals get no comment.
+pub(super) fn units_comment(v: U256, unit: &str, suffix: &str) -> String {
+    let formatted = format_units(v, unit).unwrap_or_default();
+    let trimmed = if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &formatted
+    };
+    match trimmed.split_once('.') {
+        _ if v.is_zero() => String::new(),
+        Some((_, fraction)) if fraction.len() > 6 => String::new(),
+        _ => format!(" // {trimmed} {suffix}"),
+    }
+}
+
+pub(super) fn address_lit(a: &Address) -> String {
+    format!("address!(\"{}\")", a.to_checksum(None))
+}
+
+pub(super) fn b256_lit(h: &B256) -> String {
+    format!("b256!(\"{h}\")")
+}
+
+pub(super) fn bytes_expr(b: &Bytes) -> String {
+    if b.is_empty() {
+        "Bytes::new()".into()
+    } else {
+        format!("bytes!(\"{b}\")")
+    }
+}
+
+/// Accumulates `use` items grouped by module path.
+#[derive(Default)]
+pub(super) struct Imports(BTreeMap<String, Vec<String>>);
+
+impl Imports {
+    pub(super) fn add(&mut self, module: impl Into<String>, item: impl Into<String>) {
+        let item = item.into();
+        let items = self.0.entry(module.into()).or_default();
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    }
+
+    pub(super) fn render(&self) -> String {
+        let mut lines: Vec<String> = self
+            .0
+            .iter()
+            .map(|(module, items)| {
+                let mut items = items.clone();
+                // Types before macros, matching rustfmt's ordering.
+                items.sort_by_key(|i| (i.starts_with(char::is_lowercase), i.clone()));
+                if items.len() == 1 {
+                    format!("use {module}::{};", items[0])
+                } else {
+                    format!("use {module}::{{{}}};", items.join(", "))
+                }
+            })
+            .collect();
+        lines.sort();
+        lines.iter().map(|line| format!("{line}\n")).collect()
+    }
+}
+
+/// Rust type name `sol!` uses for a `uintN`/`intN`, and whether it is a
+/// primitive integer (literal-friendly) rather than a `Uint`/`Signed` alias.
+pub(super) fn sol_int_type(bits: usize, signed: bool) -> (String, bool) {
+    let prefix = if signed { 'i' } else { 'u' };
+    match bits {
+        8 | 16 | 32 | 64 | 128 => (format!("{prefix}{bits}"), true),
+        _ => (format!("{}{bits}", prefix.to_ascii_uppercase()), false),
+    }
+}
+
+/// Rust type `sol!` uses for an elementary Solidity type (`uint24` is
+/// `U24`, `bytes32` is `B256`), adding its import. `None` for arrays,
+/// tuples and unknown names.
+pub(super) fn sol_rust_type(ty: &str, imports: &mut Imports) -> Option<String> {
+    let width = |prefix: &str| ty.strip_prefix(prefix).map(str::parse::<usize>);
+    let rust = match ty {
+        "bool" => return Some("bool".into()),
+        "string" => return Some("String".into()),
+        "address" => "Address".to_string(),
+        "bytes" => "Bytes".to_string(),
+        "function" => "Function".to_string(),
+        "uint" | "int" => return sol_rust_type(&format!("{ty}256"), imports),
+        _ => match (width("uint"), width("int"), width("bytes")) {
+            (Some(Ok(bits)), _, _) | (_, Some(Ok(bits)), _)
+                if bits % 8 == 0 && (8..=256).contains(&bits) =>
+            {
+                let (rust, primitive) = sol_int_type(bits, ty.starts_with('i'));
+                if primitive {
+                    return Some(rust);
+                }
+                if bits != 256 {
+                    imports.add("alloy::primitives::aliases", rust.clone());
+                    return Some(rust);
+                }
+                rust
+            }
+            (_, _, Some(Ok(32))) => "B256".to_string(),
+            (_, _, Some(Ok(size))) if (1..=32).contains(&size) => {
+                imports.add("alloy::primitives", "FixedBytes");
+                return Some(format!("FixedBytes<{size}>"));
+            }
+            _ => return None,
+        },
+    };
+    imports.add("alloy::primitives", rust.clone());
+    Some(rust)
+}
+
+/// Render a decoded Solidity value as the Rust expression `sol!` types
+/// accept, e.g. `address!(..)`, `U256::from(..)` or a nested struct literal.
+pub(super) fn sol_value_expr(value: &DynSolValue, indent: usize, imports: &mut Imports) -> String {
+    let pad = "    ".repeat(indent);
+    match value {
+        DynSolValue::Bool(b) => b.to_string(),
+        DynSolValue::Address(a) => {
+            imports.add("alloy::primitives", "address");
+            address_lit(a)
+        }
+        DynSolValue::Uint(v, bits) => match sol_int_type(*bits, false) {
+            (_, true) => int_lit(v),
+            (ty, false) if ty == "U256" => {
+                imports.add("alloy::primitives", "U256");
+                if u128::try_from(*v).is_err() {
+                    imports.add("alloy::primitives", "uint");
+                }
+                u256_expr(*v)
+            }
+            (ty, false) => {
+                imports.add("alloy::primitives::aliases", ty.clone());
+                format!("{ty}::from({})", int_lit(v))
+            }
+        },
+        DynSolValue::Int(v, bits) => match sol_int_type(*bits, true) {
+            (_, true) => int_lit(v),
+            (ty, false) => {
+                let module = if ty == "I256" {
+                    "alloy::primitives"
+                } else {
+                    "alloy::primitives::aliases"
+                };
+                imports.add(module, ty.clone());
+                format!("{ty}::from_dec_str(\"{v}\").unwrap()")
+            }
+        },
+        DynSolValue::FixedBytes(word, 32) => {
+            imports.add("alloy::primitives", "b256");
+            b256_lit(word)
+        }
+        DynSolValue::FixedBytes(word, size) => {
+            imports.add("alloy::primitives", "fixed_bytes");
+            format!(
+                "fixed_bytes!(\"0x{}\")",
+                alloy_primitives::hex::encode(&word[..*size])
+            )
+        }
+        DynSolValue::Bytes(b) => {
+            let b = Bytes::copy_from_slice(b);
+            if b.is_empty() {
+                imports.add("alloy::primitives", "Bytes");
+            } else {
+                imports.add("alloy::primitives", "bytes");
+            }
+            bytes_expr(&b)
+        }
+        DynSolValue::String(s) => format!("String::from({s:?})"),
+        DynSolValue::Function(f) => {
+            imports.add("alloy::primitives", "Function");
+            format!(
+                "Function::from({})",
+                b256_lit(&B256::right_padding_from(f.as_slice()))
+            )
+        }
+        DynSolValue::Array(items) | DynSolValue::FixedArray(items) => {
+            let (open, close) = match value {
+                DynSolValue::Array(_) => ("vec![", "]"),
+                _ => ("[", "]"),
+            };
+            if items.is_empty() {
+                return format!("{open}{close}");
+            }
+            let mut out = format!("{open}\n");
+            for item in items {
+                let _ = writeln!(
+                    out,
+                    "{pad}    {},",
+                    sol_value_expr(item, indent + 1, imports)
+                );
+            }
+            let _ = write!(out, "{pad}{close}");
+            out
+        }
+        DynSolValue::Tuple(items) => {
+            let items: Vec<String> = items
+                .iter()
+                .map(|item| sol_value_expr(item, indent, imports))
+                .collect();
+            if items.len() == 1 {
+                format!("({},)", items[0])
+            } else {
+                format!("({})", items.join(", "))
+            }
+        }
+        DynSolValue::CustomStruct {
+            name,
+            prop_names,
+            tuple,
+        } => {
+            let mut out = format!("{name} {{\n");
+            for (prop, item) in prop_names.iter().zip(tuple) {
+                let _ = writeln!(
+                    out,
+                    "{pad}    {prop}: {},",
+                    sol_value_expr(item, indent + 1, imports)
+                );
+            }
+            let _ = write!(out, "{pad}}}");
+            out
+        }
+    }
+}
+
+/// `maxFeePerGas` or `max-fee-per-gas` as `max_fee_per_gas`.
+pub(super) fn snake(name: &str) -> String {
+    let mut out = String::new();
+    let mut prev_lower = false;
+    for c in name.trim().chars() {
+        if c.is_ascii_uppercase() {
+            if prev_lower {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+            prev_lower = false;
+        } else if c.is_ascii_alphanumeric() {
+            out.push(c);
+            prev_lower = true;
+        } else {
+            if !out.is_empty() && !out.ends_with('_') {
+                out.push('_');
+            }
+            prev_lower = false;
+        }
+    }
+    out.trim_end_matches('_').to_string()
+}
+
+/// Whether `name` can be used as a Rust identifier as is.
+pub(super) fn is_ident(name: &str) -> bool {
+    const KEYWORDS: [&str; 38] = [
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true",
+        "type", "unsafe", "use", "where", "while", "abstract",
+    ];
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&name)
+}
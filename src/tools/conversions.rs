@@ -0,0 +1,326 @@
+use std::fmt::Write;
+
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+
+use super::{codegen::Imports, imports};
+use crate::{
+    conversions::{self, Edge, Via},
+    resources,
+    server::AlloyMcpServer,
+};
+
+/// Names in a type that are never imported.
+const BUILTIN: [&str; 5] = ["str", "u8", "u64", "String", "Vec"];
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct FindConversionRequest {
+    #[schemars(
+        description = "Type you have, e.g. \"TransactionRequest\", \"Signed<TxEip1559>\" or \"Vec<u8>\""
+    )]
+    from: String,
+    #[schemars(description = "Type you need, e.g. \"TxEnvelope\" or \"Recovered<TxEnvelope>\"")]
+    to: String,
+}
+
+/// A `From`/`TryFrom` impl bullet of an API page's `Trait Implementations`:
+/// ``- `impl From<X> for Y` (feature `f`)``. Generic impls are skipped.
+fn api_edge(line: &str) -> Option<Edge> {
+    let rest = line.strip_prefix("- `impl ")?;
+    let (header, tail) = rest.split_once('`')?;
+    let (via, header) = if let Some(header) = header.strip_prefix("From<") {
+        (Via::From, header)
+    } else {
+        (Via::TryFrom, header.strip_prefix("TryFrom<")?)
+    };
+    let mut depth = 1;
+    let end = header.find(|c| {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            _ => {}
+        }
+        depth == 0
+    })?;
+    let from = conversions::key(&header[..end]);
+    let to = header[end + 1..].strip_prefix(" for ")?;
+    let to = conversions::key(to.split(" where ").next().unwrap_or(to));
+    if from == to {
+        return None;
+    }
+    let code = match (via, to.contains('<')) {
+        (Via::From, false) => format!("{to}::from({{x}})"),
+        (Via::From, true) => "{x}.into()".into(),
+        (_, false) => format!("{to}::try_from({{x}})?"),
+        (_, true) => "{x}.try_into()?".into(),
+    };
+    let mut notes = Vec::new();
+    if let Some(error) = tail
+        .split_once("`type Error = ")
+        .and_then(|(_, e)| e.split('`').next())
+    {
+        notes.push(format!("Fails with `{error}`."));
+    }
+    if let Some(feature) = tail
+        .split_once("(feature `")
+        .and_then(|(_, f)| f.split('`').next())
+    {
+        notes.push(format!("Needs feature `{feature}`."));
+    }
+    Some(Edge {
+        from,
+        to,
+        via,
+        setup: String::new(),
+        code,
+        needs: Vec::new(),
+        uses: Vec::new(),
+        note: notes.join(" "),
+        api: true,
+    })
+}
+
+/// Types named in `key`, e.g. `Signed` and `TxEip1559` in `Signed<TxEip1559>`.
+fn names(key: &str) -> impl Iterator<Item = &str> {
+    key.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|n| n.starts_with(char::is_alphabetic) && !BUILTIN.contains(n))
+}
+
+impl AlloyMcpServer {
+    /// The curated steps followed by the non-generic `From`/`TryFrom` impls
+    /// of the generated API pages that the steps do not already cover.
+    fn conversion_edges(&self) -> Vec<Edge> {
+        let mut edges = conversions::edges();
+        let mut api: Vec<Edge> = self
+            .resources
+            .values()
+            .filter(|r| r.uri.starts_with("alloy://api/"))
+            .filter_map(|page| resources::section(&page.content, "trait-implementations"))
+            .flat_map(|section| section.lines().filter_map(api_edge))
+            .collect();
+        api.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+        for edge in api {
+            let covered = edges.iter().any(|e| {
+                conversions::key(&e.from) == edge.from && conversions::key(&e.to) == edge.to
+            });
+            if !covered {
+                edges.push(edge);
+            }
+        }
+        edges
+    }
+}
+
+#[tool_router(router = conversions_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Find the shortest chain of conversions between two types over the
+    /// steps behind `alloy://reference/conversions` and the API pages.
+    #[tool(
+        description = "Find how to turn one alloy type into another, e.g. TransactionRequest → TxEnvelope or Signed<TxEip1559> → Recovered<TxEnvelope>. Searches From/TryFrom impls, builder methods, signing, sender recovery and EIP-2718 encoding for the shortest path and returns each step with the Rust code, imports and caveats. Uses the From/TryFrom impls of the generated API pages too when the server is started with --rustdoc."
+    )]
+    fn find_conversion(
+        &self,
+        Parameters(FindConversionRequest { from, to }): Parameters<FindConversionRequest>,
+    ) -> String {
+        if from.trim().is_empty() || to.trim().is_empty() {
+            return "Both type names are needed. Pass the type you have as `from` and the type \
+                you need as `to`, e.g. `TransactionRequest` and `TxEnvelope`."
+                .into();
+        }
+        let edges = self.conversion_edges();
+        // Types as the table spells them, so `signed<txeip1559>` still matches.
+        let known = |ty: &str| {
+            let key = conversions::key(ty);
+            edges
+                .iter()
+                .flat_map(|e| [&e.from, &e.to])
+                .find(|t| conversions::key(t).eq_ignore_ascii_case(&key))
+                .cloned()
+                .unwrap_or_else(|| key.clone())
+        };
+        let (from, to) = (known(from.trim()), known(to.trim()));
+        let (from_key, to_key) = (conversions::key(&from), conversions::key(&to));
+        let mut result = format!("# `{from}` → `{to}`\n\n");
+
+        if from_key == to_key {
+            let _ = writeln!(result, "Both name the same type; no conversion is needed.");
+            return result;
+        }
+        let Some(path) = conversions::shortest(&edges, &from, &to) else {
+            let out: Vec<String> = edges
+                .iter()
+                .filter(|e| conversions::key(&e.from) == from_key)
+                .map(|e| format!("`{}`", e.to))
+                .collect();
+            let into: Vec<String> = edges
+                .iter()
+                .filter(|e| conversions::key(&e.to) == to_key)
+                .map(|e| format!("`{}`", e.from))
+                .collect();
+            let _ = writeln!(
+                result,
+                "No chain of known conversions leads from `{from}` to `{to}`.\n"
+            );
+            for (ty, list, verb) in [(&from, out, "converts to"), (&to, into, "is made from")] {
+                let _ = writeln!(
+                    result,
+                    "- `{ty}` {}",
+                    if list.is_empty() {
+                        "is not in the conversion table.".to_string()
+                    } else {
+                        format!("{verb} {}.", list.join(", "))
+                    }
+                );
+            }
+            let _ = write!(
+                result,
+                "\nCheck the spelling against `alloy://reference/conversions`, or call \
+                `list_methods` on `{from}` for methods that return `{to}`.\n"
+            );
+            return result;
+        };
+
+        result.push_str("| # | From | To | How |\n|---|------|----|-----|\n");
+        for (i, edge) in path.iter().enumerate() {
+            let how = edge.code.replace("{x}", &conversions::binding(&edge.from));
+            let _ = writeln!(
+                result,
+                "| {} | `{}` | `{}` | {} `{how}` |",
+                i + 1,
+                edge.from,
+                edge.to,
+                edge.via.title()
+            );
+        }
+
+        let paths = resources::lookup(&self.resources, "alloy://imports/paths").unwrap_or("");
+        let mut imports = Imports::default();
+        let mut needs: Vec<&String> = Vec::new();
+        let mut body = String::new();
+        // A step that borrows its input mutably (signing) needs a `mut` binding.
+        let borrows_mut: Vec<bool> = path
+            .iter()
+            .map(|e| e.setup.contains("&mut {x})") || e.code.contains("&mut {x})"))
+            .collect();
+        let mut input = conversions::binding(&from);
+        for (i, edge) in path.iter().enumerate() {
+            let output = conversions::binding(&edge.to);
+            if !edge.setup.is_empty() {
+                let _ = writeln!(body, "{}", edge.setup.replace("{x}", &input));
+            }
+            let _ = writeln!(
+                body,
+                "let {}{output}: {} = {};",
+                if borrows_mut.get(i + 1) == Some(&true) {
+                    "mut "
+                } else {
+                    ""
+                },
+                edge.to,
+                edge.code.replace("{x}", &input)
+            );
+            for path in &edge.uses {
+                if let Some((module, item)) = path.rsplit_once("::") {
+                    imports.add(module, item);
+                }
+            }
+            for name in names(&edge.to) {
+                if let Some((module, item)) = imports::import_path(paths, name)
+                    .as_deref()
+                    .and_then(|p| p.rsplit_once("::"))
+                {
+                    imports.add(module, item);
+                }
+            }
+            for need in &edge.needs {
+                if !needs.contains(&need) {
+                    needs.push(need);
+                }
+            }
+            input = output;
+        }
+        let given: Vec<String> = [format!(
+            "{}{}: {from}",
+            if borrows_mut[0] { "mut " } else { "" },
+            conversions::binding(&from)
+        )]
+        .into_iter()
+        .chain(needs.iter().map(|n| n.to_string()))
+        .collect();
+        let _ = write!(
+            result,
+            "\n## Rust\n\n```rust\n{}\n// Given {}\n{body}```\n",
+            imports.render(),
+            given.join(", ")
+        );
+
+        let mut notes: Vec<String> = path
+            .iter()
+            .filter(|e| !e.note.is_empty())
+            .map(|e| format!("`{}` → `{}`: {}", e.from, e.to, e.note))
+            .collect();
+        if path.iter().any(|e| e.code.contains(".await")) {
+            notes.push("The code awaits, so it runs inside an `async fn`.".into());
+        }
+        if path
+            .iter()
+            .any(|e| e.code.contains('?') || e.setup.contains('?'))
+        {
+            notes.push(
+                "`?` needs the surrounding function to return a `Result` that every step's error \
+                converts into, such as `eyre::Result<()>`."
+                    .into(),
+            );
+        }
+        let from_api: Vec<String> = path
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.api)
+            .map(|(i, _)| (i + 1).to_string())
+            .collect();
+        if !from_api.is_empty() {
+            notes.push(format!(
+                "Step {} comes from a `From`/`TryFrom` impl on the loaded API pages rather \
+                than the curated table.",
+                from_api.join(", ")
+            ));
+        }
+        if !notes.is_empty() {
+            result.push_str("\n## Notes\n\n");
+            for note in &notes {
+                let _ = writeln!(result, "- {note}");
+            }
+        }
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://reference/conversions` — Every conversion step\n\
+            - `alloy://consensus/recovered` — Recovered transactions and sender recovery\n\
+            - `alloy://primitives/core-types` — Primitive types and their conversions\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(from: &str, to: &str) -> String {
+        AlloyMcpServer::new().find_conversion(Parameters(FindConversionRequest {
+            from: from.into(),
+            to: to.into(),
+        }))
+    }
+
+    #[test]
+    fn chains_conversions() {
+        let result = find("Signed<TxEip1559>", "Recovered<TxEnvelope>");
+        assert!(result.contains("| 1 | `Signed<TxEip1559>` | `TxEnvelope` |"));
+        assert!(result.contains("| 2 | `TxEnvelope` | `Recovered<TxEnvelope>` |"));
+    }
+
+    #[test]
+    fn asks_for_both_type_names() {
+        assert!(find(" ", "TxEnvelope").starts_with("Both type names are needed."));
+        assert!(find("TxEnvelope", "txenvelope").contains("no conversion is needed"));
+    }
+}
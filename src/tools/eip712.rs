@@ -0,0 +1,262 @@
+use std::fmt::Write;
+
+use alloy_dyn_abi::{Eip712Types, TypedData};
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+use serde_json::Value;
+
+use super::codegen::{Imports, address_lit, b256_lit, int_lit, sol_value_expr};
+use crate::server::AlloyMcpServer;
+
+/// Canonical `EIP712Domain` field order, with the key each uses in the
+/// `domain` JSON object.
+const DOMAIN_FIELDS: [&str; 5] = ["name", "version", "chainId", "verifyingContract", "salt"];
+
+/// Compare the declared `EIP712Domain` type against the fields actually
+/// present in `domain`, since alloy hashes only the latter.
+fn domain_notes(typed_data: &TypedData) -> Vec<String> {
+    let domain = &typed_data.domain;
+    let present: Vec<&str> = [
+        domain.name.is_some(),
+        domain.version.is_some(),
+        domain.chain_id.is_some(),
+        domain.verifying_contract.is_some(),
+        domain.salt.is_some(),
+    ]
+    .into_iter()
+    .zip(DOMAIN_FIELDS)
+    .filter_map(|(set, name)| set.then_some(name))
+    .collect();
+
+    let types = Eip712Types::from(&typed_data.resolver);
+    let Some(declared) = types.get("EIP712Domain") else {
+        return vec![format!(
+            "`types.EIP712Domain` is not declared; the separator was computed from the domain fields present: {}.",
+            present.join(", ")
+        )];
+    };
+    let declared: Vec<&str> = declared.iter().map(|p| p.name()).collect();
+
+    let mut notes = Vec::new();
+    let mut sorted = declared.clone();
+    sorted.sort_by_key(|name| DOMAIN_FIELDS.iter().position(|f| f == name));
+    if sorted != declared {
+        notes.push(format!(
+            "`types.EIP712Domain` declares fields as ({}); the canonical order is ({}). \
+            Wallets hash the declared order, alloy always uses the canonical one.",
+            declared.join(", "),
+            sorted.join(", ")
+        ));
+    }
+    let mut declared_set = declared.clone();
+    declared_set.sort_unstable();
+    let mut present_set = present.clone();
+    present_set.sort_unstable();
+    if declared_set != present_set {
+        notes.push(format!(
+            "`types.EIP712Domain` declares ({}) but `domain` sets ({}). alloy hashes the fields \
+            present in `domain`; a wallet hashing the declared list will produce a different \
+            signature.",
+            declared.join(", "),
+            present.join(", ")
+        ));
+    }
+    notes
+}
+
+/// Render the `eip712_domain!` invocation for the payload's domain.
+fn domain_code(typed_data: &TypedData, imports: &mut Imports) -> String {
+    let domain = &typed_data.domain;
+    imports.add("alloy::sol_types", "eip712_domain");
+    let mut out = String::from("let domain = eip712_domain! {\n");
+    if let Some(name) = &domain.name {
+        let _ = writeln!(out, "    name: {name:?},");
+    }
+    if let Some(version) = &domain.version {
+        let _ = writeln!(out, "    version: {version:?},");
+    }
+    if let Some(chain_id) = &domain.chain_id {
+        let _ = writeln!(out, "    chain_id: {},", int_lit(chain_id));
+    }
+    if let Some(contract) = &domain.verifying_contract {
+        imports.add("alloy::primitives", "address");
+        let _ = writeln!(out, "    verifying_contract: {},", address_lit(contract));
+    }
+    if let Some(salt) = &domain.salt {
+        imports.add("alloy::primitives", "b256");
+        let _ = writeln!(out, "    salt: {},", b256_lit(salt));
+    }
+    out.push_str("};\n");
+    out
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct Eip712HashRequest {
+    #[schemars(
+        description = "EIP-712 typed data as passed to eth_signTypedData_v4: an object (or JSON string) with `types`, `primaryType`, `domain` and `message`"
+    )]
+    typed_data: Value,
+}
+
+#[tool_router(router = eip712_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Compute EIP-712 hashes for a typed data payload and show the matching
+    /// `sol!` + `eip712_domain!` code.
+    #[tool(
+        description = "Compute the EIP-712 domain separator, struct hash and signing hash for a typed data JSON payload (domain, types, primaryType, message), plus the equivalent sol! struct and eip712_domain! Rust code."
+    )]
+    fn eip712_hash(
+        &self,
+        Parameters(Eip712HashRequest { typed_data }): Parameters<Eip712HashRequest>,
+    ) -> String {
+        let typed_data: TypedData = match serde_json::from_value(typed_data) {
+            Ok(t) => t,
+            Err(e) => return format!("Invalid EIP-712 typed data: {e}"),
+        };
+        let primary = typed_data.primary_type.as_str();
+
+        let separator = typed_data.domain.separator();
+        let hashes = typed_data.encode_type().and_then(|encoded| {
+            Ok((
+                encoded,
+                typed_data.type_hash()?,
+                typed_data.hash_struct()?,
+                typed_data.eip712_signing_hash()?,
+            ))
+        });
+        let (encoded_type, type_hash, struct_hash, signing_hash) = match hashes {
+            Ok(h) => h,
+            Err(e) => {
+                return format!(
+                    "Could not hash `{primary}`: {e}\n\nCheck that every type referenced by \
+                    `{primary}` is declared in `types` and that `message` matches its fields."
+                );
+            }
+        };
+
+        let mut result = format!(
+            "# EIP-712 hashes for `{primary}`\n\n\
+            | Value | Hash |\n|-------|------|\n\
+            | Domain separator | `{separator}` |\n\
+            | Type hash | `{type_hash}` |\n\
+            | Struct hash (`hashStruct(message)`) | `{struct_hash}` |\n\
+            | Signing hash | `{signing_hash}` |\n\n\
+            Encoded type:\n\n```text\n{encoded_type}\n```\n\n\
+            The signing hash is `keccak256(0x1901 ‖ domainSeparator ‖ structHash)`; sign it with \
+            `signer.sign_hash(&hash)`, not `sign_message` (which adds the EIP-191 prefix).\n\n"
+        );
+
+        // `sol!` struct definitions, primary type first, fields in declared
+        // order (the type hash depends on it).
+        let mut imports = Imports::default();
+        imports.add("alloy", "sol");
+        imports.add("alloy::sol_types", "SolStruct");
+        let mut sol_block = String::from("sol! {\n    #[derive(Debug)]\n");
+        let defs = typed_data.resolver.linearize(primary).unwrap_or_default();
+        for (i, def) in defs.iter().enumerate() {
+            if i > 0 {
+                sol_block.push_str("\n    #[derive(Debug)]\n");
+            }
+            let _ = writeln!(sol_block, "    struct {} {{", def.type_name());
+            for prop in def.props() {
+                let _ = writeln!(sol_block, "        {} {};", prop.type_name(), prop.name());
+            }
+            sol_block.push_str("    }\n");
+        }
+        sol_block.push_str("}\n");
+
+        let domain = domain_code(&typed_data, &mut imports);
+        let message = match typed_data.coerce() {
+            Ok(value) => format!(
+                "let message = {};\n",
+                sol_value_expr(&value, 0, &mut imports)
+            ),
+            Err(e) => format!("// could not convert `message` into a literal: {e}\n"),
+        };
+        imports.add("alloy::primitives", "b256");
+
+        let _ = write!(
+            result,
+            "## Rust\n\n```rust\n{}\n{sol_block}\n{domain}\n{message}\n\
+            let hash = message.eip712_signing_hash(&domain);\n\
+            assert_eq!(hash, {});\n```\n",
+            imports.render(),
+            b256_lit(&signing_hash)
+        );
+
+        let mut notes = domain_notes(&typed_data);
+        notes.push(
+            "Struct field order is part of the type hash: keep the `sol!` fields in the order \
+            `types` declares them."
+                .into(),
+        );
+        notes.push(
+            "To hash JSON at runtime instead, deserialize into `alloy::dyn_abi::TypedData` \
+            (`dyn-abi` + `eip712` features) and call `typed_data.eip712_signing_hash()`."
+                .into(),
+        );
+        result.push_str("\n## Notes\n\n");
+        for note in &notes {
+            let _ = writeln!(result, "- {note}");
+        }
+
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://signers/signing-guide` — EIP-712 Typed Data Signing\n\
+            - `alloy://sol-macro/sol-types` — SolStruct for EIP-712\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The `Mail` example from the EIP-712 specification.
+    #[test]
+    fn hashes_the_spec_mail_example() {
+        let typed_data = serde_json::json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" }
+                ],
+                "Person": [
+                    { "name": "name", "type": "string" },
+                    { "name": "wallet", "type": "address" }
+                ],
+                "Mail": [
+                    { "name": "from", "type": "Person" },
+                    { "name": "to", "type": "Person" },
+                    { "name": "contents", "type": "string" }
+                ]
+            },
+            "primaryType": "Mail",
+            "domain": {
+                "name": "Ether Mail",
+                "version": "1",
+                "chainId": 1,
+                "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+            },
+            "message": {
+                "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+                "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+                "contents": "Hello, Bob!"
+            }
+        });
+        let result =
+            AlloyMcpServer::new().eip712_hash(Parameters(Eip712HashRequest { typed_data }));
+        for hash in [
+            "| Domain separator | `0xf2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f` |",
+            "| Struct hash (`hashStruct(message)`) | `0xc52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e` |",
+            "| Signing hash | `0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2` |",
+        ] {
+            assert!(result.contains(hash), "missing {hash} in:\n{result}");
+        }
+        assert!(result.contains(
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        ));
+    }
+}
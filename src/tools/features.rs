@@ -0,0 +1,748 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+};
+
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+
+use crate::{
+    server::AlloyMcpServer,
+    workspace::{Dependency, Workspace},
+};
+
+/// Part of alloy's API behind a cargo feature.
+pub(super) struct Gate {
+    /// Identifiers, paths or method names that need the feature. A trailing
+    /// `*` matches any identifier with that prefix.
+    patterns: &'static [&'static str],
+    /// Feature on the `alloy` umbrella crate.
+    pub(super) feature: &'static str,
+    /// Crate that provides the item when depending on `alloy-*` directly.
+    pub(super) krate: &'static str,
+    /// Feature needed on that crate, if any.
+    pub(super) crate_feature: Option<&'static str>,
+}
+
+const fn gate(
+    patterns: &'static [&'static str],
+    feature: &'static str,
+    krate: &'static str,
+    crate_feature: Option<&'static str>,
+) -> Gate {
+    Gate {
+        patterns,
+        feature,
+        krate,
+        crate_feature,
+    }
+}
+
+/// Feature gates, from the alloy 1.x manifests.
+const GATES: &[Gate] = &[
+    // Providers and transports
+    gate(
+        &["ProviderBuilder", "RootProvider", "DynProvider", "Provider"],
+        "providers",
+        "alloy-provider",
+        None,
+    ),
+    gate(
+        &["connect", "connect_http", "Http"],
+        "provider-http",
+        "alloy-provider",
+        Some("reqwest"),
+    ),
+    gate(
+        &["connect_ws", "WsConnect"],
+        "provider-ws",
+        "alloy-provider",
+        Some("ws"),
+    ),
+    gate(
+        &["connect_ipc", "IpcConnect"],
+        "provider-ipc",
+        "alloy-provider",
+        Some("ipc"),
+    ),
+    gate(
+        &[
+            "subscribe_*",
+            "connect_pubsub_with",
+            "PubSubFrontend",
+            "Subscription",
+        ],
+        "pubsub",
+        "alloy-provider",
+        Some("pubsub"),
+    ),
+    gate(
+        &["connect_anvil*"],
+        "provider-anvil-node",
+        "alloy-provider",
+        Some("anvil-node"),
+    ),
+    gate(
+        &["AnvilApi", "anvil_*"],
+        "provider-anvil-api",
+        "alloy-provider",
+        Some("anvil-api"),
+    ),
+    gate(
+        &["DebugApi", "debug_trace_*"],
+        "provider-debug-api",
+        "alloy-provider",
+        Some("debug-api"),
+    ),
+    gate(
+        &["TraceApi", "trace_*"],
+        "provider-trace-api",
+        "alloy-provider",
+        Some("trace-api"),
+    ),
+    gate(
+        &["TxPoolApi", "txpool_*"],
+        "provider-txpool-api",
+        "alloy-provider",
+        Some("txpool-api"),
+    ),
+    gate(
+        &["ClientBuilder", "RpcClient"],
+        "rpc-client",
+        "alloy-rpc-client",
+        None,
+    ),
+    gate(
+        &["Anvil", "AnvilInstance", "node_bindings"],
+        "node-bindings",
+        "alloy-node-bindings",
+        None,
+    ),
+    gate(
+        &["ProviderEnsExt", "NameOrAddress", "alloy::ens"],
+        "ens",
+        "alloy-ens",
+        None,
+    ),
+    // Signers
+    gate(
+        &["PrivateKeySigner", "LocalSigner"],
+        "signer-local",
+        "alloy-signer-local",
+        None,
+    ),
+    gate(
+        &["MnemonicBuilder", "coins_bip39"],
+        "signer-mnemonic",
+        "alloy-signer-local",
+        Some("mnemonic"),
+    ),
+    gate(
+        &["decrypt_keystore", "encrypt_keystore", "new_keystore"],
+        "signer-keystore",
+        "alloy-signer-local",
+        Some("keystore"),
+    ),
+    gate(
+        &["LedgerSigner", "HDPath"],
+        "signer-ledger",
+        "alloy-signer-ledger",
+        None,
+    ),
+    gate(
+        &["TrezorSigner"],
+        "signer-trezor",
+        "alloy-signer-trezor",
+        None,
+    ),
+    gate(&["AwsSigner"], "signer-aws", "alloy-signer-aws", None),
+    gate(&["GcpSigner"], "signer-gcp", "alloy-signer-gcp", None),
+    gate(
+        &[
+            "sign_typed_data",
+            "sign_typed_data_sync",
+            "sign_dynamic_typed_data",
+        ],
+        "eip712",
+        "alloy-signer",
+        Some("eip712"),
+    ),
+    gate(&["TypedData"], "eip712", "alloy-dyn-abi", Some("eip712")),
+    gate(
+        &[
+            "recover_address_from_msg",
+            "recover_address_from_prehash",
+            "recover_from_prehash",
+        ],
+        "k256",
+        "alloy-primitives",
+        Some("k256"),
+    ),
+    // Types
+    gate(
+        &[
+            "TxEnvelope",
+            "TxEip1559",
+            "TxLegacy",
+            "TxEip4844",
+            "TxEip7702",
+            "TypedTransaction",
+            "Signed",
+            "consensus::",
+        ],
+        "consensus",
+        "alloy-consensus",
+        None,
+    ),
+    gate(
+        &[
+            "SidecarBuilder",
+            "build_4844",
+            "build_7594",
+            "EnvKzgSettings",
+        ],
+        "kzg",
+        "alloy-consensus",
+        Some("kzg"),
+    ),
+    gate(
+        &[
+            "BlockId",
+            "BlockNumberOrTag",
+            "Encodable2718",
+            "Decodable2718",
+            "eips::",
+        ],
+        "eips",
+        "alloy-eips",
+        None,
+    ),
+    gate(
+        &[
+            "EthereumWallet",
+            "TransactionBuilder",
+            "AnyNetwork",
+            "NetworkWallet",
+            "TxSigner",
+            "TxSignerSync",
+        ],
+        "network",
+        "alloy-network",
+        None,
+    ),
+    gate(
+        &["TransactionRequest", "TransactionReceipt", "rpc::types"],
+        "rpc-types",
+        "alloy-rpc-types-eth",
+        None,
+    ),
+    gate(
+        &["rpc::types::trace"],
+        "rpc-types-trace",
+        "alloy-rpc-types-trace",
+        None,
+    ),
+    gate(
+        &["rpc::types::engine"],
+        "rpc-types-engine",
+        "alloy-rpc-types-engine",
+        None,
+    ),
+    gate(
+        &["rpc::types::beacon"],
+        "rpc-types-beacon",
+        "alloy-rpc-types-beacon",
+        None,
+    ),
+    gate(
+        &["rpc::types::mev"],
+        "rpc-types-mev",
+        "alloy-rpc-types-mev",
+        None,
+    ),
+    gate(
+        &["Genesis", "alloy::genesis"],
+        "genesis",
+        "alloy-genesis",
+        None,
+    ),
+    gate(&["alloy::trie", "HashBuilder"], "trie", "alloy-trie", None),
+    gate(
+        &["RlpEncodable", "RlpDecodable", "alloy::rlp"],
+        "rlp",
+        "alloy-rlp",
+        None,
+    ),
+    // ABI and contracts
+    gate(
+        &[
+            "sol!",
+            "SolCall",
+            "SolEvent",
+            "SolValue",
+            "SolStruct",
+            "SolError",
+            "sol_types",
+        ],
+        "sol-types",
+        "alloy-sol-types",
+        None,
+    ),
+    gate(
+        &[
+            "sol(rpc",
+            "ContractInstance",
+            "CallBuilder",
+            "alloy::contract",
+        ],
+        "contract",
+        "alloy-contract",
+        None,
+    ),
+    gate(&[".json\""], "json", "alloy-sol-types", Some("json")),
+    gate(
+        &["DynSolValue", "DynSolType", "dyn_abi"],
+        "dyn-abi",
+        "alloy-dyn-abi",
+        None,
+    ),
+    gate(&["JsonAbi", "json_abi"], "json-abi", "alloy-json-abi", None),
+];
+
+/// Umbrella features and the features they enable, for the ones the gates
+/// above use and `full`. `signers` pulls in `k256` through `alloy-signer`'s
+/// `alloy-primitives` dependency rather than the umbrella feature.
+const IMPLIES: &[(&str, &[&str])] = &[
+    (
+        "full",
+        &[
+            "consensus",
+            "eips",
+            "essentials",
+            "k256",
+            "kzg",
+            "network",
+            "provider-ws",
+            "provider-ipc",
+            "provider-trace-api",
+            "provider-txpool-api",
+            "provider-debug-api",
+            "provider-anvil-api",
+            "pubsub",
+            "rlp",
+        ],
+    ),
+    (
+        "essentials",
+        &["contract", "provider-http", "rpc-types", "signer-local"],
+    ),
+    (
+        "contract",
+        &["providers", "dyn-abi", "json-abi", "json", "sol-types"],
+    ),
+    (
+        "providers",
+        &["rpc-client", "transports", "eips", "consensus", "network"],
+    ),
+    ("rpc-client", &["rpc", "transports", "transport-http"]),
+    ("consensus", &["rlp"]),
+    ("eips", &["rlp"]),
+    ("provider-http", &["providers", "transport-http"]),
+    ("provider-ws", &["providers", "transport-ws"]),
+    ("provider-ipc", &["providers", "transport-ipc"]),
+    ("transport-ws", &["transports", "pubsub"]),
+    ("transport-ipc", &["transports", "pubsub"]),
+    ("transport-http", &["transports"]),
+    ("rpc-types", &["rpc", "serde"]),
+    ("signer-local", &["signers"]),
+    ("signer-mnemonic", &["signer-local"]),
+    ("signer-keystore", &["signer-local"]),
+    ("signer-ledger", &["signers"]),
+    ("signer-trezor", &["signers"]),
+    ("signer-aws", &["signers"]),
+    ("signer-gcp", &["signers"]),
+    ("signers", &["k256"]),
+    (
+        "provider-anvil-node",
+        &["providers", "provider-anvil-api", "node-bindings"],
+    ),
+    ("provider-anvil-api", &["providers", "rpc-types-anvil"]),
+    (
+        "provider-debug-api",
+        &["providers", "rpc-types-debug", "rpc-types-trace"],
+    ),
+    ("provider-trace-api", &["providers", "rpc-types-trace"]),
+    ("provider-txpool-api", &["providers", "rpc-types-txpool"]),
+    ("rpc-types-anvil", &["rpc-types"]),
+    ("rpc-types-debug", &["rpc-types"]),
+    ("rpc-types-trace", &["rpc-types"]),
+    ("rpc-types-txpool", &["rpc-types"]),
+    ("rpc-types-engine", &["rpc-types"]),
+    ("rpc-types-beacon", &["rpc-types"]),
+    ("rpc-types-mev", &["rpc-types"]),
+];
+
+/// The umbrella crate's default features.
+const DEFAULT_FEATURES: [&str; 4] = ["std", "reqwest", "reqwest-rustls-tls", "essentials"];
+
+/// Default features of individual crates, for the crate features the gates
+/// above use.
+const CRATE_DEFAULTS: &[(&str, &[&str])] = &[
+    ("alloy-provider", &["reqwest"]),
+    ("alloy-rpc-client", &["reqwest"]),
+    ("alloy-transport-http", &["reqwest"]),
+];
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// The first mention of `pattern` in `code`, matching identifier edges as
+/// whole words and a trailing `*` as any identifier suffix (the mention
+/// then includes the suffix).
+fn mention<'a>(code: &'a str, pattern: &str) -> Option<&'a str> {
+    let (pattern, prefix) = match pattern.strip_suffix('*') {
+        Some(p) => (p, true),
+        None => (pattern, false),
+    };
+    code.match_indices(pattern).find_map(|(pos, _)| {
+        let end = pos + pattern.len();
+        let before = code[..pos].chars().next_back();
+        let after = code[end..].chars().next();
+        if pattern.starts_with(is_ident_char) && before.is_some_and(is_ident_char) {
+            return None;
+        }
+        if prefix {
+            let len = code[end..]
+                .find(|c: char| !is_ident_char(c))
+                .unwrap_or(code.len() - end);
+            return Some(&code[pos..end + len]);
+        }
+        if pattern.ends_with(is_ident_char) && after.is_some_and(is_ident_char) {
+            return None;
+        }
+        Some(&code[pos..end])
+    })
+}
+
+/// All features enabled by `features`, including themselves.
+fn closure<'a>(features: impl IntoIterator<Item = &'a str>) -> BTreeSet<&'a str> {
+    let mut enabled = BTreeSet::new();
+    let mut stack: Vec<&str> = features.into_iter().collect();
+    while let Some(feature) = stack.pop() {
+        if enabled.insert(feature)
+            && let Some((_, implied)) = IMPLIES.iter().find(|(f, _)| *f == feature)
+        {
+            stack.extend(implied.iter().copied());
+        }
+    }
+    enabled
+}
+
+/// Drop features another listed feature already enables.
+fn minimize<'a>(features: &BTreeSet<&'a str>) -> Vec<&'a str> {
+    features
+        .iter()
+        .copied()
+        .filter(|&f| !features.iter().any(|&g| g != f && closure([g]).contains(f)))
+        .collect()
+}
+
+/// The gates `code` mentions, with the mention that matched each.
+pub(super) fn gates_in(code: &str) -> Vec<(&'static Gate, &str)> {
+    GATES
+        .iter()
+        .filter_map(|gate| {
+            gate.patterns
+                .iter()
+                .find_map(|p| mention(code, p))
+                .map(|m| (gate, m))
+        })
+        .collect()
+}
+
+/// The umbrella features a dependency on `alloy` ends up with.
+pub(super) fn umbrella_features<'a>(
+    features: impl IntoIterator<Item = &'a str>,
+    default_features: bool,
+) -> BTreeSet<&'a str> {
+    let defaults = default_features
+        .then_some(DEFAULT_FEATURES)
+        .into_iter()
+        .flatten();
+    closure(features.into_iter().chain(defaults))
+}
+
+/// Whether an `alloy-*` dependency with `features` has `feature` on.
+pub(super) fn crate_has_feature<'a>(
+    krate: &str,
+    feature: &str,
+    mut features: impl Iterator<Item = &'a str>,
+    default_features: bool,
+) -> bool {
+    features.any(|f| f == feature)
+        || (default_features
+            && CRATE_DEFAULTS
+                .iter()
+                .any(|(c, defaults)| *c == krate && defaults.contains(&feature)))
+}
+
+/// The gate naming `item` exactly, for tools that already know which item
+/// they are dealing with.
+pub(super) fn gate_for(item: &str) -> Option<(&'static str, &'static str, Option<&'static str>)> {
+    GATES
+        .iter()
+        .find(|gate| {
+            gate.patterns.iter().any(|p| match p.strip_suffix('*') {
+                Some(prefix) => item.starts_with(prefix),
+                None => *p == item,
+            })
+        })
+        .map(|gate| (gate.feature, gate.krate, gate.crate_feature))
+}
+
+/// Whether the umbrella crate's default features enable `feature`.
+pub(super) fn is_default(feature: &str) -> bool {
+    closure(DEFAULT_FEATURES).contains(feature)
+}
+
+fn toml_list(features: &[&str]) -> String {
+    let quoted: Vec<String> = features.iter().map(|f| format!("{f:?}")).collect();
+    format!("[{}]", quoted.join(", "))
+}
+
+/// The project's own dependency lines for the features `needed` (on the
+/// umbrella crate) or `crates` (crate to crate features), and what it lacks.
+fn project_section(
+    workspace: &Workspace,
+    needed: &BTreeSet<&str>,
+    crates: &BTreeMap<&str, BTreeSet<&str>>,
+) -> String {
+    let mut lines = Vec::new();
+    let mut notes = Vec::new();
+    match workspace.umbrella() {
+        Some(alloy) => {
+            let enabled = umbrella_features(
+                alloy.features.iter().map(String::as_str),
+                alloy.default_features,
+            );
+            let missing: BTreeSet<&str> = needed.difference(&enabled).copied().collect();
+            for feature in &missing {
+                notes.push(format!("`alloy`'s `{feature}` feature is off."));
+            }
+            if !missing.is_empty() {
+                lines.push(alloy.line("alloy", minimize(&missing)));
+            }
+        }
+        None => {
+            for (krate, features) in crates {
+                let Some(dependency) = workspace.dependencies.get(*krate) else {
+                    notes.push(format!("The project does not depend on `{krate}`."));
+                    let added = Dependency {
+                        requirement: Some(workspace.requirement()),
+                        default_features: true,
+                        ..Default::default()
+                    };
+                    lines.push(added.line(krate, features.iter().copied()));
+                    continue;
+                };
+                let missing: Vec<&str> = features
+                    .iter()
+                    .copied()
+                    .filter(|f| {
+                        !crate_has_feature(
+                            krate,
+                            f,
+                            dependency.features.iter().map(String::as_str),
+                            dependency.default_features,
+                        )
+                    })
+                    .collect();
+                for feature in &missing {
+                    notes.push(format!(
+                        "`{krate}`'s `{feature}` feature is off{}.",
+                        if dependency.default_features {
+                            ""
+                        } else {
+                            " (the project sets `default-features = false`)"
+                        }
+                    ));
+                }
+                if !missing.is_empty() {
+                    lines.push(dependency.line(krate, missing));
+                }
+            }
+        }
+    }
+
+    let mut section = format!(
+        "\n## Project\n\nChecked against {} in `{}`.\n\n",
+        workspace.summary(),
+        workspace.root.display()
+    );
+    if lines.is_empty() {
+        section.push_str("The project's dependencies already enable all of these features.\n");
+        return section;
+    }
+    for note in &notes {
+        let _ = writeln!(section, "- {note}");
+    }
+    let _ = write!(section, "\n```toml\n{}\n```\n", lines.join("\n"));
+    section
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct RequiredFeaturesRequest {
+    #[schemars(
+        description = "Rust code using alloy, or a list of alloy types, modules and methods (e.g. 'connect_ws, MnemonicBuilder, SidecarBuilder')"
+    )]
+    code: String,
+}
+
+#[tool_router(router = features_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Map the alloy items a snippet uses to the cargo features they need.
+    #[tool(
+        description = "Work out which alloy cargo features a snippet (or list of items) needs: connect_ws needs provider-ws, sol!(rpc) needs contract, MnemonicBuilder needs signer-mnemonic, SidecarBuilder needs kzg, and so on. Emits the minimal `alloy = { version, features = [...] }` line, a default-features = false variant, and the equivalent individual alloy-* crate dependencies."
+    )]
+    fn required_features(
+        &self,
+        Parameters(RequiredFeaturesRequest { code }): Parameters<RequiredFeaturesRequest>,
+    ) -> String {
+        let matched = gates_in(&code);
+        if matched.is_empty() {
+            return "No feature-gated alloy items found. `alloy = \"1\"` with the default features \
+                (providers over HTTP, contracts, RPC types, local signers) covers plain primitives \
+                and `sol!` types."
+                .into();
+        }
+
+        let defaults = closure(DEFAULT_FEATURES);
+        let mut result = String::from(
+            "# Required features\n\n| Item | `alloy` feature | Crate | Default |\n|------|-----------------|-------|---------|\n",
+        );
+        for (gate, pattern) in &matched {
+            let krate = match gate.crate_feature {
+                Some(f) => format!("`{}` (`{f}`)", gate.krate),
+                None => format!("`{}`", gate.krate),
+            };
+            let _ = writeln!(
+                result,
+                "| `{}` | `{}` | {krate} | {} |",
+                pattern,
+                gate.feature,
+                if defaults.contains(gate.feature) {
+                    "yes"
+                } else {
+                    "no"
+                }
+            );
+        }
+
+        let needed: BTreeSet<&str> = matched.iter().map(|(g, _)| g.feature).collect();
+        let extra: BTreeSet<&str> = needed.difference(&defaults).copied().collect();
+        let mut crates: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for (gate, _) in &matched {
+            let features = crates.entry(gate.krate).or_default();
+            features.extend(gate.crate_feature);
+        }
+        if let Some(workspace) = self.workspace() {
+            result.push_str(&project_section(&workspace, &needed, &crates));
+        }
+
+        result.push_str("\n## Cargo.toml\n\n```toml\n");
+        if extra.is_empty() {
+            result.push_str("alloy = \"1\"\n");
+        } else {
+            let _ = writeln!(
+                result,
+                "alloy = {{ version = \"1\", features = {} }}",
+                toml_list(&minimize(&extra))
+            );
+        }
+        result.push_str("```\n");
+
+        let mut lean = needed.clone();
+        lean.insert("std");
+        if needed.contains("provider-http") {
+            lean.insert("reqwest");
+        }
+        let _ = write!(
+            result,
+            "\nWithout default features:\n\n```toml\nalloy = {{ version = \"1\", default-features = false, features = {} }}\n```\n",
+            toml_list(&minimize(&lean))
+        );
+
+        result.push_str("\nWith individual crates:\n\n```toml\n");
+        for (krate, features) in &crates {
+            if features.is_empty() {
+                let _ = writeln!(result, "{krate} = \"1\"");
+            } else {
+                let features: Vec<&str> = features.iter().copied().collect();
+                let _ = writeln!(
+                    result,
+                    "{krate} = {{ version = \"1\", features = {} }}",
+                    toml_list(&features)
+                );
+            }
+        }
+        result.push_str("```\n");
+
+        let mut notes = vec![
+            "Defaults are `std`, `reqwest` with rustls and `essentials` (`contract`, `provider-http`, \
+            `rpc-types`, `signer-local`)."
+                .to_string(),
+        ];
+        if needed.contains("pubsub")
+            && !needed.contains("provider-ws")
+            && !needed.contains("provider-ipc")
+        {
+            notes.push(
+                "Subscriptions need a pubsub transport: connect with `connect_ws` (`provider-ws`) or \
+                `connect_ipc` (`provider-ipc`). Over HTTP, poll with `watch_blocks`/`watch_logs` instead."
+                    .into(),
+            );
+        }
+        if needed.contains("kzg") {
+            notes.push(
+                "`kzg` compiles c-kzg (a C toolchain is required) and loads the trusted setup on first use."
+                    .into(),
+            );
+        }
+        notes.push(
+            "`features = [\"full\"]` enables most of this at once (WebSocket, IPC, kzg, the \
+            anvil/debug/trace/txpool APIs) at the cost of compile time."
+                .into(),
+        );
+        result.push_str("\n## Notes\n\n");
+        for note in &notes {
+            let _ = writeln!(result, "- {note}");
+        }
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://provider/setup` — Transports and ProviderBuilder\n\
+            - `alloy://signers/signing-guide` — Signer types\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_items_to_alloy_features() {
+        let result = AlloyMcpServer::new().required_features(Parameters(RequiredFeaturesRequest {
+            code: "use alloy::providers::{ProviderBuilder, WsConnect};\n\
+                use alloy::signers::local::PrivateKeySigner;\n\
+                let p = ProviderBuilder::new().connect_ws(WsConnect::new(url)).await?;"
+                .into(),
+        }));
+        assert!(result.contains("| `connect_ws` | `provider-ws` | `alloy-provider` (`ws`) | no |"));
+        assert!(
+            result.contains("| `PrivateKeySigner` | `signer-local` | `alloy-signer-local` | yes |")
+        );
+        assert!(result.contains("alloy = { version = \"1\", features = [\"provider-ws\"] }"));
+        assert!(result.contains("alloy-provider = { version = \"1\", features = [\"ws\"] }"));
+    }
+}
@@ -0,0 +1,585 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+
+use super::{codegen::Imports, features, project};
+use crate::{resources, server::AlloyMcpServer};
+
+const PATHS_URI: &str = "alloy://imports/paths";
+
+/// A row of the resource's crate map.
+struct CrateInfo<'a> {
+    /// Cargo name, e.g. `alloy-consensus`.
+    krate: &'a str,
+    /// Path through the umbrella crate, e.g. `alloy::consensus`.
+    umbrella: &'a str,
+    /// Further paths to the crate root, through the umbrella or another crate.
+    also: Vec<&'a str>,
+    /// Umbrella feature, `None` when always available.
+    feature: Option<&'a str>,
+}
+
+/// Where an item is reachable from in one module of the resource's listing.
+#[derive(Clone, Copy)]
+struct Hit<'a> {
+    /// Module path, e.g. `alloy_provider::fillers`.
+    module: &'a str,
+    /// Item name as listed, with `!` for macros.
+    item: &'a str,
+    /// Crate the item comes from when the module re-exports it.
+    origin: Option<&'a str>,
+}
+
+/// One way to import the item.
+struct Route {
+    path: String,
+    /// `alloy` for umbrella paths, otherwise the `alloy-*` crate.
+    via: String,
+    /// Feature of `via` the path needs.
+    feature: Option<String>,
+}
+
+impl Route {
+    fn umbrella(&self) -> bool {
+        self.via == "alloy"
+    }
+
+    fn needs(&self) -> String {
+        match &self.feature {
+            Some(feature) if self.umbrella() && features::is_default(feature) => {
+                format!("`{feature}` feature (default)")
+            }
+            Some(feature) => format!("`{feature}` feature"),
+            None => "—".into(),
+        }
+    }
+}
+
+/// How a project depends on alloy.
+pub(super) enum Setup {
+    Umbrella,
+    /// Individual crates; empty when the caller did not name them.
+    Crates(BTreeSet<String>),
+    Unknown,
+}
+
+fn setup(dependencies: &str) -> Setup {
+    match dependencies.trim().to_lowercase().as_str() {
+        "" => return Setup::Unknown,
+        "alloy" | "umbrella" => return Setup::Umbrella,
+        "crates" | "individual" | "alloy-*" => return Setup::Crates(BTreeSet::new()),
+        _ => {}
+    }
+    let mut umbrella = false;
+    let mut crates = BTreeSet::new();
+    for line in dependencies.lines() {
+        let line = line.trim();
+        let key = match line.strip_prefix("[dependencies.") {
+            Some(rest) => rest.trim_end_matches(']'),
+            None => line.split(['=', '.']).next().unwrap_or_default(),
+        };
+        let key = key.trim().trim_matches('"');
+        if key == "alloy" {
+            umbrella = true;
+        } else if key.starts_with("alloy-") {
+            crates.insert(key.to_string());
+        }
+    }
+    if umbrella {
+        Setup::Umbrella
+    } else if !crates.is_empty() {
+        Setup::Crates(crates)
+    } else {
+        Setup::Unknown
+    }
+}
+
+/// The backticked spans of a markdown table cell or line.
+fn code_spans(text: &str) -> impl Iterator<Item = &str> {
+    text.split('`').skip(1).step_by(2)
+}
+
+fn table_rows(section: &str) -> impl Iterator<Item = Vec<&str>> {
+    section
+        .lines()
+        .filter(|l| l.starts_with("| `"))
+        .map(|l| l.trim_matches('|').split('|').map(str::trim).collect())
+}
+
+fn crate_map(content: &str) -> Vec<CrateInfo<'_>> {
+    let Some(section) = resources::section(content, "crate-map") else {
+        return Vec::new();
+    };
+    table_rows(section)
+        .filter_map(|cells| {
+            let [krate, umbrella, also, feature] = cells[..] else {
+                return None;
+            };
+            Some(CrateInfo {
+                krate: code_spans(krate).next()?,
+                umbrella: code_spans(umbrella).next()?,
+                also: code_spans(also).collect(),
+                feature: code_spans(feature).next(),
+            })
+        })
+        .collect()
+}
+
+/// Umbrella path prefixes and the crate paths they stand for, from the
+/// crate map and the root re-exports: `alloy::consensus` →
+/// `alloy_consensus`, `alloy::sol` → `alloy_sol_types::sol`.
+pub(super) fn umbrella_prefixes(content: &str) -> Vec<(String, String)> {
+    let mut prefixes = Vec::new();
+    for info in crate_map(content) {
+        let krate = info.krate.replace('-', "_");
+        prefixes.push((info.umbrella.to_string(), krate.clone()));
+        for also in info.also.iter().filter(|a| a.starts_with("alloy::")) {
+            prefixes.push((also.to_string(), krate.clone()));
+        }
+    }
+    if let Some(section) = resources::section(content, "root-re-exports") {
+        for cells in table_rows(section) {
+            let mut spans = cells.iter().take(2).filter_map(|c| code_spans(c).next());
+            if let (Some(path), Some(same)) = (spans.next(), spans.next()) {
+                prefixes.push((path.to_string(), same.to_string()));
+            }
+        }
+    }
+    prefixes
+}
+
+/// The path to import `name` by, through the umbrella crate where it can.
+pub(super) fn import_path(content: &str, name: &str) -> Option<String> {
+    let hits = find(content, name);
+    routes(&hits, &crate_map(content), name)
+        .into_iter()
+        .next()
+        .map(|route| route.path)
+}
+
+/// Modules listing `name`, exact matches first and case-insensitive ones
+/// only when there are none.
+fn find<'a>(content: &'a str, name: &str) -> Vec<Hit<'a>> {
+    let Some(section) = resources::section(content, "items") else {
+        return Vec::new();
+    };
+    let mut hits = Vec::new();
+    let mut module = "";
+    for line in section.lines() {
+        if let Some(heading) = line.strip_prefix("### ") {
+            module = heading.trim_matches('`');
+        } else if let Some(items) = line.strip_prefix("Items: ") {
+            hits.extend(code_spans(items).map(|item| Hit {
+                module,
+                item,
+                origin: None,
+            }));
+        } else if let Some(reexports) = line.strip_prefix("Re-exports: ") {
+            let spans: Vec<&str> = code_spans(reexports).collect();
+            hits.extend(spans.chunks(2).filter_map(|pair| match pair {
+                [item, origin] => Some(Hit {
+                    module,
+                    item,
+                    origin: Some(origin),
+                }),
+                _ => None,
+            }));
+        }
+    }
+    let matches = |hit: &Hit, exact: bool| {
+        let item = hit.item.trim_end_matches('!');
+        if exact {
+            item == name
+        } else {
+            item.eq_ignore_ascii_case(name)
+        }
+    };
+    let exact: Vec<Hit> = hits.iter().filter(|h| matches(h, true)).copied().collect();
+    if !exact.is_empty() {
+        return exact;
+    }
+    hits.into_iter().filter(|h| matches(h, false)).collect()
+}
+
+/// Items whose name contains `name`, for the not-found message.
+fn similar(content: &str, name: &str) -> Vec<String> {
+    let Some(section) = resources::section(content, "items") else {
+        return Vec::new();
+    };
+    let name = name.to_lowercase();
+    let names: BTreeSet<&str> = section
+        .lines()
+        .filter(|l| l.starts_with("Items: ") || l.starts_with("Re-exports: "))
+        .flat_map(code_spans)
+        .filter(|s| !s.starts_with("alloy_") && s.to_lowercase().contains(&name))
+        .collect();
+    names.into_iter().take(8).map(String::from).collect()
+}
+
+fn hyphenate(krate: &str) -> String {
+    krate.replace('_', "-")
+}
+
+/// Cargo name of the crate a module path starts with.
+fn crate_of(module: &str) -> String {
+    hyphenate(module.split("::").next().unwrap_or(module))
+}
+
+/// All paths to `name` through the modules in `hits`, umbrella paths before
+/// crate paths. The crate `required_features` names for the item comes
+/// first (`WsConnect` is usually imported from the provider), then the
+/// defining module.
+fn routes(hits: &[Hit], map: &[CrateInfo], name: &str) -> Vec<Route> {
+    let gate = features::gate_for(name);
+    let mut ordered: Vec<&Hit> = hits.iter().collect();
+    ordered.sort_by_key(|h| {
+        let gated = gate.is_some_and(|(_, krate, _)| crate_of(h.module) == krate);
+        (!gated, h.origin.is_some())
+    });
+
+    let mut routes: Vec<Route> = Vec::new();
+    for hit in ordered {
+        let (krate, sub) = match hit.module.split_once("::") {
+            Some((krate, sub)) => (krate, format!("::{sub}")),
+            None => (hit.module, String::new()),
+        };
+        let cargo = hyphenate(krate);
+        let info = map.iter().find(|c| c.krate == cargo);
+        // Gates name items loosely (`Http`), so only trust one for its crate.
+        let gate = gate.filter(|(_, gate_crate, _)| *gate_crate == cargo);
+        let crate_feature = gate.and_then(|(_, _, feature)| feature).map(String::from);
+
+        let mut found = Vec::new();
+        if let Some(info) = info {
+            // A re-export is only there when the origin's feature is on.
+            let origin_feature = hit
+                .origin
+                .and_then(|o| map.iter().find(|c| c.krate == hyphenate(o)))
+                .and_then(|c| c.feature)
+                .filter(|f| !features::is_default(f));
+            let umbrella_feature = gate
+                .map(|(feature, ..)| feature)
+                .or(origin_feature)
+                .or(info.feature)
+                .map(String::from);
+            found.push(Route {
+                path: format!("{}{sub}::{name}", info.umbrella),
+                via: "alloy".into(),
+                feature: umbrella_feature.clone(),
+            });
+            for also in info.also.iter().filter(|a| a.starts_with("alloy::")) {
+                found.push(Route {
+                    path: format!("{also}{sub}::{name}"),
+                    via: "alloy".into(),
+                    feature: umbrella_feature.clone(),
+                });
+            }
+        }
+        found.push(Route {
+            path: format!("{krate}{sub}::{name}"),
+            via: cargo,
+            feature: crate_feature,
+        });
+        if let Some(info) = info {
+            for also in info.also.iter().filter(|a| !a.starts_with("alloy::")) {
+                let root = also.split("::").next().unwrap_or(also);
+                found.push(Route {
+                    path: format!("{also}{sub}::{name}"),
+                    via: hyphenate(root),
+                    feature: None,
+                });
+            }
+        }
+        for route in found {
+            if !routes.iter().any(|r| r.path == route.path) {
+                routes.push(route);
+            }
+        }
+    }
+    routes
+}
+
+/// Root re-exports of the umbrella crate naming `name`, as routes.
+fn root_routes(content: &str, name: &str) -> Vec<(Route, String)> {
+    let Some(section) = resources::section(content, "root-re-exports") else {
+        return Vec::new();
+    };
+    table_rows(section)
+        .filter_map(|cells| {
+            let path = code_spans(cells.first()?).next()?;
+            (path.rsplit("::").next() == Some(name)).then(|| {
+                let route = Route {
+                    path: path.to_string(),
+                    via: "alloy".into(),
+                    feature: None,
+                };
+                (route, cells.get(2).copied().unwrap_or_default().to_string())
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ResolveImportRequest {
+    #[schemars(
+        description = "Item to import: a type, trait, function or macro name (e.g. 'TxEnvelope', 'NonceFiller', 'parse_ether', 'address!'), or a path ending in one"
+    )]
+    name: String,
+    #[schemars(
+        description = "How the project depends on alloy: 'alloy' (umbrella crate), 'crates' (individual alloy-* crates), or the [dependencies] section of Cargo.toml. Omit to get both styles."
+    )]
+    dependencies: Option<String>,
+}
+
+#[tool_router(router = imports_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// List every import path for an item and pick the one matching the
+    /// project's dependencies, from `alloy://imports/paths`.
+    #[tool(
+        description = "Find every valid import path for an alloy item: through the umbrella crate (alloy::consensus::TxEnvelope), through the defining crate (alloy_consensus::TxEnvelope) and through re-exports (alloy::rpc::types::BlockNumberOrTag). Given 'alloy', 'crates' or a Cargo.toml [dependencies] section, recommends the path that fits and the cargo feature it needs."
+    )]
+    fn resolve_import(
+        &self,
+        Parameters(ResolveImportRequest { name, dependencies }): Parameters<ResolveImportRequest>,
+    ) -> String {
+        let Some(content) = resources::lookup(&self.resources, PATHS_URI) else {
+            return format!("Resource `{PATHS_URI}` is missing.");
+        };
+        let name = name.trim();
+        let name = name.split('<').next().unwrap_or(name);
+        let name = name.rsplit("::").next().unwrap_or(name).trim();
+        let macro_name = name.strip_suffix('!');
+        let name = macro_name.unwrap_or(name).trim();
+        if name.is_empty() {
+            return "No item given. Pass a type, trait, function or macro name as `name`.".into();
+        }
+
+        let hits = find(content, name);
+        let roots = root_routes(content, name);
+        if hits.is_empty() && roots.is_empty() {
+            let similar = similar(content, name);
+            return if similar.is_empty() {
+                format!(
+                    "No alloy item named `{name}` in the import map. Check the spelling, or use \
+                    `search_resources` to find the type by what it does."
+                )
+            } else {
+                format!(
+                    "No alloy item named `{name}`. Similar items: {}. See `{PATHS_URI}` for every module's items.",
+                    similar
+                        .iter()
+                        .map(|s| format!("`{s}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            };
+        }
+        // Take the listed spelling, which fixes case-insensitive matches.
+        let name = hits.first().map_or(name, |h| h.item.trim_end_matches('!'));
+        let is_macro = macro_name.is_some() || hits.iter().any(|h| h.item.ends_with('!'));
+        let title = if is_macro {
+            format!("{name}!")
+        } else {
+            name.to_string()
+        };
+
+        let map = crate_map(content);
+        let mut routes = routes(&hits, &map, name);
+        let mut root_notes = Vec::new();
+        for (route, note) in roots {
+            routes.retain(|r| r.path != route.path);
+            routes.insert(0, route);
+            root_notes.push(note);
+        }
+
+        // An explicit `dependencies` wins over the detected project.
+        let workspace = self.workspace().filter(|_| dependencies.is_none());
+        let mut hidden = 0;
+        if let Some(workspace) = &workspace {
+            // Paths the project can use as is, else the ones through crates it
+            // already depends on (missing a feature).
+            let usable = |r: &Route| project::available(workspace, &r.via, r.feature.as_deref());
+            let reachable = |r: &Route| project::available(workspace, &r.via, None);
+            let total = routes.len();
+            if routes.iter().any(usable) {
+                routes.retain(usable);
+            } else if routes.iter().any(reachable) {
+                routes.retain(reachable);
+            }
+            hidden = total - routes.len();
+        }
+
+        let mut result = format!("# {title}\n\n| Path | Via | Needs |\n|------|-----|-------|\n");
+        for route in &routes {
+            let _ = writeln!(
+                result,
+                "| `{}` | `{}` | {} |",
+                route.path,
+                route.via,
+                route.needs()
+            );
+        }
+
+        let umbrella = routes.iter().find(|r| r.umbrella());
+        let defining = routes.iter().find(|r| !r.umbrella());
+        let setup = match (&workspace, dependencies.as_deref()) {
+            (Some(workspace), _) => project::setup(workspace),
+            (None, dependencies) => setup(dependencies.unwrap_or_default()),
+        };
+        let (chosen, reason) = match setup {
+            Setup::Umbrella => (
+                umbrella,
+                "The project depends on `alloy`, so import through the umbrella crate.".to_string(),
+            ),
+            Setup::Crates(crates) => {
+                let present = routes
+                    .iter()
+                    .filter(|r| !r.umbrella())
+                    .find(|r| crates.is_empty() || crates.contains(&r.via));
+                match (present, defining) {
+                    (Some(route), _) => (
+                        Some(route),
+                        format!(
+                            "The project depends on the individual crates; `{}` provides this.",
+                            route.via
+                        ),
+                    ),
+                    (None, Some(route)) => (
+                        Some(route),
+                        format!(
+                            "None of the listed crates provide `{title}`: add `{} = \"1\"` to `[dependencies]`.",
+                            route.via
+                        ),
+                    ),
+                    (None, None) => (
+                        umbrella,
+                        "Only the umbrella crate exposes this; add `alloy = \"1\"`.".to_string(),
+                    ),
+                }
+            }
+            Setup::Unknown => (
+                umbrella.or(defining),
+                match (umbrella, defining) {
+                    (Some(_), Some(route)) => format!(
+                        "With `alloy` as the dependency. With individual crates, import `{}` instead.",
+                        route.path
+                    ),
+                    _ => String::new(),
+                },
+            ),
+        };
+        if let Some(route) = chosen.or(routes.first())
+            && let Some((module, item)) = route.path.rsplit_once("::")
+        {
+            let mut imports = Imports::default();
+            imports.add(module, item);
+            let _ = write!(result, "\n## Rust\n\n```rust\n{}```\n", imports.render());
+            if !reason.is_empty() {
+                let _ = writeln!(result, "\n{reason}");
+            }
+            if route.umbrella()
+                && workspace.is_none()
+                && let Some(feature) = route.feature.as_deref()
+                && !features::is_default(feature)
+            {
+                let _ = writeln!(
+                    result,
+                    "\nNot a default feature: `alloy = {{ version = \"1\", features = [\"{feature}\"] }}`."
+                );
+            }
+        }
+
+        let mut notes: Vec<String> = root_notes
+            .into_iter()
+            .filter(|n| !n.is_empty())
+            .map(|n| format!("`alloy::{name}`: {n}."))
+            .collect();
+        let definers: BTreeSet<String> = hits
+            .iter()
+            .filter(|h| h.origin.is_none())
+            .map(|h| crate_of(h.module))
+            .collect();
+        let origins: BTreeSet<String> = hits
+            .iter()
+            .filter_map(|h| h.origin)
+            .map(hyphenate)
+            .collect();
+        let reexporters: Vec<String> = hits
+            .iter()
+            .filter(|h| h.origin.is_some())
+            .map(|h| format!("`{}`", crate_of(h.module)))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if definers.len() > 1 {
+            notes.push(format!(
+                "Several crates define an item called `{name}`: {}. They are different items; pick the one you mean.",
+                definers.iter().map(|c| format!("`{c}`")).collect::<Vec<_>>().join(", ")
+            ));
+        } else if !reexporters.is_empty()
+            && let Some(origin) = definers.first().or(origins.first())
+        {
+            notes.push(format!(
+                "Defined in `{origin}` and re-exported by {}. Every path above names the same item.",
+                reexporters.join(", ")
+            ));
+        }
+        if name == "sol" {
+            notes.push(
+                "With individual crates, `#[sol(rpc)]` bindings need `alloy-contract` and `alloy-sol-types` as dependencies; `alloy::sol!` routes them through the umbrella crate.".into(),
+            );
+        }
+        if let Some(workspace) = &workspace
+            && hidden > 0
+        {
+            notes.push(format!(
+                "{hidden} more path(s) hidden: not available with {}.",
+                workspace.summary()
+            ));
+        }
+        notes.push("alloy has no prelude; import each item from its module.".into());
+        result.push_str("\n## Notes\n\n");
+        for note in &notes {
+            let _ = writeln!(result, "- {note}");
+        }
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://imports/paths` — Crate map, re-exports and every module's items\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(name: &str, dependencies: Option<&str>) -> String {
+        AlloyMcpServer::new().resolve_import(Parameters(ResolveImportRequest {
+            name: name.into(),
+            dependencies: dependencies.map(Into::into),
+        }))
+    }
+
+    #[test]
+    fn resolves_umbrella_and_crate_paths() {
+        let result = resolve("TxEnvelope", None);
+        assert!(result.contains(
+            "| `alloy::consensus::TxEnvelope` | `alloy` | `consensus` feature (default) |"
+        ));
+        assert!(result.contains("| `alloy_consensus::TxEnvelope` | `alloy-consensus` | — |"));
+        assert!(result.contains("use alloy::consensus::TxEnvelope;"));
+    }
+
+    #[test]
+    fn follows_the_dependency_style() {
+        assert!(
+            resolve("parse_ether", Some("alloy"))
+                .contains("use alloy::primitives::utils::parse_ether;")
+        );
+        assert!(
+            resolve("ReceiptResponse", Some("crates"))
+                .contains("use alloy_network::ReceiptResponse;")
+        );
+    }
+}
@@ -0,0 +1,602 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Write,
+};
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+use syn::{Expr, Lit, Pat, spanned::Spanned, visit::Visit};
+
+use crate::server::AlloyMcpServer;
+
+/// A lint, tied to the "Common Mistakes" entry that explains it.
+struct Rule {
+    id: &'static str,
+    title: &'static str,
+    fix: &'static str,
+    resource: &'static str,
+    /// Number of the entry in the resource's "Common Mistakes" list.
+    mistake: usize,
+}
+
+const U256_FROM_STR: Rule = Rule {
+    id: "u256-from-str",
+    title: "`U256::from` on a string literal",
+    fix: "`U256::from` only converts integers: parse with `\"..\".parse::<U256>()?` or write `uint!(.._U256)`.",
+    resource: "alloy://primitives/core-types",
+    mistake: 6,
+};
+
+const U256_FROM_FLOAT: Rule = Rule {
+    id: "u256-from-float",
+    title: "`U256::from` on a float literal",
+    fix: "There is no `f64` conversion: write the integer (`uint!(1_000_000_000_000_000_000_U256)`) or use `parse_ether`/`parse_units`.",
+    resource: "alloy://primitives/core-types",
+    mistake: 1,
+};
+
+const SEND_WITHOUT_RECEIPT: Rule = Rule {
+    id: "send-without-receipt",
+    title: "Transaction sent but never awaited",
+    fix: "`send_transaction`/`.send()` only return a pending transaction: call `.get_receipt().await?` (or `.watch().await?` for just the hash) to wait for inclusion.",
+    resource: "alloy://rpc/transaction-request",
+    mistake: 2,
+};
+
+const ENVELOPE_FROM_UNSIGNED: Rule = Rule {
+    id: "envelope-from-unsigned",
+    title: "`TxEnvelope` built from an unsigned transaction",
+    fix: "Envelopes hold signed transactions: sign first (`signer.sign_transaction_sync(&mut tx)?` then `tx.into_signed(sig)`) and build the envelope from the `Signed<T>`.",
+    resource: "alloy://consensus/transactions",
+    mistake: 3,
+};
+
+const MISSING_SOL_RPC: Rule = Rule {
+    id: "missing-sol-rpc",
+    title: "Contract instance without `#[sol(rpc)]`",
+    fix: "`Contract::new(address, provider)` only exists when the `sol!` contract is annotated with `#[sol(rpc)]`.",
+    resource: "alloy://sol-macro/contract-bindings",
+    mistake: 1,
+};
+
+const BLOCK_TAG_AS_ID: Rule = Rule {
+    id: "block-tag-as-block-id",
+    title: "`BlockNumberOrTag` passed where `BlockId` is required",
+    fix: "`.block_id(..)` and `get_block(..)` take a `BlockId`: add `.into()` or use `BlockId::latest()`/`BlockId::number(n)`.",
+    resource: "alloy://eips/block-identifiers",
+    mistake: 5,
+};
+
+const RULES: [&Rule; 6] = [
+    &U256_FROM_STR,
+    &U256_FROM_FLOAT,
+    &SEND_WITHOUT_RECEIPT,
+    &ENVELOPE_FROM_UNSIGNED,
+    &MISSING_SOL_RPC,
+    &BLOCK_TAG_AS_ID,
+];
+
+/// Unsigned consensus transaction types.
+const UNSIGNED_TXS: [&str; 8] = [
+    "TxLegacy",
+    "TxEip2930",
+    "TxEip1559",
+    "TxEip4844",
+    "TxEip4844Variant",
+    "TxEip4844WithSidecar",
+    "TxEip7702",
+    "TypedTransaction",
+];
+
+/// Methods that wait on a pending transaction.
+const RECEIPT_METHODS: [&str; 4] = [
+    "get_receipt",
+    "watch",
+    "register",
+    "with_required_confirmations",
+];
+
+struct Finding {
+    rule: &'static Rule,
+    line: usize,
+    detail: String,
+}
+
+#[derive(Default)]
+struct Linter {
+    findings: Vec<Finding>,
+    /// `sol!` contracts and whether they were declared with `#[sol(rpc)]`.
+    contracts: HashMap<String, bool>,
+    /// `X::new(a, b)` calls, checked against `contracts` once every `sol!`
+    /// invocation has been seen.
+    instances: Vec<(String, usize)>,
+    /// Bindings initialized with an unsigned transaction.
+    unsigned: HashSet<String>,
+    /// Sends whose receipt is not awaited in the same statement, with the
+    /// binding holding the pending transaction.
+    sends: Vec<(usize, Option<String>)>,
+    /// Bindings that are waited on or handed to other code.
+    handled: HashSet<String>,
+}
+
+fn line(node: &impl Spanned) -> usize {
+    node.span().start().line
+}
+
+fn path_segments(expr: &Expr) -> Vec<String> {
+    match expr {
+        Expr::Path(p) => p
+            .path
+            .segments
+            .iter()
+            .map(|s| s.ident.to_string())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn single_ident(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(p) => p.path.get_ident().map(ToString::to_string),
+        Expr::Reference(r) => single_ident(&r.expr),
+        _ => None,
+    }
+}
+
+fn pat_ident(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(p) => Some(p.ident.to_string()),
+        Pat::Type(t) => pat_ident(&t.pat),
+        _ => None,
+    }
+}
+
+/// Method names along a call chain, outermost first, and the expression
+/// at its root.
+fn chain(mut expr: &Expr) -> (Vec<(String, usize)>, &Expr) {
+    let mut methods = Vec::new();
+    loop {
+        expr = match expr {
+            Expr::MethodCall(m) => {
+                methods.push((m.method.to_string(), m.args.len()));
+                &m.receiver
+            }
+            Expr::Await(a) => &a.base,
+            Expr::Try(t) => &t.expr,
+            Expr::Paren(p) => &p.expr,
+            Expr::Field(f) => &f.base,
+            _ => return (methods, expr),
+        }
+    }
+}
+
+fn is_send(method: &str, args: usize) -> bool {
+    matches!(
+        method,
+        "send_transaction" | "send_raw_transaction" | "send_tx_envelope"
+    ) || (method == "send" && args == 0)
+}
+
+/// Whether `expr` is a `BlockNumberOrTag` value rather than a `BlockId`.
+fn is_block_tag(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(_) => path_segments(expr)
+            .first()
+            .is_some_and(|s| s == "BlockNumberOrTag"),
+        Expr::Call(c) => is_block_tag(&c.func),
+        _ => false,
+    }
+}
+
+/// Whether a `#[sol(..)]` attribute body enables `rpc`.
+fn is_sol_rpc(attr: TokenStream) -> bool {
+    let mut tokens = attr.into_iter();
+    while let Some(token) = tokens.next() {
+        if matches!(&token, TokenTree::Ident(i) if i == "sol")
+            && let Some(TokenTree::Group(args)) = tokens.next()
+        {
+            return args
+                .stream()
+                .into_iter()
+                .any(|t| matches!(t, TokenTree::Ident(i) if i == "rpc"));
+        }
+    }
+    false
+}
+
+impl Linter {
+    fn report(&mut self, rule: &'static Rule, line: usize, detail: String) {
+        self.findings.push(Finding { rule, line, detail });
+    }
+
+    fn is_unsigned(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Struct(s) => s
+                .path
+                .segments
+                .last()
+                .is_some_and(|seg| UNSIGNED_TXS.contains(&seg.ident.to_string().as_str())),
+            // `TxEip1559::default()`, `TypedTransaction::Eip1559(..)`
+            Expr::Call(c) => path_segments(&c.func)
+                .first()
+                .is_some_and(|s| UNSIGNED_TXS.contains(&s.as_str())),
+            Expr::Try(t) => self.is_unsigned(&t.expr),
+            Expr::MethodCall(m) => m.method == "build_unsigned",
+            _ => single_ident(expr).is_some_and(|i| self.unsigned.contains(&i)),
+        }
+    }
+
+    /// Record the contracts a `sol!` invocation declares.
+    fn scan_sol(&mut self, tokens: TokenStream) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let mut rpc_all = false;
+        let mut rpc_next = false;
+        for (i, token) in tokens.iter().enumerate() {
+            match token {
+                TokenTree::Punct(p) if p.as_char() == '#' => {
+                    let (inner, group) = match tokens.get(i + 1) {
+                        Some(TokenTree::Punct(p)) if p.as_char() == '!' => {
+                            (true, tokens.get(i + 2))
+                        }
+                        group => (false, group),
+                    };
+                    if let Some(TokenTree::Group(g)) = group
+                        && g.delimiter() == Delimiter::Bracket
+                        && is_sol_rpc(g.stream())
+                    {
+                        if inner {
+                            rpc_all = true;
+                        } else {
+                            rpc_next = true;
+                        }
+                    }
+                }
+                TokenTree::Ident(kw) if kw == "contract" || kw == "interface" => {
+                    if let Some(TokenTree::Ident(name)) = tokens.get(i + 1) {
+                        self.contracts.insert(name.to_string(), rpc_all || rpc_next);
+                    }
+                    rpc_next = false;
+                }
+                TokenTree::Ident(kw)
+                    if ["struct", "function", "event", "error", "library", "enum"]
+                        .contains(&kw.to_string().as_str()) =>
+                {
+                    rpc_next = false;
+                }
+                // `sol!(Name, "abi.json")`
+                TokenTree::Ident(name)
+                    if matches!(tokens.get(i + 1), Some(TokenTree::Punct(p)) if p.as_char() == ',')
+                        && matches!(tokens.get(i + 2), Some(TokenTree::Literal(l)) if l.to_string().starts_with('"')) =>
+                {
+                    self.contracts.insert(name.to_string(), rpc_all || rpc_next);
+                    rpc_next = false;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Record a statement-level send that does not wait for its receipt.
+    fn check_send(&mut self, expr: &Expr, binding: Option<String>) {
+        let (methods, _) = chain(expr);
+        let sent = methods.iter().any(|(m, args)| is_send(m, *args));
+        let waited = methods
+            .iter()
+            .any(|(m, _)| RECEIPT_METHODS.contains(&m.as_str()));
+        if sent && !waited {
+            self.sends.push((line(expr), binding));
+        }
+    }
+
+    fn finish(mut self) -> Vec<Finding> {
+        for (name, line) in std::mem::take(&mut self.instances) {
+            if self.contracts.get(&name) == Some(&false) {
+                self.report(
+                    &MISSING_SOL_RPC,
+                    line,
+                    format!("`{name}::new(..)` but `{name}` is declared without `#[sol(rpc)]`."),
+                );
+            }
+        }
+        for (line, binding) in std::mem::take(&mut self.sends) {
+            match binding {
+                Some(b) if self.handled.contains(&b) => {}
+                Some(b) => self.report(
+                    &SEND_WITHOUT_RECEIPT,
+                    line,
+                    format!("`{b}` holds a pending transaction that is never waited on."),
+                ),
+                None => self.report(
+                    &SEND_WITHOUT_RECEIPT,
+                    line,
+                    "The pending transaction is dropped without waiting for a receipt.".into(),
+                ),
+            }
+        }
+        self.findings.sort_by_key(|f| f.line);
+        self.findings
+    }
+}
+
+impl<'ast> Visit<'ast> for Linter {
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if mac.path.segments.last().is_some_and(|s| s.ident == "sol") {
+            self.scan_sol(mac.tokens.clone());
+        }
+        syn::visit::visit_macro(self, mac);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        let segments = path_segments(&call.func);
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+        let first_arg = call.args.first();
+        match (segments.as_slice(), first_arg) {
+            ([.., "U256", "from"], Some(Expr::Lit(lit))) => match &lit.lit {
+                Lit::Str(s) => self.report(
+                    &U256_FROM_STR,
+                    line(call),
+                    format!("`U256::from({:?})`", s.value()),
+                ),
+                Lit::Float(f) => {
+                    self.report(&U256_FROM_FLOAT, line(call), format!("`U256::from({f})`"))
+                }
+                _ => {}
+            },
+            (
+                [
+                    ..,
+                    "TxEnvelope",
+                    "from" | "Legacy" | "Eip2930" | "Eip1559" | "Eip4844" | "Eip7702",
+                ],
+                Some(arg),
+            ) if self.is_unsigned(arg) => self.report(
+                &ENVELOPE_FROM_UNSIGNED,
+                line(call),
+                format!("`{}(..)` with an unsigned transaction", segments.join("::")),
+            ),
+            ([name, "new"], _) if call.args.len() == 2 => {
+                self.instances.push((name.to_string(), line(call)));
+            }
+            _ => {}
+        }
+        for arg in &call.args {
+            if let Some(ident) = single_ident(arg) {
+                self.handled.insert(ident);
+            }
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        let method = call.method.to_string();
+        if RECEIPT_METHODS.contains(&method.as_str())
+            && let Some(root) = single_ident(chain(&call.receiver).1)
+        {
+            self.handled.insert(root);
+        }
+        if matches!(method.as_str(), "block_id" | "get_block")
+            && let Some(arg) = call.args.first()
+            && is_block_tag(arg)
+        {
+            self.report(
+                &BLOCK_TAG_AS_ID,
+                line(call),
+                format!("`.{method}({})`", quote_expr(arg)),
+            );
+        }
+        for arg in &call.args {
+            if let Some(ident) = single_ident(arg) {
+                self.handled.insert(ident);
+            }
+        }
+        syn::visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_local(&mut self, local: &'ast syn::Local) {
+        if let Some(init) = &local.init {
+            let binding = pat_ident(&local.pat);
+            if let Pat::Type(typed) = &local.pat
+                && let syn::Type::Path(ty) = &*typed.ty
+                && ty
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|s| s.ident == "TxEnvelope")
+                && let Expr::MethodCall(into) = &*init.expr
+                && into.method == "into"
+                && self.is_unsigned(&into.receiver)
+            {
+                self.report(
+                    &ENVELOPE_FROM_UNSIGNED,
+                    line(local),
+                    "`let _: TxEnvelope = tx.into()` with an unsigned transaction".into(),
+                );
+            }
+            if let Some(binding) = &binding
+                && self.is_unsigned(&init.expr)
+            {
+                self.unsigned.insert(binding.clone());
+            }
+            self.check_send(&init.expr, binding);
+        }
+        syn::visit::visit_local(self, local);
+    }
+
+    fn visit_block(&mut self, block: &'ast syn::Block) {
+        for stmt in &block.stmts {
+            match stmt {
+                syn::Stmt::Expr(expr, Some(_)) => self.check_send(expr, None),
+                // A tail expression hands its value to the caller.
+                syn::Stmt::Expr(expr, None) => {
+                    if let Some(root) = single_ident(chain(expr).1) {
+                        self.handled.insert(root);
+                    }
+                }
+                _ => {}
+            }
+        }
+        syn::visit::visit_block(self, block);
+    }
+}
+
+/// Compact source text for an expression in a finding.
+fn quote_expr(expr: &Expr) -> String {
+    let segments = path_segments(expr);
+    match expr {
+        Expr::Path(_) => segments.join("::"),
+        Expr::Call(c) => format!("{}(..)", path_segments(&c.func).join("::")),
+        _ => "..".into(),
+    }
+}
+
+/// Parse a snippet as a file, or failing that as a function body. Returns
+/// the file and the number of lines added in front of the snippet.
+fn parse(code: &str) -> Result<(syn::File, usize), syn::Error> {
+    match syn::parse_file(code) {
+        Ok(file) => Ok((file, 0)),
+        Err(file_err) => syn::parse_file(&format!("fn snippet() {{\n{code}\n}}"))
+            .map(|file| (file, 1))
+            .map_err(|_| file_err),
+    }
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct LintAlloyCodeRequest {
+    #[schemars(
+        description = "Rust code using alloy: a whole file, items, or statements from a function body"
+    )]
+    code: String,
+}
+
+#[tool_router(router = lint_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Flag the anti-patterns listed under "Common Mistakes" in the
+    /// resources.
+    #[tool(
+        description = "Statically check alloy Rust code for the anti-patterns listed under 'Common Mistakes' in the resources: U256::from on string/float literals, send_transaction/.send() without .get_receipt()/.watch(), TxEnvelope built from an unsigned tx, Contract::new without #[sol(rpc)], BlockNumberOrTag where BlockId is required. Each finding links to the resource section that explains it. Use it to check generated code before returning it."
+    )]
+    fn lint_alloy_code(
+        &self,
+        Parameters(LintAlloyCodeRequest { code }): Parameters<LintAlloyCodeRequest>,
+    ) -> String {
+        let (file, offset) = match parse(&code) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                let start = e.span().start();
+                return format!(
+                    "Could not parse the code as Rust (line {}, column {}): {e}. Pass a whole file, \
+                    items, or statements from a function body.",
+                    start.line,
+                    start.column + 1
+                );
+            }
+        };
+        let mut linter = Linter::default();
+        linter.visit_file(&file);
+        let findings = linter.finish();
+
+        if findings.is_empty() {
+            let mut result = String::from("# Lint: no findings\n\nChecked:\n");
+            for rule in RULES {
+                let _ = writeln!(result, "- `{}` — {}", rule.id, rule.title);
+            }
+            result.push_str(
+                "\nThese checks are syntactic: they do not prove the code compiles or that types line up.\n",
+            );
+            return result;
+        }
+
+        let mut result = format!(
+            "# Lint: {} finding{}\n\n| Line | Rule | Finding |\n|------|------|---------|\n",
+            findings.len(),
+            if findings.len() == 1 { "" } else { "s" }
+        );
+        for finding in &findings {
+            let _ = writeln!(
+                result,
+                "| {} | `{}` | {} |",
+                finding.line.saturating_sub(offset),
+                finding.rule.id,
+                finding.detail.replace('|', "\\|")
+            );
+        }
+
+        result.push_str("\n## Fixes\n\n");
+        let mut seen = BTreeSet::new();
+        for finding in &findings {
+            let rule = finding.rule;
+            if seen.insert(rule.id) {
+                let _ = writeln!(
+                    result,
+                    "- **{}** — {} See `{}#common-mistakes` (#{}).",
+                    rule.title, rule.fix, rule.resource, rule.mistake
+                );
+            }
+        }
+
+        result.push_str("\n**Key resources:**\n");
+        let mut resources: Vec<(&str, Vec<&str>)> = Vec::new();
+        for finding in &findings {
+            let rule = finding.rule;
+            match resources.iter_mut().find(|(uri, _)| *uri == rule.resource) {
+                Some((_, titles)) if !titles.contains(&rule.title) => titles.push(rule.title),
+                Some(_) => {}
+                None => resources.push((rule.resource, vec![rule.title])),
+            }
+        }
+        for (uri, titles) in resources {
+            let _ = writeln!(result, "- `{uri}#common-mistakes` — {}", titles.join("; "));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources;
+
+    fn lint(code: &str) -> String {
+        AlloyMcpServer::new()
+            .lint_alloy_code(Parameters(LintAlloyCodeRequest { code: code.into() }))
+    }
+
+    #[test]
+    fn flags_known_mistakes_by_line() {
+        let result = lint(
+            "let a = U256::from(\"100\");\n\
+            let b = U256::from(1e18);\n\
+            provider.send_transaction(tx).await?;\n",
+        );
+        assert!(result.starts_with("# Lint: 3 findings"));
+        assert!(result.contains("| 1 | `u256-from-str` |"));
+        assert!(result.contains("| 2 | `u256-from-float` |"));
+        assert!(result.contains("| 3 | `send-without-receipt` |"));
+    }
+
+    #[test]
+    fn passes_correct_code() {
+        let result = lint(
+            "let value = U256::from(5u64);\n\
+            let receipt = provider.send_transaction(tx).await?.get_receipt().await?;\n",
+        );
+        assert!(result.starts_with("# Lint: no findings"), "{result}");
+    }
+
+    /// Every rule points at an entry that exists in its resource.
+    #[test]
+    fn rules_cite_existing_mistakes() {
+        let resources = resources::all();
+        for rule in RULES {
+            let uri = format!("{}#common-mistakes", rule.resource);
+            let section = resources::lookup(&resources, &uri).unwrap_or_default();
+            let entry = format!("\n{}. ", rule.mistake);
+            assert!(
+                section.contains(&entry),
+                "{} cites missing {uri} #{}",
+                rule.id,
+                rule.mistake
+            );
+        }
+    }
+}
@@ -0,0 +1,547 @@
+use std::fmt::Write;
+
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+
+use super::imports;
+use crate::{
+    resources::{self, StaticResource},
+    server::AlloyMcpServer,
+};
+
+const METHODS_URI: &str = "alloy://reference/methods";
+
+/// A method bullet as written on the reference page and the API pages:
+/// ``- `signature` — summary``.
+struct Method<'a> {
+    name: &'a str,
+    line: &'a str,
+}
+
+/// Methods a type gets from one place.
+struct Group<'a> {
+    /// The trait as implemented, e.g. `TransactionBuilder<Ethereum>`; `None`
+    /// for the type's own methods.
+    via: Option<String>,
+    methods: Vec<Method<'a>>,
+}
+
+impl Group<'_> {
+    /// The trait name without generics, as it is imported.
+    fn trait_name(&self) -> Option<&str> {
+        self.via
+            .as_deref()
+            .map(|via| via.split('<').next().unwrap_or(via))
+    }
+}
+
+fn method(line: &str) -> Option<Method<'_>> {
+    let signature = line.strip_prefix("- `")?.split('`').next()?;
+    let mut rest = signature;
+    while let Some(stripped) = ["pub ", "const ", "async ", "unsafe "]
+        .iter()
+        .find_map(|p| rest.strip_prefix(p))
+    {
+        rest = stripped;
+    }
+    let rest = rest.strip_prefix("fn ").unwrap_or(rest);
+    let name = rest
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()?;
+    (!name.is_empty()).then_some(Method { name, line })
+}
+
+/// Split `text` at the first ` for ` outside brackets.
+fn split_for(text: &str) -> Option<(&str, &str)> {
+    let mut depth = 0i32;
+    let mut prev = ' ';
+    for (i, c) in text.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' if prev != '-' => depth -= 1,
+            ')' | ']' => depth -= 1,
+            ' ' if depth == 0 && text[i..].starts_with(" for ") => {
+                return Some((&text[..i], &text[i + 5..]));
+            }
+            _ => {}
+        }
+        prev = c;
+    }
+    None
+}
+
+/// The trait and the self type of an impl header such as
+/// ``impl<C: Bound> Signer for LocalSigner<C> where ...``.
+fn impl_parts(header: &str) -> Option<(&str, &str)> {
+    let mut rest = header.trim().trim_matches('`').strip_prefix("impl")?;
+    if rest.starts_with('<') {
+        let mut depth = 0;
+        let mut prev = ' ';
+        let mut end = None;
+        for (i, c) in rest.char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' if prev != '-' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = Some(i + 1);
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            prev = c;
+        }
+        rest = &rest[end?..];
+    }
+    let (r#trait, ty) = split_for(rest.trim_start())?;
+    let ty = ty.split(" where ").next().unwrap_or(ty);
+    Some((r#trait.trim(), ty.trim()))
+}
+
+/// The bare name of a type as written in an impl: `&'a mut Foo<T>` and
+/// `alloy_rpc_types_eth::Foo` are both `Foo`.
+fn base_name(ty: &str) -> &str {
+    let mut ty = ty.trim_start_matches('&');
+    if ty.starts_with('\'') {
+        ty = ty.split_once(' ').map_or(ty, |(_, rest)| rest);
+    }
+    let ty = ty.strip_prefix("mut ").unwrap_or(ty);
+    let ty = ty.split('<').next().unwrap_or(ty);
+    ty.rsplit("::").next().unwrap_or(ty).trim()
+}
+
+/// The method groups of a type's listing, on an API page or in a section of
+/// `alloy://reference/methods`: `Methods` (a trait's `Required Methods` and
+/// `Provided Methods`) and one group per ``impl Trait for Type`` heading.
+fn listing_groups(listing: &str) -> Vec<Group<'_>> {
+    let mut groups: Vec<Group> = Vec::new();
+    let mut current = None;
+    let mut in_fence = false;
+    for line in listing.lines() {
+        if line.starts_with("```") {
+            in_fence = !in_fence;
+        } else if in_fence {
+            continue;
+        } else if line.starts_with('#') {
+            let title = line.trim_start_matches('#').trim();
+            let via = match title {
+                "Methods" | "Required Methods" | "Provided Methods" => Some(None),
+                _ if title.starts_with("`impl") => {
+                    impl_parts(title).map(|(r#trait, _)| Some(r#trait.to_string()))
+                }
+                _ => None,
+            };
+            current = via.map(|via| match groups.iter().position(|g| g.via == via) {
+                Some(index) => index,
+                None => {
+                    groups.push(Group {
+                        via,
+                        methods: Vec::new(),
+                    });
+                    groups.len() - 1
+                }
+            });
+        } else if let (Some(index), Some(method)) = (current, method(line)) {
+            groups[index].methods.push(method);
+        }
+    }
+    groups
+}
+
+/// Add `more` to `groups`, matching groups by trait name and keeping the
+/// first listing of each method.
+fn merge<'a>(groups: &mut Vec<Group<'a>>, more: Vec<Group<'a>>) {
+    for group in more {
+        let existing = groups
+            .iter_mut()
+            .find(|g| g.trait_name() == group.trait_name());
+        match existing {
+            Some(existing) => {
+                for method in group.methods {
+                    if !existing.methods.iter().any(|m| m.name == method.name) {
+                        existing.methods.push(method);
+                    }
+                }
+            }
+            None if !group.methods.is_empty() => groups.push(group),
+            None => {}
+        }
+    }
+}
+
+fn is_trait_listing(listing: &str) -> bool {
+    listing.lines().any(|line| {
+        matches!(
+            line.trim_start_matches('#').trim(),
+            "Required Methods" | "Provided Methods"
+        ) && line.starts_with('#')
+    })
+}
+
+/// Method names sharing the most `_`-separated words with `name`.
+fn closest<'a>(groups: &[Group<'a>], name: &str) -> Vec<&'a str> {
+    let words: Vec<String> = name.to_lowercase().split('_').map(String::from).collect();
+    let mut scored: Vec<(usize, &str)> = groups
+        .iter()
+        .flat_map(|g| &g.methods)
+        .map(|m| {
+            let lower = m.name.to_lowercase();
+            let shared = lower
+                .split('_')
+                .filter(|w| words.iter().any(|q| q == w))
+                .count();
+            (shared, m.name)
+        })
+        .filter(|(shared, _)| *shared * 2 >= words.len() && *shared > 0)
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(b.1)));
+    scored.dedup_by_key(|(_, name)| *name);
+    scored.into_iter().take(5).map(|(_, name)| name).collect()
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListMethodsRequest {
+    #[schemars(
+        description = "Type or trait to list methods for, e.g. \"TransactionRequest\", \"Filter\", \"U256\", \"ProviderBuilder\" or \"Provider\". Paths and generics are ignored"
+    )]
+    type_name: String,
+    #[schemars(
+        description = "Only show methods whose name contains this, and say whether a method of exactly this name exists, e.g. \"with_gas_price\""
+    )]
+    method: Option<String>,
+}
+
+impl AlloyMcpServer {
+    /// Generated API pages titled `name`, exact matches first and
+    /// case-insensitive ones only when there are none.
+    fn api_pages(&self, name: &str) -> Vec<&StaticResource> {
+        let title = format!("# `{name}`");
+        let api = || {
+            self.resources
+                .values()
+                .filter(|r| r.uri.starts_with("alloy://api/"))
+        };
+        let mut pages: Vec<&StaticResource> = api()
+            .filter(|r| r.content.lines().next() == Some(title.as_str()))
+            .collect();
+        if pages.is_empty() {
+            pages = api()
+                .filter(|r| {
+                    r.content
+                        .lines()
+                        .next()
+                        .is_some_and(|l| l.eq_ignore_ascii_case(&title))
+                })
+                .collect();
+        }
+        pages.sort_by(|a, b| a.uri.cmp(&b.uri));
+        pages
+    }
+
+    /// Trait pages whose implementors include `name`, with the trait as
+    /// implemented for it.
+    fn implemented_traits(&self, name: &str) -> Vec<(String, &StaticResource)> {
+        let mut found: Vec<(String, &StaticResource)> = self
+            .resources
+            .values()
+            .filter(|r| r.uri.starts_with("alloy://api/"))
+            .filter_map(|page| {
+                let implementors = resources::section(&page.content, "implementors")?;
+                implementors.lines().find_map(|line| {
+                    let (r#trait, ty) = impl_parts(line.strip_prefix("- ")?)?;
+                    (base_name(ty) == name).then(|| (r#trait.to_string(), page))
+                })
+            })
+            .collect();
+        found.sort_by(|a, b| a.1.uri.cmp(&b.1.uri));
+        found
+    }
+}
+
+#[tool_router(router = methods_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// List a type's inherent and trait methods from the generated API pages
+    /// and `alloy://reference/methods`.
+    #[tool(
+        description = "List the methods of an alloy type or trait (e.g. TransactionRequest, Filter, U256, ProviderBuilder, Provider, PrivateKeySigner) with signatures and a one-line description, grouped into inherent methods and the methods of each implemented trait, with the `use` line each trait needs. Pass `method` to check whether a method exists before calling it; unknown names get the closest real ones. Covers the full API when the server is started with --rustdoc, otherwise the commonly used methods."
+    )]
+    fn list_methods(
+        &self,
+        Parameters(ListMethodsRequest { type_name, method }): Parameters<ListMethodsRequest>,
+    ) -> String {
+        let name = type_name.trim().trim_start_matches('&');
+        let name = name.split('<').next().unwrap_or(name);
+        let name = name.rsplit("::").next().unwrap_or(name).trim();
+        if name.is_empty() {
+            return "No type given. Pass a type or trait name as `type_name`, e.g. `TransactionRequest`."
+                .into();
+        }
+        let query = method.as_deref().map(str::trim).filter(|m| !m.is_empty());
+
+        // API data first: an alias page hands over to the aliased type.
+        let pages = self.api_pages(name);
+        let mut sources: Vec<String> = Vec::new();
+        let mut page = pages.first().copied();
+        let mut title = page.map_or(name.to_string(), |p| {
+            p.content
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .trim_start_matches("# ")
+                .trim_matches('`')
+                .to_string()
+        });
+        let mut aliased = None;
+        if let Some(alias) = page {
+            let target = alias
+                .content
+                .split_once("\nMethods and trait implementations: `")
+                .and_then(|(_, rest)| rest.split('`').next());
+            if let Some(target) = target.and_then(|uri| self.resources.get(uri)) {
+                sources.push(alias.uri.clone());
+                aliased = target
+                    .content
+                    .lines()
+                    .next()
+                    .map(|l| l.trim_start_matches("# ").trim_matches('`').to_string());
+                page = Some(target);
+            }
+        }
+        let type_name = aliased.as_deref().unwrap_or(&title).to_string();
+
+        let mut groups: Vec<Group> = Vec::new();
+        let mut is_trait = false;
+        let mut summary = None;
+        if let Some(page) = page {
+            sources.push(page.uri.clone());
+            is_trait = page.content.contains("\nTrait `");
+            summary = page
+                .content
+                .lines()
+                .find(|l| l.contains(" in alloy") && l.ends_with('.'))
+                .map(String::from);
+            let own = listing_groups(&page.content);
+            // A trait's page lists provided methods, which impl sections omit.
+            let mut traits = Vec::new();
+            if !is_trait {
+                for (r#trait, trait_page) in self.implemented_traits(&type_name) {
+                    sources.push(trait_page.uri.clone());
+                    traits.extend(
+                        listing_groups(&trait_page.content)
+                            .into_iter()
+                            .filter(|g| g.via.is_none())
+                            .map(|g| Group {
+                                via: Some(r#trait.clone()),
+                                methods: g.methods,
+                            }),
+                    );
+                }
+            }
+            let (inherent, implemented): (Vec<Group>, Vec<Group>) =
+                own.into_iter().partition(|g| g.via.is_none());
+            merge(&mut groups, inherent);
+            merge(&mut groups, traits);
+            merge(&mut groups, implemented);
+        }
+        let from_api = !groups.is_empty();
+
+        // Then the curated reference, by the requested or the aliased name.
+        let curated_uri = [name, type_name.as_str()].iter().find_map(|n| {
+            let uri = format!("{METHODS_URI}#{}", resources::anchor(n));
+            resources::lookup(&self.resources, &uri).map(|listing| (uri, listing))
+        });
+        let mut intro = None;
+        if let Some((uri, listing)) = &curated_uri {
+            sources.push(uri.clone());
+            is_trait |= page.is_none() && is_trait_listing(listing);
+            if page.is_none() {
+                title = listing
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .trim_start_matches('#')
+                    .trim()
+                    .to_string();
+            }
+            intro = listing
+                .split("\n\n")
+                .nth(1)
+                .filter(|p| !p.starts_with('#'))
+                .map(String::from);
+            merge(&mut groups, listing_groups(listing));
+        }
+
+        if groups.is_empty() {
+            let covered: Vec<String> = resources::lookup(&self.resources, METHODS_URI)
+                .unwrap_or_default()
+                .lines()
+                .filter_map(|l| l.strip_prefix("## "))
+                .map(|t| format!("`{t}`"))
+                .collect();
+            let api = if self.resources.contains_key("alloy://api") {
+                "the generated API pages"
+            } else {
+                "the generated API pages (start the server with `--rustdoc <dir>` to load them)"
+            };
+            return format!(
+                "No methods known for `{name}`. The method reference covers {}; anything else \
+                comes from {api}. Check the spelling with `resolve_import`, or use `lookup_type` \
+                for the guides that mention it.",
+                covered.join(", ")
+            );
+        }
+
+        // `method` narrows the listing to matching names, or to the closest
+        // ones when nothing matches.
+        let total: usize = groups.iter().map(|g| g.methods.len()).sum();
+        let mut exact = None;
+        let mut suggestions = Vec::new();
+        if let Some(query) = query {
+            exact = groups.iter().find_map(|g| {
+                g.methods
+                    .iter()
+                    .find(|m| m.name == query)
+                    .map(|_| g.via.clone())
+            });
+            let lower = query.to_lowercase();
+            let mut keep: Vec<&str> = groups
+                .iter()
+                .flat_map(|g| &g.methods)
+                .filter(|m| m.name.to_lowercase().contains(&lower))
+                .map(|m| m.name)
+                .collect();
+            if exact.is_none() {
+                suggestions = closest(&groups, query);
+                if keep.is_empty() {
+                    keep = suggestions.clone();
+                }
+            }
+            for group in &mut groups {
+                group.methods.retain(|m| keep.contains(&m.name));
+            }
+            groups.retain(|g| !g.methods.is_empty());
+        }
+
+        let mut result = format!("# Methods of `{title}`\n\n");
+        if let Some(aliased) = &aliased {
+            let _ = writeln!(result, "`{title}` is an alias of `{aliased}`.\n");
+        }
+        if let Some(text) = intro.as_ref().or(summary.as_ref()) {
+            let _ = writeln!(result, "{text}\n");
+        }
+        let paths = resources::lookup(&self.resources, "alloy://imports/paths").unwrap_or("");
+        for group in &groups {
+            match (&group.via, group.trait_name()) {
+                (Some(via), Some(r#trait)) => {
+                    let _ = writeln!(result, "## `{via}` methods\n");
+                    if let Some(path) = imports::import_path(paths, r#trait) {
+                        let _ = writeln!(result, "```rust\nuse {path};\n```\n");
+                    }
+                }
+                _ if is_trait => {
+                    result.push_str("## Trait methods\n\n");
+                    if let Some(path) = imports::import_path(paths, &title) {
+                        let _ = writeln!(result, "```rust\nuse {path};\n```\n");
+                    }
+                }
+                _ => result.push_str("## Inherent methods\n\n"),
+            }
+            for method in &group.methods {
+                let _ = writeln!(result, "{}", method.line);
+            }
+            result.push('\n');
+        }
+
+        let mut notes = Vec::new();
+        let traits = groups.iter().filter(|g| g.via.is_some()).count();
+        let shown: usize = groups.iter().map(|g| g.methods.len()).sum();
+        let from_traits: usize = groups
+            .iter()
+            .filter(|g| g.via.is_some())
+            .map(|g| g.methods.len())
+            .sum();
+        notes.push(if shown < total {
+            format!("Showing {shown} of {total} methods.")
+        } else if traits > 0 {
+            format!("{total} method(s): {from_traits} from {traits} trait(s).")
+        } else {
+            format!("{total} method(s).")
+        });
+        match (query, &exact) {
+            (Some(query), Some(Some(via))) => notes.push(format!(
+                "`{query}` is a method of `{via}`: import the trait (the `use` line above) to call it."
+            )),
+            (Some(query), Some(None)) if is_trait => notes.push(format!(
+                "`{query}` is a method of the `{title}` trait: import it (the `use` line above) to call it."
+            )),
+            (Some(query), Some(None)) => {
+                notes.push(format!("`{query}` is an inherent method of `{title}`."))
+            }
+            (Some(query), None) => notes.push(if suggestions.is_empty() {
+                format!("`{title}` has no method `{query}`.")
+            } else {
+                format!(
+                    "`{title}` has no method `{query}`. Closest: {}.",
+                    suggestions
+                        .iter()
+                        .map(|s| format!("`{s}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }),
+            (None, _) => {}
+        }
+        if traits > 0 {
+            notes.push(
+                "Trait methods need the trait in scope: rustc's \"items from traits can only be \
+                used if the trait is in scope\" means the `use` line above is missing."
+                    .into(),
+            );
+        }
+        if from_api {
+            notes.push(format!(
+                "Generated from the loaded rustdoc JSON: a method not listed here is not defined \
+                on `{type_name}` or its alloy traits, unless it needs a feature the docs were \
+                built without. Derived and blanket impls (`Clone`, `Debug`, `Serialize`, ...) \
+                and traits from crates that were not loaded are left out."
+            ));
+        } else {
+            notes.push(
+                "Commonly used methods only, from `alloy://reference/methods`. Start the server \
+                with `--rustdoc <dir>` to list every method from the generated API."
+                    .into(),
+            );
+        }
+        if pages.len() > 1 {
+            notes.push(format!(
+                "Other types named `{title}`: {}.",
+                pages[1..]
+                    .iter()
+                    .map(|p| format!("`{}`", p.uri))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        result.push_str("## Notes\n\n");
+        for note in &notes {
+            let _ = writeln!(result, "- {note}");
+        }
+
+        result.push_str("\n**Key resources:**\n");
+        for uri in &sources {
+            let _ = writeln!(
+                result,
+                "- `{uri}` — {}",
+                if uri.starts_with(METHODS_URI) {
+                    "Method reference"
+                } else {
+                    "Generated API page"
+                }
+            );
+        }
+        if traits > 0 || is_trait {
+            result.push_str("- `alloy://imports/paths` — Where each trait is imported from\n");
+        }
+        result
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt::Write;
+
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+
+use super::codegen::Imports;
+use crate::{migration, server::AlloyMcpServer};
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct MigrateFromEthersRequest {
+    #[schemars(description = "ethers-rs Rust code to rewrite: a snippet, function or whole file")]
+    code: String,
+}
+
+#[tool_router(router = migrate_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Rewrite ethers-rs code to alloy using the table behind
+    /// `alloy://migration/ethers`.
+    #[tool(
+        description = "Rewrite an ethers-rs snippet to alloy: Provider<Http> and SignerMiddleware become ProviderBuilder, H160/H256/U256 become alloy-primitives types, abigen! becomes sol!, TypedTransaction becomes TransactionRequest, LocalWallet becomes PrivateKeySigner. Adds the alloy imports and leaves `// TODO(alloy):` notes where manual work is needed."
+    )]
+    fn migrate_from_ethers(
+        &self,
+        Parameters(MigrateFromEthersRequest { code }): Parameters<MigrateFromEthersRequest>,
+    ) -> String {
+        if code.trim().is_empty() {
+            return "No code given. Pass the ethers-rs snippet to migrate as `code`.".into();
+        }
+        let migration = migration::migrate(&code);
+        if migration.applied.is_empty()
+            && migration.todos.is_empty()
+            && migration.removed_uses == 0
+            && migration.stripped_paths == 0
+        {
+            return "No ethers-rs constructs found. See `alloy://migration/ethers` for the \
+                full mapping table."
+                .into();
+        }
+
+        let mut imports = Imports::default();
+        for path in &migration.imports {
+            if let Some((module, item)) = path.rsplit_once("::") {
+                imports.add(module, item);
+            }
+        }
+        let mut result = format!(
+            "# ethers-rs → alloy\n\n## Rust\n\n```rust\n{}\n{}```\n",
+            imports.render(),
+            migration.code
+        );
+
+        if !migration.applied.is_empty() {
+            result.push_str(
+                "\n## Changes\n\n| ethers-rs | alloy | Count |\n|-----------|-------|-------|\n",
+            );
+            // Renames to the same name only contribute imports.
+            for (mapping, count) in migration
+                .applied
+                .iter()
+                .filter(|(m, _)| m.ethers != m.alloy)
+            {
+                let _ = writeln!(
+                    result,
+                    "| `{}` | `{}` | {count} |",
+                    mapping.ethers, mapping.alloy
+                );
+            }
+        }
+
+        if !migration.todos.is_empty() {
+            result.push_str("\n## Manual work\n\n");
+            for mapping in &migration.todos {
+                let _ = writeln!(
+                    result,
+                    "- `{}`: {}",
+                    mapping.ethers,
+                    mapping.note.unwrap_or("no automatic rewrite.")
+                );
+            }
+        }
+
+        let mut notes: Vec<String> = migration
+            .applied
+            .iter()
+            .filter(|(m, _)| !m.manual && !migration.todos.iter().any(|t| std::ptr::eq(*t, *m)))
+            .filter_map(|(m, _)| m.note)
+            .map(String::from)
+            .collect();
+        let mut removed = Vec::new();
+        if migration.removed_uses > 0 {
+            removed.push(format!(
+                "{} `use ethers...` statement(s)",
+                migration.removed_uses
+            ));
+        }
+        if migration.stripped_paths > 0 {
+            removed.push(format!(
+                "{} `ethers::` path prefix(es)",
+                migration.stripped_paths
+            ));
+        }
+        if !removed.is_empty() {
+            notes.push(format!(
+                "Removed {}; the imports above replace them.",
+                removed.join(" and ")
+            ));
+        }
+        notes.push(
+            "Replace the `ethers` dependency with `alloy = { version = \"1\", features = [\"full\"] }`."
+                .into(),
+        );
+        result.push_str("\n## Notes\n\n");
+        for note in &notes {
+            let _ = writeln!(result, "- {note}");
+        }
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://migration/ethers` — Full ethers-rs → alloy mapping table\n\
+            - `alloy://provider/setup` — ProviderBuilder and wallets\n\
+            - `alloy://sol-macro/contract-bindings` — sol! contract bindings\n\
+            - `alloy://signers/signing-guide` — PrivateKeySigner and signing\n",
+        );
+        result
+    }
+}
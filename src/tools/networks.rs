@@ -0,0 +1,247 @@
+use std::fmt::Write;
+
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+
+use crate::{
+    networks::{self, ASSOCIATED, Assoc, NETWORKS, Network},
+    server::AlloyMcpServer,
+};
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct NetworkTypesRequest {
+    #[schemars(
+        description = "Network type or chain, e.g. \"Ethereum\", \"AnyNetwork\", \"Optimism\" or \"base\". Omit to compare every network"
+    )]
+    network: Option<String>,
+    #[schemars(
+        description = "Associated type such as \"N::ReceiptResponse\" or \"TxEnvelope\", or a concrete type such as \"OpTransactionReceipt\" to see which `N::` type it is"
+    )]
+    type_name: Option<String>,
+}
+
+/// The associated type name in `N::TxEnvelope`, `<N as Network>::TxEnvelope`
+/// or `TxEnvelope`, as `Network` declares it.
+fn associated(name: &str) -> Option<&'static str> {
+    let name = name.trim().rsplit("::").next().unwrap_or_default();
+    ASSOCIATED
+        .iter()
+        .map(|(assoc, _)| *assoc)
+        .find(|assoc| assoc.eq_ignore_ascii_case(name))
+}
+
+/// The associated types `name` is the concrete type of, by network. A full
+/// path only matches the types at that path.
+fn concrete(name: &str) -> Vec<(&'static Network, &'static Assoc)> {
+    let name = name.trim();
+    let by_path: Vec<_> = NETWORKS
+        .iter()
+        .flat_map(|n| n.types.iter().map(move |a| (n, a)))
+        .filter(|(_, a)| name.contains("::") && a.path.eq_ignore_ascii_case(name))
+        .collect();
+    if !by_path.is_empty() {
+        return by_path;
+    }
+    let base = name.split('<').next().unwrap_or(name);
+    let base = base.rsplit("::").next().unwrap_or(base).trim();
+    NETWORKS
+        .iter()
+        .flat_map(|n| n.types.iter().map(move |a| (n, a)))
+        .filter(|(_, a)| {
+            a.ty.eq_ignore_ascii_case(name)
+                || a.ty
+                    .split('<')
+                    .next()
+                    .is_some_and(|t| t.eq_ignore_ascii_case(base))
+                || a.path.ends_with(&format!("::{base}")) && !a.ty.contains('<')
+        })
+        .collect()
+}
+
+/// One associated type across every network.
+fn across(assoc: &str) -> String {
+    let mut table =
+        String::from("| Network | Concrete type | Import | Notes |\n|---|---|---|---|\n");
+    for network in NETWORKS {
+        if let Some(a) = network.types.iter().find(|a| a.name == assoc) {
+            let _ = writeln!(
+                table,
+                "| `{}` | `{}` | `{}` | {} |",
+                network.name, a.ty, a.path, a.note
+            );
+        }
+    }
+    table
+}
+
+#[tool_router(router = networks_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Resolve `N::` associated types to concrete types from the table behind
+    /// `alloy://network/{network}`.
+    #[tool(
+        description = "Show which concrete type each associated type of an alloy Network resolves to (N::TxEnvelope, N::UnsignedTx, N::TransactionRequest, N::ReceiptResponse, N::HeaderResponse, N::BlockResponse, ...) for Ethereum, AnyNetwork and Optimism (OP-stack chains such as Base), with imports and how the types convert into each other. Pass `type_name` to resolve one `N::` type, or a concrete type (e.g. OpTransactionReceipt) to see which associated type it is. Use it when generic code over `N: Network` or a custom-network provider does not compile."
+    )]
+    fn network_types(
+        &self,
+        Parameters(NetworkTypesRequest { network, type_name }): Parameters<NetworkTypesRequest>,
+    ) -> String {
+        let network_name = network.as_deref().map(str::trim).filter(|n| !n.is_empty());
+        let type_name = type_name
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty());
+        let network = match network_name.map(networks::find) {
+            Some(None) => {
+                return format!(
+                    "# Unknown network `{}`\n\nalloy has types for {}. Chains that only add \
+                    fields or transaction types can use `AnyNetwork`; chains with their own \
+                    signed transaction types need a custom `Network` implementation.\n\n\
+                    **Key resources:**\n\
+                    - `alloy://network` — Every network's associated types\n\
+                    - `alloy://network/any` — The catch-all network\n",
+                    network_name.unwrap_or_default(),
+                    NETWORKS
+                        .iter()
+                        .map(|n| format!("`{}` (`{}`)", n.name, n.path))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+            found => found.flatten(),
+        };
+
+        let mut result = match (network, type_name) {
+            (None, None) => networks::index(),
+            (Some(network), None) => networks::guide(network),
+            (network, Some(name)) => match associated(name) {
+                Some(assoc) => {
+                    // `Header` and `TransactionRequest` also name concrete types
+                    // of other associated types.
+                    let also: Vec<String> = concrete(name)
+                        .into_iter()
+                        .filter(|(n, a)| {
+                            a.name != assoc && network.is_none_or(|m| m.name == n.name)
+                        })
+                        .map(|(n, a)| {
+                            format!("`<{} as Network>::{}` (`{}`)", n.name, a.name, a.path)
+                        })
+                        .collect();
+                    let what = ASSOCIATED
+                        .iter()
+                        .find(|(a, _)| *a == assoc)
+                        .map_or("", |(_, what)| what);
+                    let mut text = match network
+                        .and_then(|n| Some((n, n.types.iter().find(|a| a.name == assoc)?)))
+                    {
+                        Some((network, a)) => format!(
+                            "# `<{} as Network>::{assoc}`\n\n`{assoc}` — {what}.\n\n\
+                            For `{}` it is **`{}`**.\n\n```rust\nuse {};\n```\n\n{}\n\n\
+                            ## Other Networks\n\n{}",
+                            network.name,
+                            network.name,
+                            a.ty,
+                            a.path,
+                            a.note,
+                            across(assoc)
+                        ),
+                        None => {
+                            format!("# `N::{assoc}`\n\n`{assoc}` — {what}.\n\n{}", across(assoc))
+                        }
+                    };
+                    if !also.is_empty() {
+                        let _ = write!(
+                            text,
+                            "\nNot to be confused with the concrete `{name}` that is {}.\n",
+                            also.join(", ")
+                        );
+                    }
+                    text
+                }
+                None => {
+                    let mut found = concrete(name);
+                    if let Some(network) = network {
+                        found.retain(|(n, _)| n.name == network.name);
+                    }
+                    if found.is_empty() {
+                        format!(
+                            "# `{name}`\n\n`{name}` is not an associated type of `Network` nor \
+                            the concrete type of one{}. The associated types are {}.\n",
+                            network
+                                .map(|n| format!(" for `{}`", n.name))
+                                .unwrap_or_default(),
+                            ASSOCIATED
+                                .iter()
+                                .map(|(a, _)| format!("`N::{a}`"))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                    } else {
+                        let mut text = format!("# `{name}`\n\n");
+                        for (network, a) in &found {
+                            let _ = writeln!(
+                                text,
+                                "- `{}` is `<{} as Network>::{}` (`use {};`). {}",
+                                a.ty, network.name, a.name, a.path, a.note
+                            );
+                        }
+                        let mut seen = Vec::new();
+                        for (_, a) in &found {
+                            if !seen.contains(&a.name) {
+                                seen.push(a.name);
+                                let _ = write!(
+                                    text,
+                                    "\n## `N::{}` on every network\n\n{}",
+                                    a.name,
+                                    across(a.name)
+                                );
+                            }
+                        }
+                        text
+                    }
+                }
+            },
+        };
+
+        result.push_str("\n**Key resources:**\n");
+        if let Some(network) = network {
+            let _ = writeln!(
+                result,
+                "- `alloy://network/{}` — `{}` types and how they relate",
+                network.slug, network.name
+            );
+        }
+        result.push_str(
+            "- `alloy://network` — Every network's associated types side by side\n\
+            - `alloy://provider/setup` — Providers for other networks\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn types(network: Option<&str>, type_name: Option<&str>) -> String {
+        AlloyMcpServer::new().network_types(Parameters(NetworkTypesRequest {
+            network: network.map(Into::into),
+            type_name: type_name.map(Into::into),
+        }))
+    }
+
+    #[test]
+    fn resolves_associated_types_per_network() {
+        let result = types(Some("optimism"), Some("N::ReceiptEnvelope"));
+        assert!(result.starts_with("# `<Optimism as Network>::ReceiptEnvelope`"));
+        assert!(result.contains("use op_alloy::consensus::OpReceiptEnvelope;"));
+        assert!(types(Some("base"), None).starts_with("# `Optimism` Network Types"));
+    }
+
+    #[test]
+    fn finds_the_associated_type_of_a_concrete_type() {
+        let result = types(None, Some("OpTransactionReceipt"));
+        assert!(
+            result.contains("`OpTransactionReceipt` is `<Optimism as Network>::ReceiptResponse`")
+        );
+        assert!(result.contains("`TransactionReceipt<OpReceiptEnvelope<Log>>`"));
+    }
+}
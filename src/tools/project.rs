@@ -0,0 +1,207 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use super::{
+    features,
+    imports::{self, Setup},
+};
+use crate::{resources, server::AlloyMcpServer, workspace::Workspace};
+
+/// How `workspace` depends on alloy, for tools that pick an import style.
+pub(super) fn setup(workspace: &Workspace) -> Setup {
+    if workspace.umbrella().is_some() {
+        Setup::Umbrella
+    } else {
+        Setup::Crates(workspace.dependencies.keys().cloned().collect())
+    }
+}
+
+/// Whether the project can import through `via` (`alloy` or an `alloy-*`
+/// crate) when the path needs `feature` of it.
+pub(super) fn available(workspace: &Workspace, via: &str, feature: Option<&str>) -> bool {
+    let Some(dependency) = workspace.dependencies.get(via) else {
+        return false;
+    };
+    let enabled = dependency.features.iter().map(String::as_str);
+    match feature {
+        None => true,
+        Some(feature) if via == "alloy" => {
+            features::umbrella_features(enabled, dependency.default_features).contains(feature)
+        }
+        Some(feature) => {
+            features::crate_has_feature(via, feature, enabled, dependency.default_features)
+        }
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Replace the path `from` with `to` where it stands on its own: not part of
+/// a longer identifier and not inside another path (`crate::alloy::...`).
+fn replace_path(line: &str, from: &str, to: &str) -> (String, usize) {
+    let mut result = String::with_capacity(line.len());
+    let mut count = 0;
+    let mut last = 0;
+    for (pos, _) in line.match_indices(from) {
+        let end = pos + from.len();
+        let before = line[..pos].chars().next_back();
+        let after = line[end..].chars().next();
+        if pos < last
+            || before.is_some_and(|c| is_ident_char(c) || c == ':')
+            || after.is_some_and(is_ident_char)
+        {
+            continue;
+        }
+        result.push_str(&line[last..pos]);
+        result.push_str(to);
+        last = end;
+        count += 1;
+    }
+    result.push_str(&line[last..]);
+    (result, count)
+}
+
+/// Crates a piece of Rust code refers to by path (`alloy` for the umbrella,
+/// `alloy-consensus` for `alloy_consensus::...`).
+fn crate_roots(code: &str) -> BTreeSet<String> {
+    let mut roots = BTreeSet::new();
+    for (pos, _) in code.match_indices("alloy") {
+        let before = code[..pos].chars().next_back();
+        if before.is_some_and(|c| is_ident_char(c) || c == ':') {
+            continue;
+        }
+        let len = code[pos..]
+            .find(|c: char| !is_ident_char(c))
+            .unwrap_or(code.len() - pos);
+        let ident = &code[pos..pos + len];
+        if code[pos + len..].starts_with("::") && (ident == "alloy" || ident.starts_with("alloy_"))
+        {
+            roots.insert(ident.replace('_', "-"));
+        }
+    }
+    roots
+}
+
+impl AlloyMcpServer {
+    /// Fit a tool's output to the client's project: switch the paths in its
+    /// Rust code to the project's import style, and flag crates and
+    /// features that code needs but the project lacks.
+    pub(crate) fn tailor(&self, workspace: &Workspace, text: String) -> String {
+        if !text.contains("```rust") {
+            return text;
+        }
+        let content = resources::lookup(&self.resources, "alloy://imports/paths").unwrap_or("");
+        let mut prefixes = imports::umbrella_prefixes(content);
+        let umbrella = workspace.umbrella();
+        if umbrella.is_some() {
+            // Crate paths back to the umbrella, for crates the project does
+            // not depend on directly. The first umbrella path wins.
+            let mut seen = BTreeSet::new();
+            prefixes = prefixes
+                .into_iter()
+                .map(|(umbrella, krate)| (krate, umbrella))
+                .filter(|(krate, _)| {
+                    let root = krate.split("::").next().unwrap_or(krate).replace('_', "-");
+                    !workspace.dependencies.contains_key(&root) && seen.insert(krate.clone())
+                })
+                .collect();
+        }
+        prefixes.sort_by_key(|(from, _)| std::cmp::Reverse(from.len()));
+
+        let mut rewritten = 0;
+        let mut code = String::new();
+        let mut in_rust = false;
+        let mut lines = Vec::new();
+        for line in text.lines() {
+            let mut line = line.to_string();
+            if line.trim_start().starts_with("```") {
+                in_rust = !in_rust && line.trim_start().starts_with("```rust");
+            } else if in_rust {
+                for (from, to) in &prefixes {
+                    let (replaced, count) = replace_path(&line, from, to);
+                    line = replaced;
+                    rewritten += count;
+                }
+                code.push_str(&line);
+                code.push('\n');
+            }
+            lines.push(line);
+        }
+        let mut result = lines.join("\n");
+        if text.ends_with('\n') {
+            result.push('\n');
+        }
+
+        let requirement = workspace.requirement();
+        let mut notes = Vec::new();
+        if rewritten > 0 {
+            notes.push(match umbrella {
+                Some(_) => format!(
+                    "Rewrote {rewritten} path(s) to `alloy::` since the project depends on the umbrella crate."
+                ),
+                None => format!(
+                    "Rewrote {rewritten} path(s) to `alloy_*` crates since the project depends on them directly."
+                ),
+            });
+        }
+        let mut fix: BTreeSet<String> = BTreeSet::new();
+        match umbrella {
+            Some(alloy) => {
+                let enabled = features::umbrella_features(
+                    alloy.features.iter().map(String::as_str),
+                    alloy.default_features,
+                );
+                for (gate, mention) in features::gates_in(&code) {
+                    if !enabled.contains(gate.feature) && fix.insert(gate.feature.to_string()) {
+                        notes.push(format!(
+                            "`{mention}` needs the `{}` feature, which the project does not enable.",
+                            gate.feature
+                        ));
+                    }
+                }
+                if !fix.is_empty() {
+                    notes.push(format!(
+                        "Fix: `{}`.",
+                        alloy.line("alloy", fix.iter().map(String::as_str))
+                    ));
+                }
+            }
+            None => {
+                for krate in crate_roots(&code) {
+                    if !workspace.dependencies.contains_key(&krate) && fix.insert(krate.clone()) {
+                        notes.push(format!(
+                            "The code uses `{krate}`, which the project does not depend on: add `{krate} = \"{requirement}\"`."
+                        ));
+                    }
+                }
+                for (gate, mention) in features::gates_in(&code) {
+                    let Some(feature) = gate.crate_feature else {
+                        continue;
+                    };
+                    if workspace.dependencies.contains_key(gate.krate)
+                        && !available(workspace, gate.krate, Some(feature))
+                    {
+                        notes.push(format!(
+                            "`{mention}` needs the `{feature}` feature of `{}`, which the project does not enable.",
+                            gate.krate
+                        ));
+                    }
+                }
+            }
+        }
+
+        if !notes.is_empty() {
+            let _ = write!(
+                result,
+                "\n## Project\n\nChecked against {} in `{}`.\n\n",
+                workspace.summary(),
+                workspace.root.display()
+            );
+            for note in &notes {
+                let _ = writeln!(result, "- {note}");
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,1055 @@
+use std::fmt::Write;
+
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+use serde_json::Value;
+
+use super::codegen::{Imports, is_ident, snake, sol_int_type};
+use crate::server::AlloyMcpServer;
+
+/// Fields alloy's own transaction types share, with the Rust type they have
+/// there. A custom type using one of these names backs the matching
+/// `Transaction` getter with it.
+const STANDARD_FIELDS: [(&str, &str); 11] = [
+    ("chain_id", "ChainId"),
+    ("nonce", "u64"),
+    ("gas_limit", "u64"),
+    ("gas_price", "u128"),
+    ("max_fee_per_gas", "u128"),
+    ("max_priority_fee_per_gas", "u128"),
+    ("to", "TxKind"),
+    ("value", "U256"),
+    ("access_list", "AccessList"),
+    ("authorization_list", "Vec<SignedAuthorization>"),
+    ("input", "Bytes"),
+];
+
+/// JSON-RPC and ethers names for the standard fields.
+const FIELD_ALIASES: [(&str, &str); 3] = [
+    ("gas", "gas_limit"),
+    ("data", "input"),
+    ("chain", "chain_id"),
+];
+
+/// Rust types a field may name directly, with the module to import them from.
+const KNOWN_TYPES: [(&str, &str); 14] = [
+    ("Address", "alloy::primitives"),
+    ("B64", "alloy::primitives"),
+    ("B128", "alloy::primitives"),
+    ("B256", "alloy::primitives"),
+    ("Bloom", "alloy::primitives"),
+    ("Bytes", "alloy::primitives"),
+    ("ChainId", "alloy::primitives"),
+    ("FixedBytes", "alloy::primitives"),
+    ("TxKind", "alloy::primitives"),
+    ("U64", "alloy::primitives"),
+    ("U128", "alloy::primitives"),
+    ("U256", "alloy::primitives"),
+    ("AccessList", "alloy::eips::eip2930"),
+    ("SignedAuthorization", "alloy::eips::eip7702"),
+];
+
+/// Types that need no import.
+const PRELUDE_TYPES: [&str; 8] = ["bool", "u8", "u16", "u32", "u64", "u128", "String", "Vec"];
+
+/// The `Transaction` getters, for delegating from the unsigned enum.
+const GETTERS: [(&str, &str, &str); 17] = [
+    ("chain_id", "", "Option<ChainId>"),
+    ("nonce", "", "u64"),
+    ("gas_limit", "", "u64"),
+    ("gas_price", "", "Option<u128>"),
+    ("max_fee_per_gas", "", "u128"),
+    ("max_priority_fee_per_gas", "", "Option<u128>"),
+    ("max_fee_per_blob_gas", "", "Option<u128>"),
+    ("priority_fee_or_price", "", "u128"),
+    ("effective_gas_price", "base_fee: Option<u64>", "u128"),
+    ("is_dynamic_fee", "", "bool"),
+    ("kind", "", "TxKind"),
+    ("is_create", "", "bool"),
+    ("value", "", "U256"),
+    ("input", "", "&Bytes"),
+    ("access_list", "", "Option<&AccessList>"),
+    ("blob_versioned_hashes", "", "Option<&[B256]>"),
+    ("authorization_list", "", "Option<&[SignedAuthorization]>"),
+];
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct TxFieldSpec {
+    #[schemars(
+        description = "Field name, snake_case or camelCase (e.g. \"sponsor\", \"maxFeePerGas\")"
+    )]
+    name: String,
+    #[schemars(
+        description = "Rust type (\"Address\", \"u64\", \"Bytes\", \"TxKind\", \"Vec<B256>\") or Solidity type (\"address\", \"uint64\", \"bytes32\", \"uint256[]\")"
+    )]
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct TxTypeSpec {
+    #[schemars(
+        description = "Transaction type name, e.g. \"Sponsored\" (generates `TxSponsored`)"
+    )]
+    name: String,
+    #[schemars(
+        description = "EIP-2718 type byte: an integer or hex string between 0x05 and 0x7f (0-4 are Ethereum's)"
+    )]
+    type_id: Value,
+    #[schemars(
+        description = "Fields in RLP encoding order. Standard names (chain_id, nonce, gas_limit, gas_price, max_fee_per_gas, max_priority_fee_per_gas, to, value, access_list, authorization_list, input) back the `Transaction` getters and get alloy's types"
+    )]
+    fields: Vec<TxFieldSpec>,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ScaffoldNetworkRequest {
+    #[schemars(description = "Network name, e.g. \"MyChain\" or \"my-chain\"")]
+    name: String,
+    #[schemars(description = "The chain's own transaction types, next to Ethereum's")]
+    tx_types: Vec<TxTypeSpec>,
+}
+
+/// One field of a custom transaction struct.
+struct Field {
+    name: String,
+    ty: String,
+    /// `#[serde(...)]` arguments.
+    serde: Vec<String>,
+}
+
+/// One custom transaction type, normalized.
+struct TxType {
+    /// Envelope variant, e.g. `Sponsored`.
+    variant: String,
+    /// Struct name, e.g. `TxSponsored`.
+    ident: String,
+    id: u8,
+    fields: Vec<Field>,
+}
+
+impl TxType {
+    fn field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    fn has(&self, name: &str) -> bool {
+        self.field(name).is_some()
+    }
+}
+
+/// `my-chain`, `my_chain` or `myChain` as `MyChain`.
+fn upper_camel(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn parse_type_id(value: &Value) -> Result<u8, String> {
+    let id = match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => {
+            let s = s.trim();
+            match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+                Some(hex) => u64::from_str_radix(hex, 16).ok(),
+                None => s.parse().ok(),
+            }
+        }
+        _ => None,
+    };
+    id.and_then(|id| u8::try_from(id).ok())
+        .ok_or_else(|| format!("`type_id` must be a byte (integer or hex string), got {value}"))
+}
+
+/// The Rust type for a field type given in Rust or Solidity, adding its
+/// imports. Solidity names are mapped the way `sol!` maps them.
+fn field_type(ty: &str, imports: &mut Imports) -> Result<String, String> {
+    let ty = ty.trim();
+    if let Some(inner) = ty.strip_suffix("[]") {
+        return Ok(format!("Vec<{}>", field_type(inner, imports)?));
+    }
+    if let Some(inner) = ty.strip_prefix("Vec<").and_then(|t| t.strip_suffix('>')) {
+        return Ok(format!("Vec<{}>", field_type(inner, imports)?));
+    }
+    if ty.starts_with("Option<") {
+        return Err(format!(
+            "`{ty}`: RLP has no optional fields. Encode absence in the value (e.g. `TxKind` for \
+            `to`, an empty `Bytes`) or give the transaction type a new field layout."
+        ));
+    }
+    let sol = |prefix: &str| ty.strip_prefix(prefix).map(|bits| bits.parse::<usize>());
+    match ty {
+        "address" => {
+            imports.add("alloy::primitives", "Address");
+            return Ok("Address".into());
+        }
+        "bytes" => {
+            imports.add("alloy::primitives", "Bytes");
+            return Ok("Bytes".into());
+        }
+        "string" => return Ok("String".into()),
+        "bytes32" => {
+            imports.add("alloy::primitives", "B256");
+            return Ok("B256".into());
+        }
+        _ => {}
+    }
+    if let Some(Ok(bits)) = sol("uint") {
+        if bits == 0 || bits > 256 || bits % 8 != 0 {
+            return Err(format!("`{ty}` is not a Solidity type"));
+        }
+        return Ok(match sol_int_type(bits, false) {
+            (ty, true) => ty,
+            (ty, false) => {
+                let module = if ty == "U256" {
+                    "alloy::primitives"
+                } else {
+                    "alloy::primitives::aliases"
+                };
+                imports.add(module, ty.clone());
+                ty
+            }
+        });
+    }
+    if let Some(Ok(_)) = sol("int") {
+        return Err(format!(
+            "`{ty}`: RLP has no signed integers. Store the two's complement in a `uint` field."
+        ));
+    }
+    if let Some(Ok(size)) = sol("bytes") {
+        if !(1..=32).contains(&size) {
+            return Err(format!("`{ty}` is not a Solidity type"));
+        }
+        imports.add("alloy::primitives", "FixedBytes");
+        return Ok(format!("FixedBytes<{size}>"));
+    }
+    let base = ty.split('<').next().unwrap_or(ty);
+    if PRELUDE_TYPES.contains(&base) {
+        return Ok(ty.to_string());
+    }
+    if let Some((name, module)) = KNOWN_TYPES.iter().find(|(name, _)| *name == base) {
+        imports.add(*module, *name);
+        return Ok(ty.to_string());
+    }
+    if base.starts_with(|c: char| c.is_ascii_uppercase()) && is_ident(base) {
+        // A type of the project's own; it has to bring the traits itself.
+        return Ok(ty.to_string());
+    }
+    Err(format!("`{ty}` is not a Rust or Solidity type"))
+}
+
+/// Normalize one requested transaction type, collecting notes on what was
+/// changed or left out.
+fn tx_type(
+    spec: &TxTypeSpec,
+    imports: &mut Imports,
+    notes: &mut Vec<String>,
+) -> Result<TxType, String> {
+    let variant = upper_camel(spec.name.trim_start_matches("Tx"));
+    if variant.is_empty() || !is_ident(&variant) {
+        return Err(format!("`{}` is not a usable type name", spec.name));
+    }
+    let ident = format!("Tx{variant}");
+    let id = parse_type_id(&spec.type_id).map_err(|e| format!("{ident}: {e}"))?;
+    match id {
+        0..=4 => {
+            return Err(format!(
+                "{ident}: type {id:#04x} is Ethereum's ({}). The envelope keeps Ethereum's types \
+                through its flattened `Ethereum(TxEnvelope)` variant; pick an unused byte.",
+                ["legacy", "EIP-2930", "EIP-1559", "EIP-4844", "EIP-7702"][usize::from(id)]
+            ));
+        }
+        0x80.. => {
+            return Err(format!(
+                "{ident}: type {id:#04x} is outside the EIP-2718 range 0x00-0x7f (bytes from \
+                0xc0 start a legacy RLP list)."
+            ));
+        }
+        0x7e => notes.push(format!(
+            "{ident}: 0x7e is the OP-stack deposit type. Deposits carry no signature, so they \
+            do not fit `Signed<T>`; for an OP-stack chain use op-alloy's `OpTxEnvelope`."
+        )),
+        _ => {}
+    }
+    if spec.fields.is_empty() {
+        return Err(format!("{ident}: give at least one field"));
+    }
+
+    let mut fields: Vec<Field> = Vec::new();
+    for field in &spec.fields {
+        let mut name = snake(&field.name);
+        if let Some((alias, standard)) = FIELD_ALIASES.iter().find(|(alias, _)| *alias == name) {
+            notes.push(format!(
+                "{ident}: `{alias}` is named `{standard}` in alloy."
+            ));
+            name = standard.to_string();
+        }
+        if !is_ident(&name) {
+            return Err(format!(
+                "{ident}: `{}` is not a usable field name",
+                field.name
+            ));
+        }
+        if fields.iter().any(|f| f.name == name) {
+            return Err(format!("{ident}: field `{name}` is given twice"));
+        }
+        let given = field_type(&field.ty, imports).map_err(|e| format!("{ident}.{name}: {e}"))?;
+        let ty = match STANDARD_FIELDS.iter().find(|(n, _)| *n == name) {
+            // A plain address `to` is how blob and 7702 transactions rule out
+            // contract creation.
+            Some(_) if name == "to" && given == "Address" => given,
+            Some((_, standard)) => {
+                let standard = standard.to_string();
+                // `ChainId` is an alias of `u64`.
+                if given != standard && !(standard == "ChainId" && given == "u64") {
+                    notes.push(format!(
+                        "{ident}: `{name}` is `{standard}` in every alloy transaction (given `{}`); \
+                        the `Transaction` getter needs that type.",
+                        field.ty.trim()
+                    ));
+                    field_type(&standard, imports)?;
+                }
+                standard
+            }
+            None => given,
+        };
+        let mut serde = Vec::new();
+        if matches!(
+            ty.as_str(),
+            "u8" | "u16" | "u32" | "u64" | "u128" | "ChainId"
+        ) {
+            serde.push("with = \"alloy::serde::quantity\"".to_string());
+        }
+        if name == "gas_limit" {
+            serde.push("rename = \"gas\"".into());
+        }
+        fields.push(Field { name, ty, serde });
+    }
+
+    let missing: Vec<String> = ["chain_id", "nonce", "gas_limit", "to", "value", "input"]
+        .into_iter()
+        .filter(|name| !fields.iter().any(|f| f.name == *name))
+        .map(|name| format!("`{name}`"))
+        .collect();
+    if !missing.is_empty() {
+        notes.push(format!(
+            "{ident} has no {} field: the `Transaction` getters return `None`, zero or empty \
+            for {}.",
+            missing.join(", "),
+            if missing.len() == 1 { "it" } else { "them" }
+        ));
+    }
+    if !fields.iter().any(|f| f.name == "chain_id") {
+        notes.push(format!(
+            "{ident}: without `chain_id` its signatures are valid on every chain that accepts \
+            the type."
+        ));
+    }
+    if !fields.iter().any(|f| f.name == "nonce") {
+        notes.push(format!(
+            "{ident}: without `nonce` a signed transaction can be replayed."
+        ));
+    }
+    Ok(TxType {
+        variant,
+        ident,
+        id,
+        fields,
+    })
+}
+
+/// The `Transaction` getters of a custom type, backed by its standard fields.
+fn getter_bodies(tx: &TxType) -> Vec<(&'static str, String)> {
+    let field = |name: &str, some: &str, none: &str| {
+        if tx.has(name) {
+            some.replace('$', name)
+        } else {
+            none.to_string()
+        }
+    };
+    let dynamic = tx.has("max_fee_per_gas") && tx.has("max_priority_fee_per_gas");
+    let effective = if dynamic {
+        "alloy::eips::eip1559::calc_effective_gas_price(\n            self.max_fee_per_gas,\n            \
+        self.max_priority_fee_per_gas,\n            base_fee,\n        )"
+            .to_string()
+    } else if tx.has("gas_price") {
+        "self.gas_price".into()
+    } else {
+        field("max_fee_per_gas", "self.$", "0")
+    };
+    let (kind, is_create) = match tx.field("to").map(|f| f.ty.as_str()) {
+        Some("Address") => ("TxKind::Call(self.to)".into(), "false".into()),
+        Some(_) => ("self.to".to_string(), "self.to.is_create()".to_string()),
+        None => ("TxKind::Create".into(), "true".into()),
+    };
+    vec![
+        ("chain_id", field("chain_id", "Some(self.$)", "None")),
+        ("nonce", field("nonce", "self.$", "0")),
+        ("gas_limit", field("gas_limit", "self.$", "0")),
+        ("gas_price", field("gas_price", "Some(self.$)", "None")),
+        (
+            "max_fee_per_gas",
+            if tx.has("max_fee_per_gas") {
+                "self.max_fee_per_gas".into()
+            } else {
+                field("gas_price", "self.$", "0")
+            },
+        ),
+        (
+            "max_priority_fee_per_gas",
+            field("max_priority_fee_per_gas", "Some(self.$)", "None"),
+        ),
+        ("max_fee_per_blob_gas", "None".into()),
+        (
+            "priority_fee_or_price",
+            if tx.has("max_priority_fee_per_gas") {
+                "self.max_priority_fee_per_gas".into()
+            } else {
+                field("gas_price", "self.$", "0")
+            },
+        ),
+        ("effective_gas_price", effective),
+        ("is_dynamic_fee", tx.has("max_fee_per_gas").to_string()),
+        ("kind", kind),
+        ("is_create", is_create),
+        ("value", field("value", "self.$", "U256::ZERO")),
+        ("input", field("input", "&self.$", "&EMPTY_INPUT")),
+        ("access_list", field("access_list", "Some(&self.$)", "None")),
+        ("blob_versioned_hashes", "None".into()),
+        (
+            "authorization_list",
+            field("authorization_list", "Some(&self.$)", "None"),
+        ),
+    ]
+}
+
+/// The struct and consensus trait impls for one custom transaction type.
+fn tx_code(tx: &TxType) -> String {
+    let TxType {
+        ident, id, fields, ..
+    } = tx;
+    let mut out = format!(
+        "/// `{ident}` (EIP-2718 type `{id:#04x}`), signed as `{id:#04x} || rlp([fields])`.\n\
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]\n\
+        #[serde(rename_all = \"camelCase\")]\npub struct {ident} {{\n"
+    );
+    for field in fields {
+        if !field.serde.is_empty() {
+            let _ = writeln!(out, "    #[serde({})]", field.serde.join(", "));
+        }
+        let _ = writeln!(out, "    pub {}: {},", field.name, field.ty);
+    }
+    let _ = write!(
+        out,
+        "}}\n\nimpl {ident} {{\n    pub const TX_TYPE: u8 = {id:#04x};\n}}\n\n\
+        impl Typed2718 for {ident} {{\n    fn ty(&self) -> u8 {{\n        Self::TX_TYPE\n    }}\n}}\n\n\
+        impl RlpEcdsaEncodableTx for {ident} {{\n    fn rlp_encoded_fields_length(&self) -> usize {{\n"
+    );
+    for (i, field) in fields.iter().enumerate() {
+        let lead = if i == 0 { "        " } else { "            + " };
+        let _ = writeln!(out, "{lead}self.{}.length()", field.name);
+    }
+    out.push_str("    }\n\n    fn rlp_encode_fields(&self, out: &mut dyn BufMut) {\n");
+    for field in fields {
+        let _ = writeln!(out, "        self.{}.encode(out);", field.name);
+    }
+    let _ = write!(
+        out,
+        "    }}\n}}\n\nimpl RlpEcdsaDecodableTx for {ident} {{\n    \
+        const DEFAULT_TX_TYPE: u8 = Self::TX_TYPE;\n\n    \
+        fn rlp_decode_fields(buf: &mut &[u8]) -> alloy::rlp::Result<Self> {{\n        Ok(Self {{\n"
+    );
+    for field in fields {
+        let _ = writeln!(out, "            {}: Decodable::decode(buf)?,", field.name);
+    }
+    let _ = write!(
+        out,
+        "        }})\n    }}\n}}\n\n\
+        impl Encodable for {ident} {{\n    fn encode(&self, out: &mut dyn BufMut) {{\n        \
+        self.rlp_encode(out);\n    }}\n\n    fn length(&self) -> usize {{\n        \
+        self.rlp_encoded_length()\n    }}\n}}\n\n\
+        impl Decodable for {ident} {{\n    fn decode(buf: &mut &[u8]) -> alloy::rlp::Result<Self> {{\n        \
+        Self::rlp_decode(buf)\n    }}\n}}\n\nimpl Transaction for {ident} {{\n"
+    );
+    let bodies = getter_bodies(tx);
+    for (i, (name, params, ret)) in GETTERS.iter().enumerate() {
+        let params = match *params {
+            "base_fee: Option<u64>" if !bodies[i].1.contains("base_fee") => {
+                ", _base_fee: Option<u64>".to_string()
+            }
+            "" => String::new(),
+            params => format!(", {params}"),
+        };
+        if i > 0 {
+            out.push('\n');
+        }
+        let _ = writeln!(
+            out,
+            "    fn {name}(&self{params}) -> {ret} {{\n        {}\n    }}",
+            bodies[i].1
+        );
+    }
+    let set_chain_id = if tx.has("chain_id") {
+        "fn set_chain_id(&mut self, chain_id: ChainId) {\n        self.chain_id = chain_id;\n    }"
+    } else {
+        "fn set_chain_id(&mut self, _chain_id: ChainId) {}"
+    };
+    let _ = write!(
+        out,
+        "}}\n\nimpl SignableTransaction<Signature> for {ident} {{\n    {set_chain_id}\n\n    \
+        fn encode_for_signing(&self, out: &mut dyn BufMut) {{\n        \
+        out.put_u8(Self::TX_TYPE);\n        self.encode(out);\n    }}\n\n    \
+        fn payload_len_for_signature(&self) -> usize {{\n        self.length() + 1\n    }}\n}}\n"
+    );
+    out
+}
+
+/// A `match self` over every envelope variant, calling `call` on the
+/// inner transaction (`$` stands for it).
+fn match_variants(txs: &[TxType], indent: &str, call: &str) -> String {
+    let mut out = format!("{indent}match self {{\n");
+    for variant in std::iter::once("Ethereum").chain(txs.iter().map(|t| t.variant.as_str())) {
+        let _ = writeln!(
+            out,
+            "{indent}    Self::{variant}(tx) => {},",
+            call.replace('$', "tx")
+        );
+    }
+    let _ = write!(out, "{indent}}}");
+    out
+}
+
+/// The full module for `network`.
+fn network_code(network: &str, txs: &[TxType], mut imports: Imports) -> String {
+    let envelope = format!("{network}TxEnvelope");
+    let tx_type = format!("{network}TxType");
+    let typed = format!("{network}TypedTransaction");
+    let request = format!("{network}TransactionRequest");
+
+    for item in [
+        "Header",
+        "SignableTransaction",
+        "Signed",
+        "Transaction",
+        "TransactionEnvelope",
+        "TxEnvelope",
+        "TypedTransaction",
+    ] {
+        imports.add("alloy::consensus", item);
+    }
+    imports.add("alloy::consensus::crypto", "RecoveryError");
+    for item in [
+        "Recovered",
+        "RlpEcdsaDecodableTx",
+        "RlpEcdsaEncodableTx",
+        "SignerRecoverable",
+    ] {
+        imports.add("alloy::consensus::transaction", item);
+    }
+    imports.add("alloy::eips", "Typed2718");
+    imports.add("alloy::eips::eip2930", "AccessList");
+    imports.add("alloy::eips::eip7702", "SignedAuthorization");
+    for item in [
+        "AnyReceiptEnvelope",
+        "BuildResult",
+        "Network",
+        "NetworkWallet",
+        "TransactionBuilder",
+        "TransactionBuilderError",
+    ] {
+        imports.add("alloy::network", item);
+    }
+    for item in [
+        "Address",
+        "B256",
+        "Bytes",
+        "ChainId",
+        "Signature",
+        "TxKind",
+        "U256",
+    ] {
+        imports.add("alloy::primitives", item);
+    }
+    for item in [
+        "ChainIdFiller",
+        "GasFiller",
+        "JoinFill",
+        "NonceFiller",
+        "RecommendedFillers",
+    ] {
+        imports.add("alloy::providers::fillers", item);
+    }
+    for item in ["BufMut", "Decodable", "Encodable"] {
+        imports.add("alloy::rlp", item);
+    }
+    imports.add("alloy::rpc::types", "TransactionInputKind");
+    imports.add("alloy::rpc::types", "TransactionRequest");
+    imports.add("serde", "Deserialize");
+    imports.add("serde", "Serialize");
+    imports.add("std::ops", "Deref");
+    imports.add("std::ops", "DerefMut");
+
+    let mut out = imports.render();
+    out.push('\n');
+    if txs.iter().any(|tx| !tx.has("input")) {
+        out.push_str(
+            "/// Input of transaction types without an `input` field.\n\
+            static EMPTY_INPUT: Bytes = Bytes::new();\n\n",
+        );
+    }
+    for tx in txs {
+        out.push_str(&tx_code(tx));
+        out.push('\n');
+    }
+
+    // Signed envelope.
+    let _ = write!(
+        out,
+        "/// Every signed transaction on {network}: Ethereum's types plus the chain's own.\n\
+        #[derive(Debug, Clone, TransactionEnvelope)]\n\
+        #[envelope(alloy_consensus = ::alloy::consensus, tx_type_name = {tx_type})]\n\
+        pub enum {envelope} {{\n    \
+        /// Legacy, EIP-2930, EIP-1559, EIP-4844 and EIP-7702, keeping their type bytes.\n    \
+        #[envelope(flatten)]\n    Ethereum(TxEnvelope),\n"
+    );
+    for tx in txs {
+        let _ = writeln!(
+            out,
+            "    #[envelope(ty = {:#04x})]\n    {}(Signed<{}>),",
+            tx.id, tx.variant, tx.ident
+        );
+    }
+    let _ = write!(
+        out,
+        "}}\n\nimpl std::fmt::Display for {tx_type} {{\n    \
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{\n        \
+        match self {{\n            Self::Ethereum(ty) => ty.fmt(f),\n"
+    );
+    for tx in txs {
+        let _ = writeln!(
+            out,
+            "            Self::{0} => f.write_str(\"{0}\"),",
+            tx.variant
+        );
+    }
+    let _ = write!(
+        out,
+        "        }}\n    }}\n}}\n\nimpl SignerRecoverable for {envelope} {{\n    \
+        fn recover_signer(&self) -> Result<Address, RecoveryError> {{\n{}\n    }}\n\n    \
+        fn recover_signer_unchecked(&self) -> Result<Address, RecoveryError> {{\n{}\n    }}\n}}\n\n",
+        match_variants(txs, "        ", "SignerRecoverable::recover_signer($)"),
+        match_variants(
+            txs,
+            "        ",
+            "SignerRecoverable::recover_signer_unchecked($)"
+        ),
+    );
+
+    // Unsigned enum. The derive's `typed = ..` option cannot be used with a
+    // flattened variant, so it is written out.
+    let _ = write!(
+        out,
+        "/// An unsigned {network} transaction, as signed by `NetworkWallet`.\n\
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]\npub enum {typed} {{\n    \
+        Ethereum(TypedTransaction),\n"
+    );
+    for tx in txs {
+        let _ = writeln!(out, "    {}({}),", tx.variant, tx.ident);
+    }
+    let _ = write!(
+        out,
+        "}}\n\nimpl {typed} {{\n    fn inner(&self) -> &dyn Transaction {{\n{}\n    }}\n}}\n\n\
+        impl Typed2718 for {typed} {{\n    fn ty(&self) -> u8 {{\n        self.inner().ty()\n    }}\n}}\n\n\
+        impl Transaction for {typed} {{\n",
+        match_variants(txs, "        ", "$")
+    );
+    for (i, (name, params, ret)) in GETTERS.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let (params, args) = match *params {
+            "" => (String::new(), ""),
+            params => (format!(", {params}"), "base_fee"),
+        };
+        let _ = writeln!(
+            out,
+            "    fn {name}(&self{params}) -> {ret} {{\n        self.inner().{name}({args})\n    }}"
+        );
+    }
+    let _ = write!(
+        out,
+        "}}\n\nimpl SignableTransaction<Signature> for {typed} {{\n    \
+        fn set_chain_id(&mut self, chain_id: ChainId) {{\n{}\n    }}\n\n    \
+        fn encode_for_signing(&self, out: &mut dyn BufMut) {{\n{}\n    }}\n\n    \
+        fn payload_len_for_signature(&self) -> usize {{\n{}\n    }}\n}}\n\n\
+        impl From<{envelope}> for {typed} {{\n    fn from(envelope: {envelope}) -> Self {{\n        \
+        match envelope {{\n            {envelope}::Ethereum(tx) => Self::Ethereum(tx.into()),\n",
+        match_variants(txs, "        ", "$.set_chain_id(chain_id)"),
+        match_variants(txs, "        ", "$.encode_for_signing(out)"),
+        match_variants(txs, "        ", "$.payload_len_for_signature()"),
+    );
+    for tx in txs {
+        let _ = writeln!(
+            out,
+            "            {envelope}::{0}(tx) => Self::{0}(tx.strip_signature()),",
+            tx.variant
+        );
+    }
+    let _ = write!(
+        out,
+        "        }}\n    }}\n}}\n\n\
+        impl From<Signed<{typed}>> for {envelope} {{\n    \
+        fn from(signed: Signed<{typed}>) -> Self {{\n        \
+        let signature = *signed.signature();\n        match signed.strip_signature() {{\n            \
+        {typed}::Ethereum(tx) => Self::Ethereum(tx.into_signed(signature).into()),\n"
+    );
+    for tx in txs {
+        let _ = writeln!(
+            out,
+            "            {typed}::{0}(tx) => Self::{0}(tx.into_signed(signature)),",
+            tx.variant
+        );
+    }
+    out.push_str("        }\n    }\n}\n\n");
+
+    // Recovered aliases.
+    let _ = writeln!(
+        out,
+        "/// A {network} transaction with its sender recovered.\n\
+        pub type Recovered{network}Tx = Recovered<{envelope}>;"
+    );
+    for tx in txs {
+        let _ = writeln!(
+            out,
+            "/// A `{0}` with its sender recovered.\npub type Recovered{0} = Recovered<Signed<{0}>>;",
+            tx.ident
+        );
+    }
+    out.push('\n');
+
+    // Transaction request.
+    let _ = write!(
+        out,
+        "/// `TransactionRequest` for {network}. It builds Ethereum's transaction types; \
+        custom types are\n/// signed directly and sent with `send_raw_transaction`.\n\
+        #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]\n\
+        #[serde(transparent)]\npub struct {request}(pub TransactionRequest);\n\n\
+        impl Deref for {request} {{\n    type Target = TransactionRequest;\n\n    \
+        fn deref(&self) -> &Self::Target {{\n        &self.0\n    }}\n}}\n\n\
+        impl DerefMut for {request} {{\n    fn deref_mut(&mut self) -> &mut Self::Target {{\n        \
+        &mut self.0\n    }}\n}}\n\n\
+        impl From<TransactionRequest> for {request} {{\n    \
+        fn from(request: TransactionRequest) -> Self {{\n        Self(request)\n    }}\n}}\n\n\
+        impl From<{typed}> for {request} {{\n    fn from(tx: {typed}) -> Self {{\n        \
+        match tx {{\n            {typed}::Ethereum(tx) => Self(tx.into()),\n            \
+        tx => Self(TransactionRequest {{\n                to: Some(tx.kind()),\n                \
+        value: Some(tx.value()),\n                input: tx.input().clone().into(),\n                \
+        nonce: Some(tx.nonce()),\n                chain_id: tx.chain_id(),\n                \
+        gas: Some(tx.gas_limit()),\n                gas_price: tx.gas_price(),\n                \
+        max_fee_per_gas: tx.is_dynamic_fee().then(|| tx.max_fee_per_gas()),\n                \
+        max_priority_fee_per_gas: tx.max_priority_fee_per_gas(),\n                \
+        access_list: tx.access_list().cloned(),\n                \
+        transaction_type: Some(tx.ty()),\n                ..Default::default()\n            }}),\n        \
+        }}\n    }}\n}}\n\n\
+        impl From<{envelope}> for {request} {{\n    fn from(envelope: {envelope}) -> Self {{\n        \
+        {typed}::from(envelope).into()\n    }}\n}}\n\n"
+    );
+    let _ = writeln!(out, "impl TransactionBuilder<{network}> for {request} {{");
+    let delegated: [(&str, &str, &str); 25] = [
+        ("chain_id", "", "Option<ChainId>"),
+        ("set_chain_id", "chain_id: ChainId", ""),
+        ("nonce", "", "Option<u64>"),
+        ("set_nonce", "nonce: u64", ""),
+        ("take_nonce", "", "Option<u64>"),
+        ("input", "", "Option<&Bytes>"),
+        ("set_input<T: Into<Bytes>>", "input: T", ""),
+        (
+            "set_input_kind<T: Into<Bytes>>",
+            "input: T, kind: TransactionInputKind",
+            "",
+        ),
+        ("from", "", "Option<Address>"),
+        ("set_from", "from: Address", ""),
+        ("kind", "", "Option<TxKind>"),
+        ("clear_kind", "", ""),
+        ("set_kind", "kind: TxKind", ""),
+        ("value", "", "Option<U256>"),
+        ("set_value", "value: U256", ""),
+        ("gas_price", "", "Option<u128>"),
+        ("set_gas_price", "gas_price: u128", ""),
+        ("max_fee_per_gas", "", "Option<u128>"),
+        ("set_max_fee_per_gas", "max_fee_per_gas: u128", ""),
+        ("max_priority_fee_per_gas", "", "Option<u128>"),
+        (
+            "set_max_priority_fee_per_gas",
+            "max_priority_fee_per_gas: u128",
+            "",
+        ),
+        ("gas_limit", "", "Option<u64>"),
+        ("set_gas_limit", "gas_limit: u64", ""),
+        ("access_list", "", "Option<&AccessList>"),
+        ("set_access_list", "access_list: AccessList", ""),
+    ];
+    for (name, params, ret) in delegated {
+        let method = name.split('<').next().unwrap_or(name);
+        let args: Vec<&str> = params
+            .split(", ")
+            .filter_map(|p| p.split(':').next())
+            .filter(|a| !a.is_empty())
+            .collect();
+        let (receiver, deref) = if ret.is_empty() || method == "take_nonce" {
+            ("&mut self", "deref_mut")
+        } else {
+            ("&self", "deref")
+        };
+        let params = if params.is_empty() {
+            String::new()
+        } else {
+            format!(", {params}")
+        };
+        let ret = if ret.is_empty() {
+            String::new()
+        } else {
+            format!(" -> {ret}")
+        };
+        let _ = writeln!(
+            out,
+            "    fn {name}({receiver}{params}){ret} {{\n        self.{deref}().{method}({})\n    }}\n",
+            args.join(", ")
+        );
+    }
+    let _ = write!(
+        out,
+        "    fn complete_type(&self, ty: {tx_type}) -> Result<(), Vec<&'static str>> {{\n        \
+        match ty {{\n            {tx_type}::Ethereum(ty) => self.deref().complete_type(ty),\n            \
+        _ => Err(vec![\"unsupported_transaction_type\"]),\n        }}\n    }}\n\n    \
+        fn can_submit(&self) -> bool {{\n        self.deref().can_submit()\n    }}\n\n    \
+        fn can_build(&self) -> bool {{\n        self.deref().can_build()\n    }}\n\n    \
+        fn output_tx_type(&self) -> {tx_type} {{\n        \
+        {tx_type}::Ethereum(self.deref().output_tx_type())\n    }}\n\n    \
+        fn output_tx_type_checked(&self) -> Option<{tx_type}> {{\n        \
+        self.deref().output_tx_type_checked().map({tx_type}::Ethereum)\n    }}\n\n    \
+        fn prep_for_submission(&mut self) {{\n        self.deref_mut().prep_for_submission()\n    }}\n\n    \
+        fn build_unsigned(self) -> BuildResult<{typed}, {network}> {{\n        \
+        if let Err((tx_type, missing)) = self.0.missing_keys() {{\n            \
+        return Err(TransactionBuilderError::InvalidTransactionRequest(\n                \
+        {tx_type}::Ethereum(tx_type),\n                missing,\n            )\n            \
+        .into_unbuilt(self));\n        }}\n        Ok({typed}::Ethereum(\n            \
+        self.0.build_typed_tx().expect(\"checked by missing_keys\"),\n        ))\n    }}\n\n    \
+        async fn build<W: NetworkWallet<{network}>>(\n        self,\n        wallet: &W,\n    \
+        ) -> Result<{envelope}, TransactionBuilderError<{network}>> {{\n        \
+        Ok(wallet.sign_request(self).await?)\n    }}\n}}\n\n"
+    );
+
+    // Network.
+    let _ = write!(
+        out,
+        "/// The {network} network: use it as `N` in `Provider<N>` and `ProviderBuilder`.\n\
+        #[derive(Debug, Clone, Copy)]\npub struct {network};\n\n\
+        impl Network for {network} {{\n    type TxType = {tx_type};\n    \
+        type TxEnvelope = {envelope};\n    type UnsignedTx = {typed};\n    \
+        // Accepts receipts of any type byte; replace it to decode the chain's receipt fields.\n    \
+        type ReceiptEnvelope = AnyReceiptEnvelope;\n    type Header = Header;\n    \
+        type TransactionRequest = {request};\n    \
+        type TransactionResponse = alloy::rpc::types::Transaction<{envelope}>;\n    \
+        type ReceiptResponse =\n        \
+        alloy::rpc::types::TransactionReceipt<AnyReceiptEnvelope<alloy::rpc::types::Log>>;\n    \
+        type HeaderResponse = alloy::rpc::types::Header;\n    \
+        type BlockResponse =\n        \
+        alloy::rpc::types::Block<Self::TransactionResponse, Self::HeaderResponse>;\n}}\n\n\
+        /// Ethereum's fillers without the blob gas filler, which needs `TransactionBuilder4844`.\n\
+        impl RecommendedFillers for {network} {{\n    \
+        type RecommendedFillers = JoinFill<GasFiller, JoinFill<NonceFiller, ChainIdFiller>>;\n\n    \
+        fn recommended_fillers() -> Self::RecommendedFillers {{\n        Default::default()\n    }}\n}}\n"
+    );
+    out
+}
+
+/// Code sending and recovering transactions on the scaffolded network.
+fn usage_code(network: &str, module: &str, tx: &TxType) -> String {
+    let mut literal = format!("let tx = {} {{\n", tx.ident);
+    if tx.has("chain_id") {
+        literal.push_str("    chain_id: provider.get_chain_id().await?,\n");
+    }
+    if tx.has("nonce") {
+        literal.push_str("    nonce: provider.get_transaction_count(signer.address()).await?,\n");
+    }
+    literal.push_str("    // ...\n    ..Default::default()\n};\n");
+    format!(
+        "use alloy::{{\n    consensus::{{SignableTransaction, transaction::SignerRecoverable}},\n    \
+        eips::Encodable2718,\n    network::{{EthereumWallet, TransactionBuilder}},\n    \
+        primitives::U256,\n    providers::{{Provider, ProviderBuilder}},\n    \
+        signers::{{SignerSync, local::PrivateKeySigner}},\n}};\n\
+        use crate::{module}::{{{network}, {network}TransactionRequest, {network}TxEnvelope, {0}, Recovered{network}Tx}};\n\n\
+        let provider = ProviderBuilder::new_with_network::<{network}>()\n    \
+        .wallet(EthereumWallet::from(signer.clone()))\n    .connect_http(url);\n\n\
+        // Ethereum's transaction types go through the builder and the fillers.\n\
+        let tx = {network}TransactionRequest::default().with_to(to).with_value(U256::from(1));\n\
+        let receipt = provider.send_transaction(tx).await?.get_receipt().await?;\n\n\
+        // Custom types are signed directly and sent raw.\n\
+        {literal}\
+        let signature = signer.sign_hash_sync(&tx.signature_hash())?;\n\
+        let envelope = {network}TxEnvelope::{1}(tx.into_signed(signature));\n\
+        let pending = provider.send_raw_transaction(&envelope.encoded_2718()).await?;\n\n\
+        // Sender recovery for transactions read from blocks or the mempool.\n\
+        let recovered: Recovered{network}Tx = envelope.try_into_recovered()?;\n\
+        let sender = recovered.signer();\n",
+        tx.ident, tx.variant
+    )
+}
+
+#[tool_router(router = scaffold_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Generate the boilerplate of a custom alloy `Network` for a chain with
+    /// its own transaction types.
+    #[tool(
+        description = "Generate a compilable skeleton for a custom alloy Network from a network name and the chain's transaction types (type byte plus fields): the transaction structs with RLP/EIP-2718 encoding and signing, a TransactionEnvelope-derived envelope that keeps Ethereum's types, the unsigned transaction enum, a TransactionRequest wrapper implementing TransactionBuilder, the Network impl with recommended fillers, and Recovered type aliases. Use it instead of hand-writing Encodable2718/Decodable2718 and Network impls."
+    )]
+    fn scaffold_network(
+        &self,
+        Parameters(ScaffoldNetworkRequest { name, tx_types }): Parameters<ScaffoldNetworkRequest>,
+    ) -> String {
+        let network = upper_camel(&name);
+        if network.is_empty() || !is_ident(&network) {
+            return format!("`{name}` is not a usable network name: use e.g. \"MyChain\".");
+        }
+        if ["Ethereum", "AnyNetwork", "Optimism"].contains(&network.as_str()) {
+            return format!(
+                "`{network}` is one of alloy's networks; see `network_types` for its types or \
+                pick another name."
+            );
+        }
+        if tx_types.is_empty() {
+            return "Give at least one transaction type. A chain with only Ethereum's types does not \
+                need a custom network: use `Ethereum`, or `AnyNetwork` if its RPC responses carry \
+                extra fields.\n\n**Key resources:**\n\
+                - `alloy://network/any` — The catch-all network\n"
+                .into();
+        }
+
+        let mut imports = Imports::default();
+        let mut notes = Vec::new();
+        let mut txs: Vec<TxType> = Vec::new();
+        for spec in &tx_types {
+            let tx = match tx_type(spec, &mut imports, &mut notes) {
+                Ok(tx) => tx,
+                Err(e) => return format!("Could not scaffold `{network}`: {e}"),
+            };
+            if let Some(other) = txs
+                .iter()
+                .find(|t| t.id == tx.id || t.variant == tx.variant)
+            {
+                return format!(
+                    "Could not scaffold `{network}`: `{}` and `{}` share a {}.",
+                    other.ident,
+                    tx.ident,
+                    if other.id == tx.id {
+                        "type byte"
+                    } else {
+                        "name"
+                    }
+                );
+            }
+            txs.push(tx);
+        }
+        let module = snake(&network);
+
+        let mut result = format!(
+            "# `{network}` network\n\nTransaction types: Ethereum's (`0x00`-`0x04`), {}.\n\n\
+            ## Cargo.toml\n\n```toml\n[dependencies]\n\
+            alloy = {{ version = \"1\", features = [\"full\"] }}\n\
+            serde = {{ version = \"1\", features = [\"derive\"] }}\n```\n\n\
+            ## `src/{module}.rs`\n\n```rust\n{}```\n\n## Usage\n\n```rust\n{}```\n\n",
+            txs.iter()
+                .map(|tx| format!("`{}` (`{:#04x}`)", tx.ident, tx.id))
+                .collect::<Vec<_>>()
+                .join(", "),
+            network_code(&network, &txs, imports),
+            usage_code(&network, &module, &txs[0])
+        );
+
+        notes.extend([
+            format!(
+                "The envelope derive generates `{network}TxType` and the envelope's \
+                `Transaction`, `Typed2718`, `Encodable2718`, `Decodable2718` and serde impls. \
+                It must sit in a module, not the crate root. `alloy_consensus = ::alloy::consensus` \
+                is for the `alloy` umbrella crate; drop it when depending on `alloy-consensus`."
+            ),
+            "`ReceiptEnvelope` is `AnyReceiptEnvelope`, which accepts every type byte; the \
+            header and RPC types are Ethereum's. Swap in the chain's own types where its RPC \
+            returns extra fields."
+                .into(),
+            format!(
+                "`EthereumWallet` signs for `{network}`: `{network}TypedTransaction` implements \
+                `SignableTransaction` and `{network}TxEnvelope` converts from `Signed<_>`."
+            ),
+        ]);
+        result.push_str("## Notes\n\n");
+        for note in &notes {
+            let _ = writeln!(result, "- {note}");
+        }
+
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://consensus/recovered` — Custom transaction envelopes and `Recovered` aliases\n\
+            - `alloy://encoding/rlp-eip2718` — EIP-2718 encoding and type ids\n\
+            - `alloy://network` — The associated types each network sets\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scaffold(request: Value) -> String {
+        let request = serde_json::from_value(request).unwrap();
+        AlloyMcpServer::new().scaffold_network(Parameters(request))
+    }
+
+    #[test]
+    fn maps_fields_and_the_type_byte() {
+        let result = scaffold(serde_json::json!({
+            "name": "my-chain",
+            "tx_types": [{
+                "name": "Sponsored",
+                "type_id": "0x50",
+                "fields": [
+                    { "name": "chain_id", "type": "u64" },
+                    { "name": "sponsor", "type": "address" },
+                    { "name": "amount", "type": "uint256" }
+                ]
+            }]
+        }));
+        assert!(result.starts_with("# `MyChain` network"));
+        assert!(result.contains("## `src/my_chain.rs`"));
+        for line in [
+            "pub chain_id: ChainId,",
+            "pub sponsor: Address,",
+            "pub amount: U256,",
+            "#[envelope(ty = 0x50)]",
+            "impl Network for MyChain {",
+        ] {
+            assert!(result.contains(line), "missing `{line}`");
+        }
+    }
+
+    #[test]
+    fn rejects_ethereum_type_bytes() {
+        let result = scaffold(serde_json::json!({
+            "name": "MyChain",
+            "tx_types": [{ "name": "Sponsored", "type_id": 2, "fields": [] }]
+        }));
+        assert!(
+            result.contains("type 0x02 is Ethereum's (EIP-1559)"),
+            "{result}"
+        );
+    }
+}
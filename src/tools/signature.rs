@@ -0,0 +1,427 @@
+use std::fmt::Write;
+
+use alloy_dyn_abi::TypedData;
+use alloy_primitives::{Address, B256, Signature, eip191_hash_message, hex, keccak256};
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+use serde_json::Value;
+
+use super::codegen::{Imports, address_lit, b256_lit};
+use crate::server::AlloyMcpServer;
+
+/// A `v` value normalized to a y-parity bit, with an explanation of the
+/// encoding it was in.
+struct NormalizedV {
+    y_parity: bool,
+    encoding: String,
+    chain_id: Option<u64>,
+}
+
+/// Normalize every `v` encoding alloy accepts: raw parity, legacy
+/// `27`/`28`, and EIP-155 `chain_id * 2 + 35 + parity`.
+fn normalize_v(v: u64) -> Result<NormalizedV, String> {
+    match v {
+        0 | 1 => Ok(NormalizedV {
+            y_parity: v == 1,
+            encoding: format!(
+                "`v = {v}` is a raw y-parity bit (typed transactions, `Signature::as_rsy`)."
+            ),
+            chain_id: None,
+        }),
+        27 | 28 => Ok(NormalizedV {
+            y_parity: v == 28,
+            encoding: format!(
+                "`v = {v}` is the legacy encoding (`eth_sign`, `personal_sign`, pre-EIP-155 \
+                transactions): y_parity = v - 27 = {}.",
+                v - 27
+            ),
+            chain_id: None,
+        }),
+        35.. => {
+            let chain_id = (v - 35) / 2;
+            Ok(NormalizedV {
+                y_parity: (v - 35) % 2 == 1,
+                encoding: format!(
+                    "`v = {v}` is EIP-155 encoded (legacy transactions): y_parity = (v - 35) % 2 = {}, \
+                    chain_id = (v - 35) / 2 = {chain_id}.",
+                    (v - 35) % 2
+                ),
+                chain_id: Some(chain_id),
+            })
+        }
+        _ => Err(format!(
+            "`v = {v}` is not a valid encoding. Expected 0/1 (y-parity), 27/28 (legacy) or \
+            >= 35 (EIP-155)."
+        )),
+    }
+}
+
+/// What the signature is checked against.
+enum Payload {
+    Message(Vec<u8>),
+    Hash(B256),
+    TypedData(Box<TypedData>),
+}
+
+impl Payload {
+    /// The prehash the signer should have signed, and how it was derived.
+    fn prehash(&self) -> Result<(B256, &'static str), String> {
+        match self {
+            Self::Message(msg) => Ok((
+                eip191_hash_message(msg),
+                "EIP-191: keccak256(\"\\x19Ethereum Signed Message:\\n\" ‖ len(message) ‖ message)",
+            )),
+            Self::Hash(hash) => Ok((*hash, "prehash given directly")),
+            Self::TypedData(data) => data
+                .eip712_signing_hash()
+                .map(|h| {
+                    (
+                        h,
+                        "EIP-712: keccak256(0x1901 ‖ domainSeparator ‖ hashStruct(message))",
+                    )
+                })
+                .map_err(|e| format!("Could not compute the EIP-712 signing hash: {e}")),
+        }
+    }
+
+    /// Hashes produced by common signing mistakes, used to explain a
+    /// mismatch.
+    fn mistakes(&self) -> Vec<(B256, &'static str)> {
+        match self {
+            Self::Message(msg) => {
+                let mut out = vec![(
+                    keccak256(msg),
+                    "the message was hashed without the EIP-191 prefix (`sign_hash(&keccak256(msg))` instead of `sign_message(msg)`)",
+                )];
+                if let Ok(hash) = B256::try_from(msg.as_slice()) {
+                    out.push((
+                        hash,
+                        "the 32-byte message was signed as a raw hash (`sign_hash`) instead of as a message",
+                    ));
+                }
+                out
+            }
+            Self::Hash(hash) => vec![(
+                eip191_hash_message(hash),
+                "the hash was signed as a message (`sign_message(hash)` adds the EIP-191 prefix; use `sign_hash(&hash)`)",
+            )],
+            Self::TypedData(data) => {
+                let mut out = Vec::new();
+                if let Ok(hash) = data.hash_struct() {
+                    out.push((
+                        hash,
+                        "the struct hash was signed without the domain (`keccak256(0x1901 ‖ ...)` is missing)",
+                    ));
+                }
+                if let Ok(hash) = data.eip712_signing_hash() {
+                    out.push((
+                        eip191_hash_message(hash),
+                        "the EIP-712 signing hash was signed with `sign_message`, which adds the EIP-191 prefix",
+                    ));
+                }
+                out
+            }
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct RecoverSignerRequest {
+    #[schemars(
+        description = "Signature hex: 65 bytes r ‖ s ‖ v (v may be 0/1, 27/28 or EIP-155), or 64 bytes (ERC-2098 compact, or r ‖ s when `v` is given)"
+    )]
+    signature: String,
+    #[schemars(
+        description = "Optional v value overriding the signature's last byte, e.g. an EIP-155 `v` from an RPC transaction"
+    )]
+    v: Option<u64>,
+    #[schemars(description = "EIP-191 personal message that was signed (UTF-8 text)")]
+    message: Option<String>,
+    #[schemars(description = "Treat `message` as 0x-prefixed hex bytes instead of UTF-8 text")]
+    message_is_hex: Option<bool>,
+    #[schemars(
+        description = "32-byte prehash that was signed: an EIP-712 signing hash or a transaction's `signature_hash()`"
+    )]
+    hash: Option<String>,
+    #[schemars(
+        description = "EIP-712 typed data JSON (types, primaryType, domain, message); its signing hash is computed"
+    )]
+    typed_data: Option<Value>,
+    #[schemars(description = "Address the signature is expected to come from")]
+    expected_address: Option<String>,
+}
+
+impl RecoverSignerRequest {
+    fn payload(&self) -> Result<Payload, String> {
+        match (&self.message, &self.hash, &self.typed_data) {
+            (Some(msg), None, None) if self.message_is_hex.unwrap_or(false) => hex::decode(msg)
+                .map(Payload::Message)
+                .map_err(|e| format!("`message` is not valid hex: {e}")),
+            (Some(msg), None, None) => Ok(Payload::Message(msg.as_bytes().to_vec())),
+            (None, Some(hash), None) => hash
+                .trim()
+                .parse()
+                .map(Payload::Hash)
+                .map_err(|e| format!("`hash` is not a 32-byte hex value: {e}")),
+            (None, None, Some(data)) => serde_json::from_value(data.clone())
+                .map(|d| Payload::TypedData(Box::new(d)))
+                .map_err(|e| format!("Invalid EIP-712 typed data: {e}")),
+            _ => Err("Provide exactly one of `message`, `hash` or `typed_data`.".into()),
+        }
+    }
+
+    /// Parse the signature, returning it with an explanation of its `v`.
+    fn signature(&self) -> Result<(Signature, NormalizedV), String> {
+        let bytes = hex::decode(self.signature.trim())
+            .map_err(|e| format!("`signature` is not valid hex: {e}"))?;
+        match (bytes.len(), self.v) {
+            (64 | 65, Some(v)) => {
+                let v = normalize_v(v)?;
+                Ok((Signature::from_bytes_and_parity(&bytes, v.y_parity), v))
+            }
+            (65, None) => {
+                let v = normalize_v(u64::from(bytes[64]))?;
+                Ok((Signature::from_bytes_and_parity(&bytes, v.y_parity), v))
+            }
+            (64, None) => {
+                let sig = Signature::from_erc2098(&bytes);
+                let encoding = format!(
+                    "64-byte ERC-2098 compact signature: y_parity ({}) is the top bit of `s`.",
+                    u8::from(sig.v())
+                );
+                Ok((
+                    sig,
+                    NormalizedV {
+                        y_parity: sig.v(),
+                        encoding,
+                        chain_id: None,
+                    },
+                ))
+            }
+            (len, _) => Err(format!(
+                "`signature` must be 65 bytes (r ‖ s ‖ v) or 64 bytes, got {len}"
+            )),
+        }
+    }
+}
+
+/// Rust code reproducing the recovery.
+fn recovery_code(
+    request: &RecoverSignerRequest,
+    payload: &Payload,
+    signature: &Signature,
+    prehash: &B256,
+    recovered: &Address,
+) -> String {
+    let mut imports = Imports::default();
+    imports.add("alloy::primitives", "Signature");
+    imports.add("alloy::primitives", "address");
+
+    // `as_bytes` re-encodes `v` as 27/28, which `FromStr` accepts for every
+    // input encoding (compact, EIP-155, raw parity).
+    let mut body = format!(
+        "let signature: Signature = \"{}\".parse()?;\n",
+        hex::encode_prefixed(signature.as_bytes())
+    );
+
+    match payload {
+        Payload::Message(msg) => match std::str::from_utf8(msg) {
+            Ok(text) if !request.message_is_hex.unwrap_or(false) => {
+                let _ = writeln!(
+                    body,
+                    "let signer = signature.recover_address_from_msg({text:?})?;"
+                );
+            }
+            _ => {
+                imports.add("alloy::primitives", "hex");
+                let _ = writeln!(
+                    body,
+                    "let message = hex::decode(\"{}\")?;\nlet signer = signature.recover_address_from_msg(&message)?;",
+                    hex::encode_prefixed(msg)
+                );
+            }
+        },
+        Payload::Hash(_) | Payload::TypedData(_) => {
+            imports.add("alloy::primitives", "b256");
+            let _ = writeln!(
+                body,
+                "let signer = signature.recover_address_from_prehash(&{})?;",
+                b256_lit(prehash)
+            );
+        }
+    }
+    let _ = writeln!(body, "assert_eq!(signer, {});", address_lit(recovered));
+    format!("{}\n{body}", imports.render())
+}
+
+#[tool_router(router = signature_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Recover the signer of a message, EIP-712 payload or hash and check it
+    /// against an expected address.
+    #[tool(
+        description = "Recover the signer address from a 65-byte signature over an EIP-191 message, EIP-712 typed data or a prehash (e.g. tx signature_hash), check it against an expected address, and explain v/parity normalization (0/1, 27/28, EIP-155)."
+    )]
+    fn recover_signer(&self, Parameters(request): Parameters<RecoverSignerRequest>) -> String {
+        let payload = match request.payload() {
+            Ok(p) => p,
+            Err(e) => return e,
+        };
+        let (signature, v) = match request.signature() {
+            Ok(s) => s,
+            Err(e) => return e,
+        };
+        let expected = match request
+            .expected_address
+            .as_deref()
+            .map(str::parse::<Address>)
+        {
+            Some(Ok(a)) => Some(a),
+            Some(Err(e)) => return format!("`expected_address` is not a valid address: {e}"),
+            None => None,
+        };
+        let (prehash, derivation) = match payload.prehash() {
+            Ok(p) => p,
+            Err(e) => return e,
+        };
+
+        let mut result = format!(
+            "# Signature recovery\n\n\
+            | Component | Value |\n|-----------|-------|\n\
+            | r | `{:#066x}` |\n| s | `{:#066x}` |\n| y_parity | `{}` |\n",
+            signature.r(),
+            signature.s(),
+            signature.v()
+        );
+        if let Some(chain_id) = v.chain_id {
+            let _ = writeln!(result, "| chain_id (from v) | `{chain_id}` |");
+        }
+        let _ = write!(
+            result,
+            "| Signed hash | `{prehash}` |\n\n\
+            **v normalization:** {}\n\n**Hash derivation:** {derivation}\n\n",
+            v.encoding
+        );
+
+        let recovered = match signature.recover_address_from_prehash(&prehash) {
+            Ok(a) => a,
+            Err(e) => {
+                let _ = writeln!(
+                    result,
+                    "**Recovery failed:** {e}. The r/s values are not a valid secp256k1 signature \
+                    for this hash."
+                );
+                return result;
+            }
+        };
+        let _ = writeln!(
+            result,
+            "**Recovered signer:** `{}`",
+            recovered.to_checksum(None)
+        );
+
+        let mut notes = Vec::new();
+        if let Some(expected) = expected {
+            if expected == recovered {
+                result.push_str("\n✅ Matches the expected address.\n");
+            } else {
+                let _ = writeln!(
+                    result,
+                    "\n❌ Does not match the expected address `{}`.",
+                    expected.to_checksum(None)
+                );
+                let flipped = signature.with_parity(!signature.v());
+                let mut diagnosed = false;
+                for (hash, mistake) in payload.mistakes() {
+                    for (sig, parity_note) in
+                        [(signature, ""), (flipped, " (with flipped y_parity)")]
+                    {
+                        if sig.recover_address_from_prehash(&hash).ok() == Some(expected) {
+                            let _ = writeln!(result, "\n**Likely cause:** {mistake}{parity_note}.");
+                            diagnosed = true;
+                        }
+                    }
+                }
+                if !diagnosed
+                    && flipped.recover_address_from_prehash(&prehash).ok() == Some(expected)
+                {
+                    result.push_str(
+                        "\n**Likely cause:** the y_parity is inverted, so the `v` value was \
+                        normalized incorrectly (e.g. `v - 27` applied to a 0/1 value, or an \
+                        EIP-155 `v` read as 27/28).\n",
+                    );
+                    diagnosed = true;
+                }
+                if !diagnosed {
+                    result.push_str(
+                        "\nNo common mistake (missing/extra EIP-191 prefix, flipped parity) \
+                        explains the mismatch: the payload differs from what was signed, or a \
+                        different key signed it.\n",
+                    );
+                }
+            }
+        }
+
+        if signature.normalize_s().is_some() {
+            notes.push(
+                "`s` is in the upper half of the curve order (malleable). ecrecover accepts it, \
+                but EIP-2 rejects it in transactions and OpenZeppelin's `ECDSA.recover` reverts; \
+                `signature.normalized_s()` gives the canonical form (it flips y_parity)."
+                    .to_string(),
+            );
+        }
+        if matches!(payload, Payload::Hash(_)) {
+            notes.push(
+                "For transactions the signed hash is `tx.signature_hash()`, not the transaction \
+                hash. `Recovered::try_from_signed(signed)` / `envelope.try_into_recovered()` \
+                performs this recovery for you (see alloy://consensus/recovered)."
+                    .to_string(),
+            );
+        }
+        notes.push(
+            "Recovery needs the `k256` (or `secp256k1`) feature of `alloy-primitives`.".to_string(),
+        );
+
+        let _ = write!(
+            result,
+            "\n## Rust\n\n```rust\n{}```\n\n## Notes\n\n",
+            recovery_code(&request, &payload, &signature, &prehash, &recovered)
+        );
+        for note in &notes {
+            let _ = writeln!(result, "- {note}");
+        }
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://primitives/core-types` — Signature type\n\
+            - `alloy://consensus/recovered` — Recovering transaction senders\n\
+            - `alloy://signers/signing-guide` — Signing messages, hashes and typed data\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `personal_sign("hello")` with Anvil's first dev key.
+    #[test]
+    fn recovers_a_personal_sign_signature() {
+        let result = AlloyMcpServer::new().recover_signer(Parameters(RecoverSignerRequest {
+            signature: "0xf16ea9a3478698f695fd1401bfe27e9e4a7e8e3da94aa72b021125e31fa899cc\
+                573c48ea3fe1d4ab61a9db10c19032026e3ed2dbccba5a178235ac27f94504311c"
+                .into(),
+            v: None,
+            message: Some("hello".into()),
+            message_is_hex: None,
+            hash: None,
+            typed_data: None,
+            expected_address: Some("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".into()),
+        }));
+        assert!(result.contains(
+            "| Signed hash | `0x50b2c43fd39106bafbba0da34fc430e1f91e3c96ea2acee2bc34119f92b37750` |"
+        ));
+        assert!(
+            result.contains("**Recovered signer:** `0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266`")
+        );
+        assert!(result.contains("Matches the expected address."));
+    }
+}
@@ -0,0 +1,939 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use alloy_primitives::keccak256;
+use proc_macro2::Span;
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+use syn_solidity::{
+    ContractKind, File, FunctionKind, Item, ItemError, ItemEvent, ItemFunction, ItemStruct,
+    Mutability, ParameterList, SolPath, Spanned, Type, TypeArray, VariableDefinition,
+};
+
+use super::codegen::{Imports, sol_rust_type};
+use crate::server::AlloyMcpServer;
+
+/// Rust and alloy type names written where `sol!` expects Solidity, with
+/// the Solidity type to use instead.
+pub(super) const RUST_NAMES: [(&str, &str); 18] = [
+    ("U256", "uint256"),
+    ("I256", "int256"),
+    ("Address", "address"),
+    ("B256", "bytes32"),
+    ("Bytes", "bytes"),
+    ("String", "string"),
+    ("FixedBytes", "bytesN"),
+    ("Vec", "T[]"),
+    ("u8", "uint8"),
+    ("u16", "uint16"),
+    ("u32", "uint32"),
+    ("u64", "uint64"),
+    ("u128", "uint128"),
+    ("usize", "uint256"),
+    ("i8", "int8"),
+    ("i32", "int32"),
+    ("i64", "int64"),
+    ("i128", "int128"),
+];
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ValidateSolRequest {
+    #[schemars(description = "A `sol!` invocation, or just the Solidity inside it")]
+    code: String,
+}
+
+/// The Solidity inside a `sol! { ... }` invocation, with everything around
+/// it blanked out so spans keep the caller's lines and columns.
+fn sol_body(code: &str) -> String {
+    let blank = |s: &str| -> String {
+        s.chars()
+            .map(|c| if c == '\n' { '\n' } else { ' ' })
+            .collect()
+    };
+    let Some(pos) = code.find("sol!") else {
+        return code.to_string();
+    };
+    let rest = &code[pos + 4..];
+    let Some(open) = rest.find(|c: char| !c.is_whitespace()).map(|i| pos + 4 + i) else {
+        return blank(code);
+    };
+    let close = match code[open..].chars().next() {
+        Some('{') => '}',
+        Some('(') => ')',
+        Some('[') => ']',
+        _ => return code.to_string(),
+    };
+    let end = code
+        .rfind(close)
+        .filter(|&end| end > open)
+        .unwrap_or(code.len());
+    format!("{}{}", blank(&code[..=open]), &code[open + 1..end])
+}
+
+/// `line N, column M` and the source line with the span underlined.
+fn locate(code: &str, span: Span) -> (String, String) {
+    let (start, end) = (span.start(), span.end());
+    let location = format!("line {}, column {}", start.line, start.column + 1);
+    let Some(source) = code.lines().nth(start.line.saturating_sub(1)) else {
+        return (location, String::new());
+    };
+    let width = match end.line == start.line && end.column > start.column {
+        true => end.column - start.column,
+        false => 1,
+    };
+    let gutter = " ".repeat(start.line.to_string().len());
+    let snippet = format!(
+        "  ```text\n  {} | {source}\n  {gutter} | {}{}\n  ```\n",
+        start.line,
+        " ".repeat(start.column),
+        "^".repeat(width)
+    );
+    (location, snippet)
+}
+
+/// Whether `#[sol(rpc)]` and `#[sol(bytecode = "...")]` are set by `attrs`;
+/// `rpc` is `None` when not mentioned, so contracts inherit the file's.
+fn sol_flags(attrs: &[syn::Attribute]) -> (Option<bool>, bool) {
+    let (mut rpc, mut bytecode) = (None, false);
+    for attr in attrs.iter().filter(|a| a.path().is_ident("sol")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            let value = match meta.input.peek(syn::Token![=]) {
+                true => Some(meta.value()?.parse::<syn::Expr>()?),
+                false => None,
+            };
+            if meta.path.is_ident("rpc") {
+                rpc = Some(!matches!(
+                    value,
+                    Some(syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Bool(ref b), .. })) if !b.value
+                ));
+            } else if meta.path.is_ident("bytecode") {
+                bytecode = true;
+            }
+            Ok(())
+        });
+    }
+    (rpc, bytecode)
+}
+
+/// A type the block declares, as far as the Rust side is concerned.
+enum Decl<'a> {
+    Struct(&'a ItemStruct),
+    Enum,
+    Udt(&'a Type),
+    Contract,
+}
+
+/// The types a `sol!` block declares, keyed by `(contract, name)`; `None`
+/// for file-level items.
+#[derive(Default)]
+struct Decls<'a>(BTreeMap<(Option<String>, String), Decl<'a>>);
+
+impl<'a> Decls<'a> {
+    fn new(file: &'a File) -> Self {
+        let mut decls = Self::default();
+        decls.collect(None, &file.items);
+        decls
+    }
+
+    fn collect(&mut self, ns: Option<&str>, items: &'a [Item]) {
+        for item in items {
+            let (name, decl) = match item {
+                Item::Contract(c) => {
+                    self.collect(Some(&c.name.as_string()), &c.body);
+                    (&c.name, Decl::Contract)
+                }
+                Item::Struct(s) => (&s.name, Decl::Struct(s)),
+                Item::Enum(e) => (&e.name, Decl::Enum),
+                Item::Udt(u) => (&u.name, Decl::Udt(&u.ty)),
+                _ => continue,
+            };
+            self.0
+                .insert((ns.map(str::to_string), name.as_string()), decl);
+        }
+    }
+
+    /// The declaration `path` refers to from inside `ns`: qualified names
+    /// first, then the enclosing contract, the file, and any contract.
+    fn resolve(&self, path: &SolPath, ns: Option<&str>) -> Option<(Option<&str>, &Decl<'a>)> {
+        let segments: Vec<String> = path.iter().map(|s| s.as_string()).collect();
+        let found = match segments.as_slice() {
+            [contract, name] => self
+                .0
+                .get_key_value(&(Some(contract.clone()), name.clone())),
+            [name] => self
+                .0
+                .get_key_value(&(ns.map(str::to_string), name.clone()))
+                .or_else(|| self.0.get_key_value(&(None, name.clone())))
+                .or_else(|| self.0.iter().find(|((_, n), _)| n == name)),
+            _ => None,
+        };
+        found.map(|((ns, _), decl)| (ns.as_deref(), decl))
+    }
+
+    /// Rust type `sol!` uses for `ty`, following the Solidity-to-Rust type
+    /// mapping; struct and enum paths are qualified by their contract.
+    fn rust_type(&self, ty: &Type, ns: Option<&str>, imports: &mut Imports) -> String {
+        match ty {
+            Type::Bool(_) => "bool".into(),
+            Type::String(_) => "String".into(),
+            Type::Address(..) | Type::Bytes(_) | Type::Function(_) => {
+                let sol = match ty {
+                    Type::Address(..) => "address",
+                    Type::Bytes(_) => "bytes",
+                    _ => "function",
+                };
+                sol_rust_type(sol, imports).unwrap_or_default()
+            }
+            Type::FixedBytes(_, size) => {
+                sol_rust_type(&format!("bytes{size}"), imports).unwrap_or_default()
+            }
+            Type::Int(_, bits) | Type::Uint(_, bits) => {
+                let prefix = if matches!(ty, Type::Int(..)) {
+                    "int"
+                } else {
+                    "uint"
+                };
+                let bits = bits.map_or(256, |b| b.get());
+                sol_rust_type(&format!("{prefix}{bits}"), imports)
+                    .unwrap_or_else(|| format!("{prefix}{bits}"))
+            }
+            Type::Array(array) => {
+                let inner = self.rust_type(&array.ty, ns, imports);
+                match &array.size {
+                    None => format!("Vec<{inner}>"),
+                    Some(_) => format!("[{inner}; {}]", array_size(array)),
+                }
+            }
+            Type::Tuple(tuple) => {
+                let items: Vec<String> = tuple
+                    .types
+                    .iter()
+                    .map(|t| self.rust_type(t, ns, imports))
+                    .collect();
+                match items.as_slice() {
+                    [single] => format!("({single},)"),
+                    _ => format!("({})", items.join(", ")),
+                }
+            }
+            Type::Mapping(_) => "mapping".into(),
+            Type::Custom(path) => match self.resolve(path, ns) {
+                Some((owner, Decl::Struct(_) | Decl::Enum)) => {
+                    let name = path.last().as_string();
+                    match owner {
+                        Some(owner) => format!("{owner}::{name}"),
+                        None => name,
+                    }
+                }
+                Some((owner, Decl::Udt(underlying))) => self.rust_type(underlying, owner, imports),
+                Some((_, Decl::Contract)) => sol_rust_type("address", imports).unwrap_or_default(),
+                None => path.to_string(),
+            },
+        }
+    }
+
+    /// Canonical ABI type of `ty`, as used in signatures: structs as
+    /// tuples, enums as `uint8`, contracts as `address`.
+    fn canonical(&self, ty: &Type, ns: Option<&str>) -> String {
+        match ty {
+            Type::Address(..) => "address".into(),
+            Type::Int(_, bits) => format!("int{}", bits.map_or(256, |b| b.get())),
+            Type::Uint(_, bits) => format!("uint{}", bits.map_or(256, |b| b.get())),
+            Type::Array(array) => {
+                let size = match &array.size {
+                    None => String::new(),
+                    Some(_) => array_size(array),
+                };
+                format!("{}[{size}]", self.canonical(&array.ty, ns))
+            }
+            Type::Tuple(tuple) => {
+                let items: Vec<String> =
+                    tuple.types.iter().map(|t| self.canonical(t, ns)).collect();
+                format!("({})", items.join(","))
+            }
+            Type::Custom(path) => match self.resolve(path, ns) {
+                Some((owner, Decl::Struct(s))) => {
+                    let items: Vec<String> = s
+                        .fields
+                        .iter()
+                        .map(|f| self.canonical(&f.ty, owner))
+                        .collect();
+                    format!("({})", items.join(","))
+                }
+                Some((_, Decl::Enum)) => "uint8".into(),
+                Some((owner, Decl::Udt(underlying))) => self.canonical(underlying, owner),
+                Some((_, Decl::Contract)) => "address".into(),
+                None => path.to_string(),
+            },
+            ty => ty.to_string(),
+        }
+    }
+
+    /// Whether an indexed event parameter of type `ty` is stored as a hash.
+    fn is_value_type(&self, path: &SolPath, ns: Option<&str>) -> bool {
+        match self.resolve(path, ns) {
+            Some((_, Decl::Enum | Decl::Contract)) => true,
+            Some((owner, Decl::Udt(underlying))) => match underlying {
+                Type::Custom(path) => self.is_value_type(path, owner),
+                ty => ty.is_value_type_simple(),
+            },
+            _ => false,
+        }
+    }
+
+    /// Custom type names in `ty` that resolve to nothing.
+    fn unresolved<'t>(&self, ty: &'t Type, ns: Option<&str>, out: &mut Vec<&'t SolPath>) {
+        match ty {
+            Type::Array(array) => self.unresolved(&array.ty, ns, out),
+            Type::Tuple(tuple) => tuple.types.iter().for_each(|t| self.unresolved(t, ns, out)),
+            Type::Mapping(mapping) => {
+                self.unresolved(&mapping.key, ns, out);
+                self.unresolved(&mapping.value, ns, out);
+            }
+            Type::Custom(path) if self.resolve(path, ns).is_none() => out.push(path),
+            _ => {}
+        }
+    }
+}
+
+/// Length of a fixed-size array: the literal, or the source text of a
+/// constant expression.
+fn array_size(array: &TypeArray) -> String {
+    match (array.size(), &array.size) {
+        (Some(size), _) => size.to_string(),
+        (None, Some(expr)) => expr.span().source_text().unwrap_or_else(|| "N".into()),
+        (None, None) => String::new(),
+    }
+}
+
+/// An item `sol!` names after its Solidity name, and so numbers when
+/// overloaded.
+enum Named<'a> {
+    /// A function, or the getter of a public state variable.
+    Function(Box<ItemFunction>, Option<Getter<'a>>),
+    Event(&'a ItemEvent),
+    Error(&'a ItemError),
+}
+
+/// The state variable behind a getter and, for a struct, the getter's
+/// return types: the members that are not arrays or mappings.
+struct Getter<'a> {
+    var: &'a VariableDefinition,
+    returns: Option<Vec<Type>>,
+}
+
+impl<'a> Named<'a> {
+    fn new(decls: &Decls<'_>, item: &'a Item, ns: Option<&str>) -> Option<Self> {
+        Some(match item {
+            Item::Function(f)
+                if matches!(f.kind, FunctionKind::Function(_)) && f.name.is_some() =>
+            {
+                Self::Function(Box::new(f.clone()), None)
+            }
+            Item::Variable(var)
+                if var
+                    .attributes
+                    .visibility()
+                    .is_some_and(|v| v.is_public() || v.is_external()) =>
+            {
+                let returns = match &var.ty {
+                    Type::Custom(path) => match decls.resolve(path, ns) {
+                        Some((_, Decl::Struct(s))) => Some(
+                            s.fields
+                                .types()
+                                .filter(|t| !matches!(t, Type::Array(_) | Type::Mapping(_)))
+                                .cloned()
+                                .collect(),
+                        ),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                Self::Function(
+                    Box::new(ItemFunction::from_variable_definition(var.clone())),
+                    Some(Getter { var, returns }),
+                )
+            }
+            Item::Event(event) => Self::Event(event),
+            Item::Error(error) => Self::Error(error),
+            _ => return None,
+        })
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Self::Function(f, _) => f.name().as_string(),
+            Self::Event(e) => e.name.as_string(),
+            Self::Error(e) => e.name.as_string(),
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Self::Function(_, Some(getter)) => getter.var.span(),
+            Self::Function(f, None) => f.span(),
+            Self::Event(e) => e.span(),
+            Self::Error(e) => e.span(),
+        }
+    }
+
+    /// The parameter types the signature is built from.
+    fn parameter_types(&self) -> Vec<&Type> {
+        match self {
+            Self::Function(f, _) => f.parameters.types().collect(),
+            Self::Event(e) => e.parameters.iter().map(|p| &p.ty).collect(),
+            Self::Error(e) => e.parameters.types().collect(),
+        }
+    }
+
+    /// Canonical signature, e.g. `transfer(address,uint256)`.
+    fn signature(&self, decls: &Decls<'_>, ns: Option<&str>) -> String {
+        let types: Vec<String> = self
+            .parameter_types()
+            .into_iter()
+            .map(|t| decls.canonical(t, ns))
+            .collect();
+        format!("{}({})", self.name(), types.join(","))
+    }
+
+    /// The selector, or why there is none: a signature with unresolved
+    /// types hashes to nothing `sol!` would generate.
+    fn selector(&self, decls: &Decls<'_>, ns: Option<&str>, signature: &str) -> String {
+        let mut unresolved = Vec::new();
+        for ty in self.parameter_types() {
+            decls.unresolved(ty, ns, &mut unresolved);
+        }
+        if unresolved.is_empty() {
+            return format!("selector `{}`", selector(signature));
+        }
+        let mut names: Vec<String> = unresolved.iter().map(|p| format!("`{p}`")).collect();
+        names.dedup();
+        format!("no selector until {} resolves", names.join(", "))
+    }
+}
+
+/// `a: T` fields for `params`, `_N` for unnamed ones.
+fn fields(
+    decls: &Decls<'_>,
+    params: &ParameterList,
+    ns: Option<&str>,
+    imports: &mut Imports,
+) -> Vec<String> {
+    params
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let name = p.name.as_ref().map_or(format!("_{i}"), |n| n.as_string());
+            format!("{name}: {}", decls.rust_type(&p.ty, ns, imports))
+        })
+        .collect()
+}
+
+/// Argument names of `params`, `_N` for unnamed ones.
+fn arg_names(params: &ParameterList) -> Vec<String> {
+    params
+        .iter()
+        .enumerate()
+        .map(|(i, p)| p.name.as_ref().map_or(format!("_{i}"), |n| n.as_string()))
+        .collect()
+}
+
+/// `Name`, `Name(T)` or `Name { a: T }`: the shape `sol!` gives call and
+/// error structs.
+fn call_like(name: &str, params: &ParameterList, fields: &[String]) -> String {
+    match params.len() {
+        0 => name.to_string(),
+        1 if params[0].name.is_none() => {
+            let ty = fields[0].split_once(": ").map_or("", |(_, ty)| ty);
+            format!("{name}({ty})")
+        }
+        _ => format!("{name} {{ {} }}", fields.join(", ")),
+    }
+}
+
+fn selector(signature: &str) -> String {
+    format!(
+        "0x{}",
+        alloy_primitives::hex::encode(&keccak256(signature)[..4])
+    )
+}
+
+/// What the tool reports about one contract or the file level.
+#[derive(Default)]
+struct Report {
+    items: Vec<String>,
+    errors: Vec<(Span, String)>,
+    notes: Vec<String>,
+    /// Functions (with getters), events and errors.
+    counts: [usize; 3],
+}
+
+/// List the Rust items `sol!` generates for `items`, declared in contract
+/// `ns` (or at file level), and collect the errors the macro would raise.
+fn expand(
+    decls: &Decls<'_>,
+    items: &[Item],
+    ns: Option<&str>,
+    (rpc, bytecode): (bool, bool),
+    report: &mut Report,
+    imports: &mut Imports,
+) {
+    let path = |name: &str| match ns {
+        Some(ns) => format!("{ns}::{name}"),
+        None => name.to_string(),
+    };
+
+    // Unresolved types, usually Rust types written in Solidity position.
+    let mut unresolved = Vec::new();
+    for item in items {
+        let types: Vec<&Type> = match item {
+            Item::Function(f) => f
+                .parameters
+                .types()
+                .chain(f.returns.iter().flat_map(|r| r.returns.types()))
+                .collect(),
+            Item::Variable(var) => vec![&var.ty],
+            Item::Event(e) => e.parameters.iter().map(|p| &p.ty).collect(),
+            Item::Error(e) => e.parameters.types().collect(),
+            Item::Struct(s) => s.fields.types().collect(),
+            Item::Udt(u) => vec![&u.ty],
+            _ => Vec::new(),
+        };
+        for ty in types {
+            decls.unresolved(ty, ns, &mut unresolved);
+        }
+    }
+    for path in unresolved {
+        let name = path.to_string();
+        let hint = match RUST_NAMES.iter().find(|(rust, _)| *rust == name) {
+            Some((_, sol)) => format!(": `sol!` takes Solidity types, use `{sol}`"),
+            None => {
+                ": declare it in the block (struct, enum, `type ... is ...` or contract)".into()
+            }
+        };
+        report
+            .errors
+            .push((path.span(), format!("unresolved type `{name}`{hint}")));
+    }
+
+    // Declared types.
+    for item in items {
+        match item {
+            Item::Struct(s) => {
+                let fields: Vec<String> = s
+                    .fields
+                    .iter()
+                    .map(|f| {
+                        let name = f.name.as_ref().map(|n| n.as_string()).unwrap_or_default();
+                        format!("{name}: {}", decls.rust_type(&f.ty, ns, imports))
+                    })
+                    .collect();
+                report.items.push(format!(
+                    "- `{} {{ {} }}` — struct (`SolStruct`)",
+                    path(&s.name.as_string()),
+                    fields.join(", ")
+                ));
+            }
+            Item::Enum(e) => {
+                let variants: Vec<String> =
+                    e.variants.iter().map(|v| v.ident.as_string()).collect();
+                report.items.push(format!(
+                    "- `{} {{ {} }}` — enum over `u8`, plus `__Invalid` for out-of-range values",
+                    path(&e.name.as_string()),
+                    variants.join(", ")
+                ));
+            }
+            Item::Udt(u) => {
+                let underlying = decls.rust_type(&u.ty, ns, imports);
+                report.items.push(format!(
+                    "- `{}({underlying})` — user-defined value type; parameters and fields of \
+                    this type take a plain `{underlying}`",
+                    path(&u.name.as_string())
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    // Items sharing a name get a `_N` suffix in declaration order.
+    let named: Vec<Named<'_>> = items
+        .iter()
+        .filter_map(|item| Named::new(decls, item, ns))
+        .collect();
+    let signatures: Vec<String> = named.iter().map(|n| n.signature(decls, ns)).collect();
+    let mut overloaded = Vec::new();
+    let mut counters: BTreeMap<String, usize> = BTreeMap::new();
+    let mut rust_names = Vec::new();
+    for (i, item) in named.iter().enumerate() {
+        let name = item.name();
+        if named.iter().filter(|n| n.name() == name).count() < 2 {
+            rust_names.push(name);
+            continue;
+        }
+        let counter = counters.entry(name.clone()).or_default();
+        let rust = format!("{name}_{counter}");
+        *counter += 1;
+        if signatures[..i].contains(&signatures[i]) {
+            report.errors.push((
+                item.span(),
+                format!(
+                    "`{}` is declared twice: overloads need different parameter types",
+                    signatures[i]
+                ),
+            ));
+        }
+        overloaded.push(format!("`{}` → `{rust}`", signatures[i]));
+        rust_names.push(rust);
+    }
+    if !overloaded.is_empty() {
+        report.notes.push(format!(
+            "Overloads{}: {}. The `_N` suffix follows declaration order and carries over to \
+            the `*Call`/`*Return` structs, instance methods, event filters and enum variants.",
+            ns.map(|ns| format!(" in `{ns}`")).unwrap_or_default(),
+            overloaded.join(", ")
+        ));
+    }
+
+    let mut methods = Vec::new();
+    let mut filters = Vec::new();
+    for ((item, rust), signature) in named.iter().zip(&rust_names).zip(&signatures) {
+        match item {
+            Named::Function(function, getter) => {
+                report.items.extend(function_lines(
+                    decls,
+                    function,
+                    getter.as_ref(),
+                    &path(rust),
+                    (signature, &item.selector(decls, ns, signature)),
+                    ns,
+                    imports,
+                ));
+                methods.push(format!(
+                    "`{rust}({})`",
+                    arg_names(&function.parameters).join(", ")
+                ));
+                report.counts[0] += 1;
+            }
+            Named::Event(event) => {
+                let mut hashed = Vec::new();
+                let fields: Vec<String> = event
+                    .parameters
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| {
+                        let name = p.name.as_ref().map_or(format!("_{i}"), |n| n.as_string());
+                        let ty = if p.indexed_as_hash(|path| decls.is_value_type(path, ns)) {
+                            hashed.push(format!("`{name}`"));
+                            sol_rust_type("bytes32", imports).unwrap_or_default()
+                        } else {
+                            decls.rust_type(&p.ty, ns, imports)
+                        };
+                        format!("{name}: {ty}")
+                    })
+                    .collect();
+                let shape = match fields.is_empty() {
+                    true => path(rust),
+                    false => format!("{} {{ {} }}", path(rust), fields.join(", ")),
+                };
+                let mut line = format!(
+                    "- `{shape}` — event `{signature}`{}",
+                    if event.is_anonymous() {
+                        ", anonymous"
+                    } else {
+                        ""
+                    }
+                );
+                if !hashed.is_empty() {
+                    let _ = write!(
+                        line,
+                        "; indexed {} {} the keccak256 hash of the value",
+                        hashed.join(", "),
+                        if hashed.len() == 1 { "holds" } else { "hold" }
+                    );
+                }
+                report.items.push(line);
+                filters.push(format!("`{rust}_filter()`"));
+                report.counts[1] += 1;
+            }
+            Named::Error(error) => {
+                let fields = fields(decls, &error.parameters, ns, imports);
+                report.items.push(format!(
+                    "- `{}` — error `{signature}`, {}",
+                    call_like(&path(rust), &error.parameters, &fields),
+                    item.selector(decls, ns, signature)
+                ));
+                report.counts[2] += 1;
+            }
+        }
+    }
+
+    let Some(ns) = ns else {
+        return;
+    };
+    let constructor = items.iter().find_map(|item| match item {
+        Item::Function(f) if matches!(f.kind, FunctionKind::Constructor(_)) => Some(f),
+        _ => None,
+    });
+    if let Some(constructor) = constructor {
+        let fields = fields(decls, &constructor.parameters, Some(ns), imports);
+        report.items.push(format!(
+            "- `{ns}::constructorCall {{ {} }}` — constructor arguments",
+            fields.join(", ")
+        ));
+    }
+    if rpc {
+        let mut line = format!("- `{ns}::{ns}Instance` — `{ns}::new(address, &provider)`");
+        if !methods.is_empty() {
+            let _ = write!(line, "; methods {}", methods.join(", "));
+        }
+        if !filters.is_empty() {
+            let _ = write!(line, "; event filters {}", filters.join(", "));
+        }
+        report.items.push(line);
+        if bytecode {
+            let args: String = constructor
+                .map(|c| arg_names(&c.parameters))
+                .unwrap_or_default()
+                .iter()
+                .map(|a| format!(", {a}"))
+                .collect();
+            report.items.push(format!(
+                "- `{ns}::deploy(&provider{args})` and `{ns}::BYTECODE` — from the `bytecode` attribute"
+            ));
+        }
+    } else if !methods.is_empty() {
+        report.notes.push(format!(
+            "`{ns}` has no `#[sol(rpc)]`, so there is no `{ns}Instance` or `{ns}::new`: add it \
+            to call the contract through a provider, or encode the `*Call` structs yourself."
+        ));
+    }
+    let enums: Vec<String> = [
+        (!methods.is_empty(), "Calls", "function calls"),
+        (!filters.is_empty(), "Events", "events"),
+        (report.counts[2] > 0, "Errors", "errors"),
+    ]
+    .into_iter()
+    .filter(|(present, _, _)| *present)
+    .map(|(_, suffix, what)| format!("`{ns}::{ns}{suffix}` ({what})"))
+    .collect();
+    if !enums.is_empty() {
+        report.items.push(format!(
+            "- {} — one variant per item, for decoding calldata, logs and revert data",
+            enums.join(", ")
+        ));
+    }
+}
+
+/// The `*Call` and `*Return` lines for one function; `path` is its Rust
+/// name, with the contract and any overload suffix, and `selector` the
+/// text `Named::selector` gave for it.
+fn function_lines(
+    decls: &Decls<'_>,
+    function: &ItemFunction,
+    getter: Option<&Getter<'_>>,
+    path: &str,
+    (signature, selector): (&str, &str),
+    ns: Option<&str>,
+    imports: &mut Imports,
+) -> [String; 2] {
+    let params = &function.parameters;
+    let call_fields = fields(decls, params, ns, imports);
+    let mutability = match function.attributes.mutability() {
+        Some(Mutability::View(_)) => ", view",
+        Some(Mutability::Pure(_)) => ", pure",
+        Some(Mutability::Payable(_)) => ", payable",
+        _ => "",
+    };
+    let call = format!(
+        "- `{}` — {}`{signature}`, {selector}{mutability}",
+        call_like(&format!("{path}Call"), params, &call_fields),
+        getter
+            .map(|g| format!("getter for `{}`, ", g.var.name))
+            .unwrap_or_default(),
+    );
+
+    let returns: Vec<String> = match getter.and_then(|g| g.returns.as_ref()) {
+        Some(types) => types
+            .iter()
+            .enumerate()
+            .map(|(i, t)| format!("_{i}: {}", decls.rust_type(t, ns, imports)))
+            .collect(),
+        None => match &function.returns {
+            Some(returns) => fields(decls, &returns.returns, ns, imports),
+            None => Vec::new(),
+        },
+    };
+    let decoded = match returns.as_slice() {
+        [] => "`.call()` returns `()`".to_string(),
+        [single] => format!(
+            "`.call()` returns the `{}` directly",
+            single.split_once(": ").map_or("", |(_, ty)| ty)
+        ),
+        _ => format!("`.call()` returns `{path}Return`"),
+    };
+    let ret = match returns.is_empty() {
+        true => format!("- `{path}Return {{}}` — {decoded}"),
+        false => format!("- `{path}Return {{ {} }}` — {decoded}", returns.join(", ")),
+    };
+    [call, ret]
+}
+
+#[tool_router(router = sol_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Parse a `sol!` block with `syn-solidity` and list the Rust items the
+    /// macro generates from it.
+    #[tool(
+        description = "Check a sol! block (or the Solidity inside it) with syn-solidity: report syntax errors and unresolved types with line and column, and list the Rust items the macro generates (fooCall, fooReturn, event and error structs, FooInstance, the Calls/Events/Errors enums) with their field types and selectors."
+    )]
+    fn validate_sol(
+        &self,
+        Parameters(ValidateSolRequest { code }): Parameters<ValidateSolRequest>,
+    ) -> String {
+        let body = sol_body(&code);
+        let trimmed = body.trim().trim_end_matches(',');
+        if trimmed.ends_with(".json\"") {
+            return "This `sol!` reads a JSON ABI from a file, which this tool cannot see. Pass the \
+                ABI to `abi_to_sol` for the equivalent Solidity, then check that here."
+                .into();
+        }
+
+        let file: File = match syn::parse_str(&body) {
+            Ok(file) => file,
+            Err(e) => {
+                let mut result = String::from("# `sol!` syntax errors\n\n");
+                for error in e {
+                    let (location, snippet) = locate(&code, error.span());
+                    let _ = writeln!(result, "- {location}: {error}");
+                    result.push_str(&snippet);
+                }
+                result.push_str(
+                    "`sol!` takes Solidity syntax: `uint256` not `U256`, `;` after each \
+                    declaration, and `external`/`view` modifiers as in an interface.\n\n\
+                    **Key resources:**\n\
+                    - `alloy://sol-macro/contract-bindings` — Defining Contract Bindings\n",
+                );
+                return result;
+            }
+        };
+
+        let decls = Decls::new(&file);
+        let (file_rpc, file_bytecode) = sol_flags(&file.attrs);
+        let mut imports = Imports::default();
+        let mut sections = Vec::new();
+        let mut errors = Vec::new();
+        let mut notes = Vec::new();
+        let mut counts = [0; 3];
+
+        let mut root = Report::default();
+        expand(
+            &decls,
+            &file.items,
+            None,
+            (false, false),
+            &mut root,
+            &mut imports,
+        );
+        for item in &file.items {
+            let Item::Contract(contract) = item else {
+                continue;
+            };
+            let name = contract.name.as_string();
+            let (rpc, bytecode) = sol_flags(&contract.attrs);
+            let rpc = rpc.or(file_rpc).unwrap_or(false);
+            let mut report = Report::default();
+            expand(
+                &decls,
+                &contract.body,
+                Some(&name),
+                (rpc, bytecode || file_bytecode),
+                &mut report,
+                &mut imports,
+            );
+            let kind = match contract.kind {
+                ContractKind::AbstractContract(..) => "abstract contract",
+                ContractKind::Contract(_) => "contract",
+                ContractKind::Interface(_) => "interface",
+                ContractKind::Library(_) => "library",
+            };
+            let mut section = format!(
+                "### `{name}` — {kind}, line {}\n\nModule `{name}`{}:\n\n",
+                contract.span().start().line,
+                if rpc { " with `#[sol(rpc)]`" } else { "" }
+            );
+            for line in &report.items {
+                let _ = writeln!(section, "{line}");
+            }
+            sections.push(section);
+            errors.extend(report.errors);
+            notes.extend(report.notes);
+            counts
+                .iter_mut()
+                .zip(report.counts)
+                .for_each(|(c, n)| *c += n);
+        }
+        if !root.items.is_empty() {
+            let mut section = String::from("### File level\n\n");
+            for line in &root.items {
+                let _ = writeln!(section, "{line}");
+            }
+            sections.insert(0, section);
+        }
+        errors.extend(root.errors);
+        notes.extend(root.notes);
+        counts
+            .iter_mut()
+            .zip(root.counts)
+            .for_each(|(c, n)| *c += n);
+        errors.sort_by_key(|(span, _)| (span.start().line, span.start().column));
+
+        let mut result = match errors.is_empty() {
+            true => format!(
+                "# `sol!` check: parses\n\n{} function(s), {} event(s), {} error(s).\n",
+                counts[0], counts[1], counts[2]
+            ),
+            false => format!(
+                "# `sol!` check: {} error(s)\n\nThe block parses, but `sol!` rejects it:\n\n",
+                errors.len()
+            ),
+        };
+        for (span, message) in &errors {
+            let (location, snippet) = locate(&code, *span);
+            let _ = writeln!(result, "- {location}: {message}");
+            result.push_str(&snippet);
+        }
+        if !sections.is_empty() {
+            result.push_str("\n## Generated items\n\n");
+            result.push_str(
+                "Paths are from the module `sol!` is invoked in; types follow the \
+                Solidity-to-Rust type mapping.\n\n",
+            );
+            result.push_str(&sections.join("\n"));
+        }
+        if !notes.is_empty() {
+            result.push_str("\n## Notes\n\n");
+            for note in &notes {
+                let _ = writeln!(result, "- {note}");
+            }
+        }
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://sol-macro/contract-bindings` — Solidity-to-Rust Type Mapping, Overloaded Functions\n\
+            - `alloy://sol-macro/sol-types` — SolCall, SolEvent and SolStruct\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unresolved_signatures_get_no_selector() {
+        let result = AlloyMcpServer::new().validate_sol(Parameters(ValidateSolRequest {
+            code:
+                "contract C { function f(Unknown x); function g(uint256 a); error E(Unknown z); }"
+                    .into(),
+        }));
+        assert!(result.contains("`f(Unknown)`, no selector until `Unknown` resolves"));
+        assert!(result.contains("error `E(Unknown)`, no selector until `Unknown` resolves"));
+        assert!(result.contains("`g(uint256)`, selector `0xe420264a`"));
+    }
+}
@@ -0,0 +1,368 @@
+use std::fmt::Write;
+
+use alloy_dyn_abi::DynSolType;
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+
+use super::{
+    codegen::{Imports, sol_rust_type},
+    sol::RUST_NAMES,
+};
+use crate::server::AlloyMcpServer;
+
+/// What `DynSolType::parse` accepts, for inputs it rejects.
+const ABI_TYPES: &str = "Use an ABI type: `uint<N>`/`int<N>` with N a multiple of 8 up to 256, \
+    `bytes<N>` with N up to 32, `address`, `bool`, `string`, `bytes`, `function`, arrays \
+    `T[]`/`T[N]` and tuples `(T,U)` or `tuple(T,U)`. Structs are tuples of their members; enums \
+    are `uint8`; contracts are `address`.";
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct MapSolTypeRequest {
+    #[schemars(
+        description = "Solidity ABI type, e.g. \"uint24\", \"bytes32[]\", \"tuple(address,uint256)[]\", \"int8\", \"string\" or \"function\""
+    )]
+    ty: String,
+}
+
+/// Rust type `sol!` generates for `ty`, e.g. `Vec<(Address, U256)>`.
+fn rust_type(ty: &DynSolType, imports: &mut Imports) -> String {
+    match ty {
+        DynSolType::Array(inner) => format!("Vec<{}>", rust_type(inner, imports)),
+        DynSolType::FixedArray(inner, size) => format!("[{}; {size}]", rust_type(inner, imports)),
+        DynSolType::Tuple(types) => tuple(types.iter().map(|t| rust_type(t, imports)).collect()),
+        _ => sol_rust_type(&ty.sol_type_name(), imports).unwrap_or_default(),
+    }
+}
+
+/// `SolType` marker for `ty`, e.g. `sol_data::Array<sol_data::Uint<8>>`.
+fn marker(ty: &DynSolType) -> String {
+    match ty {
+        DynSolType::Bool => "sol_data::Bool".into(),
+        DynSolType::Int(bits) => format!("sol_data::Int<{bits}>"),
+        DynSolType::Uint(bits) => format!("sol_data::Uint<{bits}>"),
+        DynSolType::FixedBytes(size) => format!("sol_data::FixedBytes<{size}>"),
+        DynSolType::Address => "sol_data::Address".into(),
+        DynSolType::Function => "sol_data::Function".into(),
+        DynSolType::Bytes => "sol_data::Bytes".into(),
+        DynSolType::String => "sol_data::String".into(),
+        DynSolType::Array(inner) => format!("sol_data::Array<{}>", marker(inner)),
+        DynSolType::FixedArray(inner, size) => {
+            format!("sol_data::FixedArray<{}, {size}>", marker(inner))
+        }
+        DynSolType::Tuple(types) => tuple(types.iter().map(marker).collect()),
+        _ => ty.sol_type_name().into_owned(),
+    }
+}
+
+/// Rust types whose `SolValue` impl encodes as `ty`, the `sol!` type first.
+/// Empty when there is none: `u8` is reserved for `bytes` and `bytesN`, so
+/// `uint8` only encodes through its marker.
+fn sol_values(ty: &DynSolType, imports: &mut Imports) -> Vec<String> {
+    let first = |ty: &DynSolType, imports: &mut Imports| sol_values(ty, imports).into_iter().next();
+    match ty {
+        DynSolType::Uint(8) => Vec::new(),
+        DynSolType::FixedBytes(size) => {
+            let mut types = vec![rust_type(ty, imports)];
+            if *size == 32 {
+                types.push("FixedBytes<32>".into());
+            }
+            types.push(format!("[u8; {size}]"));
+            types
+        }
+        DynSolType::Bytes => vec![rust_type(ty, imports), "Vec<u8>".into(), "&[u8]".into()],
+        DynSolType::String => vec!["String".into(), "&str".into()],
+        DynSolType::Array(inner) => match first(inner, imports) {
+            Some(inner) => vec![format!("Vec<{inner}>"), format!("&[{inner}]")],
+            None => Vec::new(),
+        },
+        DynSolType::FixedArray(inner, size) => match first(inner, imports) {
+            Some(inner) => vec![format!("[{inner}; {size}]")],
+            None => Vec::new(),
+        },
+        DynSolType::Tuple(types) => types
+            .iter()
+            .map(|t| first(t, imports))
+            .collect::<Option<Vec<_>>>()
+            .map(|items| vec![tuple(items)])
+            .unwrap_or_default(),
+        _ => vec![rust_type(ty, imports)],
+    }
+}
+
+/// A Rust tuple type, with the trailing comma a single element needs.
+fn tuple(items: Vec<String>) -> String {
+    match items.as_slice() {
+        [single] => format!("({single},)"),
+        _ => format!("({})", items.join(", ")),
+    }
+}
+
+/// A static type whose head takes more than `usize::MAX` bytes, such as
+/// `uint256[9999999999999][9999999999999]`.
+struct TooLarge;
+
+/// Bytes a static type takes in the head; `None` for dynamic types, which
+/// put a 32-byte offset there instead.
+fn static_size(ty: &DynSolType) -> Result<Option<usize>, TooLarge> {
+    match ty {
+        DynSolType::Bytes | DynSolType::String | DynSolType::Array(_) => Ok(None),
+        DynSolType::FixedArray(inner, size) => match static_size(inner)? {
+            Some(s) => s.checked_mul(*size).map(Some).ok_or(TooLarge),
+            None => Ok(None),
+        },
+        DynSolType::Tuple(types) => {
+            let sizes: Vec<_> = types.iter().map(static_size).collect();
+            if sizes.iter().any(|s| matches!(s, Ok(None))) {
+                return Ok(None);
+            }
+            let mut total = 0usize;
+            for size in sizes.into_iter().flat_map(|s| s.transpose()) {
+                total = total.checked_add(size?).ok_or(TooLarge)?;
+            }
+            Ok(Some(total))
+        }
+        _ => Ok(Some(32)),
+    }
+}
+
+/// Bytes `abi_encode_packed` writes for an elementary type.
+fn packed_size(ty: &DynSolType) -> Option<usize> {
+    match ty {
+        DynSolType::Bool => Some(1),
+        DynSolType::Int(bits) | DynSolType::Uint(bits) => Some(bits / 8),
+        DynSolType::FixedBytes(size) => Some(*size),
+        DynSolType::Address => Some(20),
+        DynSolType::Function => Some(24),
+        _ => None,
+    }
+}
+
+/// What the tail of a dynamic type holds.
+fn tail(ty: &DynSolType) -> String {
+    match ty {
+        DynSolType::Bytes | DynSolType::String => {
+            "a length word, then the data right-padded to a multiple of 32 bytes".into()
+        }
+        DynSolType::Array(inner) => format!(
+            "a length word, then the elements encoded as a tuple of that length ({})",
+            element(inner)
+        ),
+        DynSolType::FixedArray(inner, size) => format!(
+            "the {size} elements encoded as a tuple ({}), with no length word",
+            element(inner)
+        ),
+        _ => "the members' heads (offsets for dynamic members) followed by their tails".into(),
+    }
+}
+
+fn element(ty: &DynSolType) -> String {
+    match static_size(ty) {
+        Ok(Some(size)) => format!("{size} bytes each"),
+        Ok(None) => "a 32-byte offset each, relative to the first element, then their tails".into(),
+        Err(TooLarge) => "each too large to encode, over `usize::MAX` bytes".into(),
+    }
+}
+
+#[tool_router(router = sol_type_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Map one Solidity type to the Rust side of `alloy-sol-types`.
+    #[tool(
+        description = "Map a Solidity ABI type (uint24, bytes32[], tuple(address,uint256)[], int8, string, function, ...) to the Rust type sol! generates, its SolType marker (sol_data::...), the Rust types SolValue encodes as it, and how it is ABI-encoded: static or dynamic, head size, tail layout and packed size. Covers odd integer widths and nested arrays."
+    )]
+    fn map_sol_type(
+        &self,
+        Parameters(MapSolTypeRequest { ty }): Parameters<MapSolTypeRequest>,
+    ) -> String {
+        let input = ty.trim();
+        let ty = match DynSolType::parse(input) {
+            Ok(ty) => ty,
+            Err(e) => {
+                let hint = match RUST_NAMES.iter().find(|(rust, _)| *rust == input) {
+                    Some((_, sol)) => {
+                        format!("`{input}` is the Rust side; the Solidity type is `{sol}`.")
+                    }
+                    None => ABI_TYPES.into(),
+                };
+                return format!("# `{input}`\n\nNot a Solidity ABI type: {e}\n\n{hint}\n");
+            }
+        };
+
+        let mut imports = Imports::default();
+        let rust = rust_type(&ty, &mut imports);
+        let marker = marker(&ty);
+        let mut values = Imports::default();
+        let sol_values = sol_values(&ty, &mut values);
+        let name = ty.sol_type_name();
+
+        let mut result = format!("# `{name}`\n\n");
+        if name != input {
+            let _ = writeln!(result, "Canonical form of `{input}`.\n");
+        }
+        result.push_str("| | |\n|---|---|\n");
+        // The aliases spelled out, as they appear in rustdoc and errors.
+        let expanded = match &ty {
+            DynSolType::Uint(bits) if rust.starts_with('U') => {
+                format!(" (`Uint<{bits}, {}>`)", bits.div_ceil(64))
+            }
+            DynSolType::Int(bits) if rust.starts_with('I') => {
+                format!(" (`Signed<{bits}, {}>`)", bits.div_ceil(64))
+            }
+            DynSolType::FixedBytes(32) => " (`FixedBytes<32>`)".into(),
+            DynSolType::Function => " (`FixedBytes<24>`)".into(),
+            _ => String::new(),
+        };
+        let _ = writeln!(result, "| `sol!` Rust type | `{rust}`{expanded} |");
+        let _ = writeln!(result, "| `SolType` marker | `{marker}` |");
+        let _ = writeln!(
+            result,
+            "| `SolValue` types | {} |",
+            if sol_values.is_empty() {
+                "none, encode through the marker".to_string()
+            } else {
+                sol_values
+                    .iter()
+                    .map(|t| format!("`{t}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        );
+        let head = static_size(&ty);
+        let encoding = match head {
+            Ok(Some(size)) => format!(
+                "static, {size} bytes ({} word{}) in place in the head",
+                size / 32,
+                if size == 32 { "" } else { "s" }
+            ),
+            Ok(None) => "dynamic, a 32-byte offset in the head and the data in the tail".into(),
+            Err(TooLarge) => {
+                "static, but too large to encode: the head exceeds `usize::MAX` bytes".into()
+            }
+        };
+        let _ = writeln!(result, "| Encoding | {encoding} |");
+        if let Some(size) = packed_size(&ty) {
+            let _ = writeln!(
+                result,
+                "| Packed | {size} byte{} |",
+                if size == 1 { "" } else { "s" }
+            );
+        }
+
+        if matches!(head, Ok(None)) {
+            let _ = writeln!(result, "\nThe tail holds {}.", tail(&ty));
+        }
+        if let DynSolType::Tuple(types) = &ty {
+            result.push_str(
+                "\n| Member | Head offset | Head bytes |\n|--------|-------------|------------|\n",
+            );
+            let too_large = || "too large".to_string();
+            let mut offset = Some(0usize);
+            for member in types {
+                let (size, bytes) = match static_size(member) {
+                    Ok(Some(size)) => (Some(size), size.to_string()),
+                    Ok(None) => (Some(32), "32 (offset)".into()),
+                    Err(TooLarge) => (None, too_large()),
+                };
+                let _ = writeln!(
+                    result,
+                    "| `{}` | {} | {bytes} |",
+                    member.sol_type_name(),
+                    offset.map_or_else(too_large, |o| o.to_string())
+                );
+                offset = offset.zip(size).and_then(|(o, s)| o.checked_add(s));
+            }
+        }
+
+        imports.add("alloy::sol_types", "SolType");
+        imports.add("alloy::sol_types", "sol_data");
+        let _ = write!(
+            result,
+            "\n```rust\n{}\ntype Marker = {marker};\n\n\
+            let encoded = Marker::abi_encode(&value);\n\
+            let decoded: {rust} = Marker::abi_decode(&encoded)?;\n",
+            imports.render()
+        );
+        if !sol_values.is_empty() {
+            let _ = writeln!(
+                result,
+                "// Or through SolValue: `value.abi_encode()` and `{}::abi_decode(&encoded)?`",
+                match sol_values[0].contains(['<', '[', '(']) {
+                    true => format!("<{}>", sol_values[0]),
+                    false => sol_values[0].clone(),
+                }
+            );
+        }
+        result.push_str("```\n");
+
+        let mut notes = Vec::new();
+        if find(&ty, &|t| matches!(t, DynSolType::Uint(8))).is_some() {
+            notes.push(
+                "`u8` has no `SolValue` impl: `Vec<u8>` and `[u8; N]` encode as `bytes` and \
+                `bytesN`. Encode `uint8` values, and arrays of them, through the marker."
+                    .to_string(),
+            );
+        }
+        let odd = find(&ty, &|t| match t {
+            DynSolType::Int(bits) | DynSolType::Uint(bits) => {
+                ![8, 16, 32, 64, 128, 256].contains(bits)
+            }
+            _ => false,
+        });
+        match odd {
+            Some(DynSolType::Uint(bits)) => notes.push(format!(
+                "`U{bits}` is a `Uint` alias from `alloy::primitives::aliases`: build it with \
+                `U{bits}::from(x)` (panics if `x` does not fit) or `U{bits}::try_from(x)?`, and read \
+                it with `.to::<u64>()` or `u64::try_from(v)?`."
+            )),
+            Some(DynSolType::Int(bits)) => notes.push(format!(
+                "`I{bits}` is a `Signed` alias from `alloy::primitives::aliases`: build it with \
+                `I{bits}::try_from(x)?` and read it with `i64::try_from(v)?`."
+            )),
+            _ => {}
+        }
+        if find(&ty, &|t| matches!(t, DynSolType::Function)).is_some() {
+            notes.push(
+                "`function` is an external function pointer: the 20-byte address followed by the \
+                4-byte selector (`Function::from_address_and_selector`)."
+                    .into(),
+            );
+        }
+        if find(&ty, &|t| matches!(t, DynSolType::Tuple(_))).is_some() {
+            notes.push(
+                "Tuples are how structs appear in an ABI. A `struct` declared in `sol!` takes the \
+                tuple's place with named fields and implements `SolValue` itself."
+                    .into(),
+            );
+        }
+        if matches!(head, Ok(None)) {
+            notes.push(
+                "As an `indexed` event parameter this type is not stored; the topic holds its \
+                keccak256 hash, a `B256`."
+                    .into(),
+            );
+        }
+        if !notes.is_empty() {
+            result.push_str("\n## Notes\n\n");
+            for note in &notes {
+                let _ = writeln!(result, "- {note}");
+            }
+        }
+
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://sol-macro/contract-bindings` — Solidity-to-Rust Type Mapping\n\
+            - `alloy://sol-macro/sol-types` — SolType: Encoding Primitives, SolValue: Encode Rust Values Directly, Encoding Modes\n",
+        );
+        result
+    }
+}
+
+/// `ty` or the first type nested in it that matches.
+fn find<'a>(ty: &'a DynSolType, matches: &dyn Fn(&DynSolType) -> bool) -> Option<&'a DynSolType> {
+    if matches(ty) {
+        return Some(ty);
+    }
+    match ty {
+        DynSolType::Array(inner) | DynSolType::FixedArray(inner, _) => find(inner, matches),
+        DynSolType::Tuple(types) => types.iter().find_map(|t| find(t, matches)),
+        _ => None,
+    }
+}
@@ -0,0 +1,830 @@
+use std::fmt::Write;
+
+use alloy_primitives::{Address, B256, Bytes, U256};
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+use serde_json::{Map, Value};
+
+use super::codegen::{
+    Imports, address_lit, b256_lit, bytes_expr, int_lit, u256_expr, units_comment,
+};
+use crate::server::AlloyMcpServer;
+
+/// The consensus transaction types the generator can emit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TxType {
+    Legacy,
+    Eip2930,
+    Eip1559,
+    Eip4844,
+}
+
+impl TxType {
+    fn struct_name(self) -> &'static str {
+        match self {
+            Self::Legacy => "TxLegacy",
+            Self::Eip2930 => "TxEip2930",
+            Self::Eip1559 => "TxEip1559",
+            Self::Eip4844 => "TxEip4844",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Legacy => "Legacy (type 0)",
+            Self::Eip2930 => "EIP-2930 (type 1)",
+            Self::Eip1559 => "EIP-1559 (type 2)",
+            Self::Eip4844 => "EIP-4844 (type 3)",
+        }
+    }
+
+    /// Rust field types of the consensus struct, in declaration order.
+    fn field_types(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Legacy => &[
+                ("chain_id", "Option<ChainId>"),
+                ("nonce", "u64"),
+                ("gas_price", "u128"),
+                ("gas_limit", "u64"),
+                ("to", "TxKind"),
+                ("value", "U256"),
+                ("input", "Bytes"),
+            ],
+            Self::Eip2930 => &[
+                ("chain_id", "ChainId"),
+                ("nonce", "u64"),
+                ("gas_price", "u128"),
+                ("gas_limit", "u64"),
+                ("to", "TxKind"),
+                ("value", "U256"),
+                ("access_list", "AccessList"),
+                ("input", "Bytes"),
+            ],
+            Self::Eip1559 => &[
+                ("chain_id", "ChainId"),
+                ("nonce", "u64"),
+                ("gas_limit", "u64"),
+                ("max_fee_per_gas", "u128"),
+                ("max_priority_fee_per_gas", "u128"),
+                ("to", "TxKind"),
+                ("value", "U256"),
+                ("access_list", "AccessList"),
+                ("input", "Bytes"),
+            ],
+            Self::Eip4844 => &[
+                ("chain_id", "ChainId"),
+                ("nonce", "u64"),
+                ("gas_limit", "u64"),
+                ("max_fee_per_gas", "u128"),
+                ("max_priority_fee_per_gas", "u128"),
+                ("to", "Address"),
+                ("value", "U256"),
+                ("access_list", "AccessList"),
+                ("blob_versioned_hashes", "Vec<B256>"),
+                ("max_fee_per_blob_gas", "u128"),
+                ("input", "Bytes"),
+            ],
+        }
+    }
+}
+
+/// `(address, storage keys)` pairs of an access list.
+type AccessListEntries = Vec<(Address, Vec<B256>)>;
+
+/// Signature components taken from a signed transaction object.
+struct SignatureParts {
+    r: U256,
+    s: U256,
+    y_parity: bool,
+    hash: Option<B256>,
+}
+
+/// A transaction parsed from RPC-shaped JSON, with fields narrowed to the
+/// Rust types alloy uses for them.
+struct ParsedTx {
+    tx_type: TxType,
+    /// Which JSON field decided the transaction type.
+    type_source: String,
+    from: Option<Address>,
+    to: Option<Address>,
+    value: Option<U256>,
+    input: Option<Bytes>,
+    nonce: Option<u64>,
+    chain_id: Option<u64>,
+    gas_limit: Option<u64>,
+    gas_price: Option<u128>,
+    max_fee_per_gas: Option<u128>,
+    max_priority_fee_per_gas: Option<u128>,
+    max_fee_per_blob_gas: Option<u128>,
+    access_list: Option<AccessListEntries>,
+    blob_versioned_hashes: Option<Vec<B256>>,
+    signature: Option<SignatureParts>,
+    notes: Vec<String>,
+}
+
+/// Look up the first non-null field among `names` (RPC aliases such as
+/// `gas`/`gasLimit` or `input`/`data`).
+fn field<'a>(obj: &'a Map<String, Value>, names: &[&str]) -> Option<(&'a str, &'a Value)> {
+    names.iter().find_map(|name| {
+        obj.get_key_value(*name)
+            .filter(|(_, v)| !v.is_null())
+            .map(|(k, v)| (k.as_str(), v))
+    })
+}
+
+/// Parse a JSON quantity: a hex string (`"0x5208"`), a decimal string, or
+/// an integer number.
+fn parse_quantity(name: &str, value: &Value) -> Result<U256, String> {
+    match value {
+        Value::Number(n) => n.as_u64().map(U256::from).ok_or_else(|| {
+            format!(
+                "`{name}` must be a non-negative integer; pass large values as hex or decimal strings"
+            )
+        }),
+        Value::String(s) => s
+            .trim()
+            .parse::<U256>()
+            .map_err(|e| format!("`{name}` is not a valid quantity ({s:?}): {e}")),
+        other => Err(format!("`{name}` must be a quantity, got {other}")),
+    }
+}
+
+fn quantity(obj: &Map<String, Value>, names: &[&str]) -> Result<Option<U256>, String> {
+    field(obj, names)
+        .map(|(name, value)| parse_quantity(name, value))
+        .transpose()
+}
+
+fn quantity_u64(obj: &Map<String, Value>, names: &[&str]) -> Result<Option<u64>, String> {
+    field(obj, names)
+        .map(|(name, value)| {
+            let v = parse_quantity(name, value)?;
+            u64::try_from(v).map_err(|_| format!("`{name}` ({v}) does not fit in u64"))
+        })
+        .transpose()
+}
+
+fn quantity_u128(obj: &Map<String, Value>, names: &[&str]) -> Result<Option<u128>, String> {
+    field(obj, names)
+        .map(|(name, value)| {
+            let v = parse_quantity(name, value)?;
+            u128::try_from(v).map_err(|_| format!("`{name}` ({v}) does not fit in u128"))
+        })
+        .transpose()
+}
+
+fn parse_str<T: std::str::FromStr>(name: &str, value: &Value, what: &str) -> Result<T, String>
+where
+    T::Err: std::fmt::Display,
+{
+    let s = value
+        .as_str()
+        .ok_or_else(|| format!("`{name}` must be a hex string ({what})"))?;
+    s.trim()
+        .parse()
+        .map_err(|e| format!("`{name}` is not a valid {what} ({s:?}): {e}"))
+}
+
+fn address(obj: &Map<String, Value>, names: &[&str]) -> Result<Option<Address>, String> {
+    field(obj, names)
+        .map(|(name, value)| parse_str(name, value, "20-byte address"))
+        .transpose()
+}
+
+fn hashes(name: &str, value: &Value) -> Result<Vec<B256>, String> {
+    value
+        .as_array()
+        .ok_or_else(|| format!("`{name}` must be an array of 32-byte hashes"))?
+        .iter()
+        .map(|v| parse_str(name, v, "32-byte hash"))
+        .collect()
+}
+
+fn access_list(obj: &Map<String, Value>) -> Result<Option<AccessListEntries>, String> {
+    let Some((_, value)) = field(obj, &["accessList"]) else {
+        return Ok(None);
+    };
+    let items = value
+        .as_array()
+        .ok_or("`accessList` must be an array of { address, storageKeys }")?;
+    items
+        .iter()
+        .map(|item| {
+            let item = item
+                .as_object()
+                .ok_or("`accessList` entries must be objects with `address` and `storageKeys`")?;
+            let address =
+                address(item, &["address"])?.ok_or("`accessList` entry is missing `address`")?;
+            let keys = match field(item, &["storageKeys"]) {
+                Some((name, v)) => hashes(name, v)?,
+                None => Vec::new(),
+            };
+            Ok((address, keys))
+        })
+        .collect::<Result<_, String>>()
+        .map(Some)
+}
+
+/// Decide the transaction type from an explicit `type` field, or infer it
+/// from which fee fields are present.
+fn detect_type(obj: &Map<String, Value>) -> Result<(TxType, String), String> {
+    if let Some(ty) = quantity(obj, &["type"])? {
+        let ty = match u64::try_from(ty) {
+            Ok(0) => TxType::Legacy,
+            Ok(1) => TxType::Eip2930,
+            Ok(2) => TxType::Eip1559,
+            Ok(3) => TxType::Eip4844,
+            Ok(4) => {
+                return Err(
+                    "EIP-7702 (type 4) transactions carry a signed authorization list \
+                    and are not supported by this generator. See `TxEip7702` in \
+                    alloy://consensus/transactions."
+                        .into(),
+                );
+            }
+            _ => {
+                return Err(format!(
+                    "Unsupported transaction type {ty:#x}. Only Ethereum types 0-3 are supported; \
+                    network-specific types (e.g. OP deposit 0x7e) need their network's consensus crate."
+                ));
+            }
+        };
+        return Ok((ty, format!("explicit `type` field ({})", ty.label())));
+    }
+
+    let inferred = if let Some((name, _)) = field(obj, &["blobVersionedHashes", "maxFeePerBlobGas"])
+    {
+        (TxType::Eip4844, name)
+    } else if let Some((name, _)) = field(obj, &["maxFeePerGas", "maxPriorityFeePerGas"]) {
+        (TxType::Eip1559, name)
+    } else if field(obj, &["gasPrice"]).is_some() && field(obj, &["accessList"]).is_some() {
+        (TxType::Eip2930, "gasPrice + accessList")
+    } else if field(obj, &["gasPrice"]).is_some() {
+        (TxType::Legacy, "gasPrice")
+    } else {
+        return Ok((
+            TxType::Eip1559,
+            "no fee fields present; defaulting to EIP-1559".into(),
+        ));
+    };
+    Ok((inferred.0, format!("inferred from `{}`", inferred.1)))
+}
+
+/// Extract signature components, normalizing `v` to a y-parity bit.
+fn signature(
+    obj: &Map<String, Value>,
+    tx_type: TxType,
+    chain_id: &mut Option<u64>,
+    notes: &mut Vec<String>,
+) -> Result<Option<SignatureParts>, String> {
+    let (Some(r), Some(s)) = (quantity(obj, &["r"])?, quantity(obj, &["s"])?) else {
+        return Ok(None);
+    };
+
+    let y_parity = if let Some(parity) = quantity_u64(obj, &["yParity"])? {
+        match parity {
+            0 => false,
+            1 => true,
+            other => return Err(format!("`yParity` must be 0 or 1, got {other}")),
+        }
+    } else {
+        let v =
+            quantity_u64(obj, &["v"])?.ok_or("signature has `r`/`s` but no `v` or `yParity`")?;
+        match v {
+            0 | 1 => v == 1,
+            27 | 28 => {
+                notes.push(format!(
+                    "`v = {v}` is a pre-EIP-155 legacy value; y-parity is `v - 27`."
+                ));
+                v == 28
+            }
+            v if v >= 35 && tx_type == TxType::Legacy => {
+                let derived = (v - 35) / 2;
+                notes.push(format!(
+                    "`v = {v}` is EIP-155 encoded: y-parity is `(v - 35) % 2`, chain id is `(v - 35) / 2 = {derived}`."
+                ));
+                if chain_id.is_none() {
+                    *chain_id = Some(derived);
+                } else if *chain_id != Some(derived) {
+                    return Err(format!(
+                        "`v = {v}` encodes chain id {derived}, but `chainId` is {}",
+                        chain_id.unwrap_or_default()
+                    ));
+                }
+                (v - 35) % 2 == 1
+            }
+            other => {
+                return Err(format!(
+                    "`v = {other}` is not valid for a {} transaction",
+                    tx_type.label()
+                ));
+            }
+        }
+    };
+
+    let hash = field(obj, &["hash"])
+        .map(|(name, v)| parse_str(name, v, "32-byte hash"))
+        .transpose()?;
+
+    Ok(Some(SignatureParts {
+        r,
+        s,
+        y_parity,
+        hash,
+    }))
+}
+
+fn parse_transaction(obj: &Map<String, Value>) -> Result<ParsedTx, String> {
+    let (tx_type, type_source) = detect_type(obj)?;
+    let mut notes = Vec::new();
+    let mut chain_id = quantity_u64(obj, &["chainId"])?;
+    let signature = signature(obj, tx_type, &mut chain_id, &mut notes)?;
+
+    let input = field(obj, &["input", "data"])
+        .map(|(name, v)| parse_str::<Bytes>(name, v, "hex byte string"))
+        .transpose()?;
+
+    let tx = ParsedTx {
+        tx_type,
+        type_source,
+        from: address(obj, &["from"])?,
+        to: address(obj, &["to"])?,
+        value: quantity(obj, &["value"])?,
+        input,
+        nonce: quantity_u64(obj, &["nonce"])?,
+        chain_id,
+        gas_limit: quantity_u64(obj, &["gas", "gasLimit"])?,
+        gas_price: quantity_u128(obj, &["gasPrice"])?,
+        max_fee_per_gas: quantity_u128(obj, &["maxFeePerGas"])?,
+        max_priority_fee_per_gas: quantity_u128(obj, &["maxPriorityFeePerGas"])?,
+        max_fee_per_blob_gas: quantity_u128(obj, &["maxFeePerBlobGas"])?,
+        access_list: access_list(obj)?,
+        blob_versioned_hashes: field(obj, &["blobVersionedHashes"])
+            .map(|(name, v)| hashes(name, v))
+            .transpose()?,
+        signature,
+        notes,
+    };
+
+    Ok(tx)
+}
+
+fn access_list_expr(list: &[(Address, Vec<B256>)], indent: &str, imports: &mut Imports) -> String {
+    imports.add("alloy::eips::eip2930", "AccessList");
+    if list.is_empty() {
+        return "AccessList::default()".into();
+    }
+    imports.add("alloy::eips::eip2930", "AccessListItem");
+    imports.add("alloy::primitives", "address");
+    let mut out = String::from("AccessList(vec![\n");
+    for (address, keys) in list {
+        let _ = writeln!(out, "{indent}    AccessListItem {{");
+        let _ = writeln!(out, "{indent}        address: {},", address_lit(address));
+        if keys.is_empty() {
+            let _ = writeln!(out, "{indent}        storage_keys: vec![],");
+        } else {
+            imports.add("alloy::primitives", "b256");
+            let _ = writeln!(out, "{indent}        storage_keys: vec![");
+            for key in keys {
+                let _ = writeln!(out, "{indent}            {},", b256_lit(key));
+            }
+            let _ = writeln!(out, "{indent}        ],");
+        }
+        let _ = writeln!(out, "{indent}    }},");
+    }
+    let _ = write!(out, "{indent}])");
+    out
+}
+
+/// Render the consensus struct literal (plus signing code for signed
+/// objects).
+fn consensus_code(tx: &ParsedTx) -> Result<(String, Vec<String>), String> {
+    let ty = tx.tx_type;
+    let mut imports = Imports::default();
+    let mut fields = Vec::new();
+    let mut missing = Vec::new();
+    imports.add("alloy::consensus", ty.struct_name());
+
+    match (ty, tx.chain_id) {
+        (TxType::Legacy, Some(id)) => fields.push(format!("chain_id: Some({}),", int_lit(id))),
+        (_, Some(id)) => fields.push(format!("chain_id: {},", int_lit(id))),
+        (TxType::Legacy, None) => {}
+        (_, None) => missing.push("chain_id"),
+    }
+    match tx.nonce {
+        Some(n) => fields.push(format!("nonce: {},", int_lit(n))),
+        None => missing.push("nonce"),
+    }
+    if matches!(ty, TxType::Legacy | TxType::Eip2930) {
+        match tx.gas_price {
+            Some(p) => fields.push(format!(
+                "gas_price: {},{}",
+                int_lit(p),
+                units_comment(U256::from(p), "gwei", "gwei")
+            )),
+            None => missing.push("gas_price"),
+        }
+    }
+    match tx.gas_limit {
+        Some(g) => fields.push(format!("gas_limit: {},", int_lit(g))),
+        None => missing.push("gas_limit"),
+    }
+    if matches!(ty, TxType::Eip1559 | TxType::Eip4844) {
+        match tx.max_fee_per_gas {
+            Some(f) => fields.push(format!(
+                "max_fee_per_gas: {},{}",
+                int_lit(f),
+                units_comment(U256::from(f), "gwei", "gwei")
+            )),
+            None => missing.push("max_fee_per_gas"),
+        }
+        match tx.max_priority_fee_per_gas {
+            Some(f) => fields.push(format!(
+                "max_priority_fee_per_gas: {},{}",
+                int_lit(f),
+                units_comment(U256::from(f), "gwei", "gwei")
+            )),
+            None => missing.push("max_priority_fee_per_gas"),
+        }
+    }
+    match (ty, tx.to) {
+        (TxType::Eip4844, Some(to)) => {
+            imports.add("alloy::primitives", "address");
+            fields.push(format!("to: {},", address_lit(&to)));
+        }
+        (TxType::Eip4844, None) => {
+            return Err(
+                "EIP-4844 transactions cannot create contracts: `to` is required and has type \
+                `Address`, not `TxKind`."
+                    .into(),
+            );
+        }
+        (_, Some(to)) => {
+            imports.add("alloy::primitives", "TxKind");
+            imports.add("alloy::primitives", "address");
+            fields.push(format!("to: TxKind::Call({}),", address_lit(&to)));
+        }
+        (_, None) => {
+            imports.add("alloy::primitives", "TxKind");
+            fields.push("to: TxKind::Create, // no `to`: contract deployment".into());
+        }
+    }
+    let value = tx.value.unwrap_or_default();
+    imports.add("alloy::primitives", "U256");
+    if u128::try_from(value).is_err() {
+        imports.add("alloy::primitives", "uint");
+    }
+    fields.push(format!(
+        "value: {},{}",
+        u256_expr(value),
+        units_comment(value, "ether", "ETH")
+    ));
+    if ty != TxType::Legacy {
+        let list = tx.access_list.as_deref().unwrap_or_default();
+        fields.push(format!(
+            "access_list: {},",
+            access_list_expr(list, "    ", &mut imports)
+        ));
+    }
+    if ty == TxType::Eip4844 {
+        match &tx.blob_versioned_hashes {
+            Some(hashes) if !hashes.is_empty() => {
+                imports.add("alloy::primitives", "b256");
+                let mut list = String::from("blob_versioned_hashes: vec![\n");
+                for hash in hashes {
+                    let _ = writeln!(list, "        {},", b256_lit(hash));
+                }
+                list.push_str("    ],");
+                fields.push(list);
+            }
+            _ => missing.push("blob_versioned_hashes"),
+        }
+        match tx.max_fee_per_blob_gas {
+            Some(f) => fields.push(format!(
+                "max_fee_per_blob_gas: {},{}",
+                int_lit(f),
+                units_comment(U256::from(f), "gwei", "gwei")
+            )),
+            None => missing.push("max_fee_per_blob_gas"),
+        }
+    }
+    let input = tx.input.clone().unwrap_or_default();
+    if input.is_empty() {
+        imports.add("alloy::primitives", "Bytes");
+    } else {
+        imports.add("alloy::primitives", "bytes");
+    }
+    fields.push(format!("input: {},", bytes_expr(&input)));
+
+    let mut body = format!("let tx = {} {{\n", ty.struct_name());
+    for f in &fields {
+        let _ = writeln!(body, "    {f}");
+    }
+    if !missing.is_empty() {
+        let _ = writeln!(
+            body,
+            "    // not in the input, left at their defaults: {}",
+            missing.join(", ")
+        );
+        body.push_str("    ..Default::default()\n");
+    }
+    body.push_str("};\n");
+
+    if let Some(sig) = &tx.signature {
+        imports.add("alloy::consensus", "SignableTransaction");
+        imports.add("alloy::consensus", "TxEnvelope");
+        imports.add("alloy::primitives", "Signature");
+        imports.add("alloy::primitives", "uint");
+        let _ = write!(
+            body,
+            "\nlet signature = Signature::new(\n    uint!({:#x}_U256), // r\n    uint!({:#x}_U256), // s\n    {}, // y_parity\n);\n\
+            let signed = tx.into_signed(signature);\n",
+            sig.r, sig.s, sig.y_parity
+        );
+        if let Some(hash) = &sig.hash {
+            imports.add("alloy::primitives", "b256");
+            let _ = writeln!(body, "assert_eq!(*signed.hash(), {});", b256_lit(hash));
+        }
+        body.push_str("let envelope = TxEnvelope::from(signed);\n");
+    }
+
+    let mut notes = Vec::new();
+    if !missing.is_empty() {
+        notes.push(format!(
+            "The struct literal has no fillers: set {} before signing or the transaction will be rejected.",
+            missing
+                .iter()
+                .map(|m| format!("`{m}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    Ok((format!("{}\n{body}", imports.render()), notes))
+}
+
+/// Render a `TransactionRequest` builder chain.
+fn request_code(tx: &ParsedTx) -> String {
+    let ty = tx.tx_type;
+    let mut imports = Imports::default();
+    imports.add("alloy::network", "TransactionBuilder");
+    imports.add("alloy::rpc::types", "TransactionRequest");
+    // (call, trailing comment) pairs, so the final `;` lands before the comment.
+    let mut calls: Vec<(String, String)> = Vec::new();
+
+    if let Some(from) = &tx.from {
+        imports.add("alloy::primitives", "address");
+        calls.push((format!(".with_from({})", address_lit(from)), String::new()));
+    }
+    if let Some(to) = &tx.to {
+        imports.add("alloy::primitives", "address");
+        calls.push((format!(".with_to({})", address_lit(to)), String::new()));
+    }
+    if let Some(value) = tx.value.filter(|v| !v.is_zero()) {
+        imports.add("alloy::primitives", "U256");
+        if u128::try_from(value).is_err() {
+            imports.add("alloy::primitives", "uint");
+        }
+        calls.push((
+            format!(".with_value({})", u256_expr(value)),
+            units_comment(value, "ether", "ETH"),
+        ));
+    }
+    if let Some(input) = tx.input.as_ref().filter(|i| !i.is_empty()) {
+        imports.add("alloy::primitives", "bytes");
+        if tx.to.is_none() {
+            calls.push((
+                format!(".with_deploy_code({})", bytes_expr(input)),
+                " // no `to`: contract deployment".into(),
+            ));
+        } else {
+            calls.push((format!(".with_input({})", bytes_expr(input)), String::new()));
+        }
+    }
+    if let Some(n) = tx.nonce {
+        calls.push((format!(".with_nonce({})", int_lit(n)), String::new()));
+    }
+    if let Some(id) = tx.chain_id {
+        calls.push((format!(".with_chain_id({})", int_lit(id)), String::new()));
+    }
+    if let Some(g) = tx.gas_limit {
+        calls.push((format!(".with_gas_limit({})", int_lit(g)), String::new()));
+    }
+    let fees = match ty {
+        TxType::Legacy | TxType::Eip2930 => vec![("with_gas_price", tx.gas_price)],
+        TxType::Eip1559 | TxType::Eip4844 => vec![
+            ("with_max_fee_per_gas", tx.max_fee_per_gas),
+            ("with_max_priority_fee_per_gas", tx.max_priority_fee_per_gas),
+        ],
+    };
+    for (method, fee) in fees {
+        if let Some(f) = fee {
+            calls.push((
+                format!(".{method}({})", int_lit(f)),
+                units_comment(U256::from(f), "gwei", "gwei"),
+            ));
+        }
+    }
+    if let Some(list) = tx.access_list.as_ref().filter(|l| !l.is_empty()) {
+        calls.push((
+            format!(
+                ".with_access_list({})",
+                access_list_expr(list, "    ", &mut imports)
+            ),
+            String::new(),
+        ));
+    }
+    if let Some(f) = tx.max_fee_per_blob_gas.filter(|_| ty == TxType::Eip4844) {
+        imports.add("alloy::network", "TransactionBuilder4844");
+        calls.push((
+            format!(".with_max_fee_per_blob_gas({})", int_lit(f)),
+            units_comment(U256::from(f), "gwei", "gwei"),
+        ));
+    }
+
+    let blob_hashes = tx
+        .blob_versioned_hashes
+        .as_ref()
+        .filter(|h| !h.is_empty() && ty == TxType::Eip4844);
+    let binding = if blob_hashes.is_some() {
+        "let mut tx"
+    } else {
+        "let tx"
+    };
+    let mut body = format!("{binding} = TransactionRequest::default()");
+    let last = calls.len().saturating_sub(1);
+    for (i, (call, comment)) in calls.iter().enumerate() {
+        let end = if i == last { ";" } else { "" };
+        let _ = write!(body, "\n    {call}{end}{comment}");
+    }
+    if calls.is_empty() {
+        body.push(';');
+    }
+    body.push('\n');
+
+    if let Some(hashes) = blob_hashes {
+        imports.add("alloy::primitives", "b256");
+        body.push_str(
+            "// `.with_blob_sidecar(sidecar)` derives these from the sidecar; set them directly\n\
+            // only when the sidecar is attached elsewhere.\n\
+            tx.blob_versioned_hashes = Some(vec![\n",
+        );
+        for hash in hashes {
+            let _ = writeln!(body, "    {},", b256_lit(hash));
+        }
+        body.push_str("]);\n");
+    }
+
+    format!("{}\n{body}", imports.render())
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct GenerateTransactionCodeRequest {
+    #[schemars(
+        description = "Transaction JSON: an RPC TransactionRequest (e.g. {\"to\": \"0x...\", \"value\": \"0xde0b6b3a7640000\", \"maxFeePerGas\": \"0x6fc23ac00\"}) or a signed transaction object as returned by eth_getTransactionByHash. Quantities may be hex strings, decimal strings or integers."
+    )]
+    transaction: serde_json::Value,
+    #[schemars(
+        description = "Output style: 'consensus' (TxEip1559/TxEip4844/TxLegacy struct literal), 'request' (TransactionRequest builder chain), or 'both' (default)"
+    )]
+    style: Option<String>,
+}
+
+#[tool_router(router = transaction_code_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Generate alloy Rust code that builds a transaction given as JSON.
+    #[tool(
+        description = "Generate alloy Rust code from transaction JSON (RPC TransactionRequest or signed tx object): TxEip1559/TxEip4844/TxLegacy struct literals and/or a TransactionRequest builder chain, with correct field types and literal macros."
+    )]
+    fn generate_transaction_code(
+        &self,
+        Parameters(GenerateTransactionCodeRequest { transaction, style }): Parameters<
+            GenerateTransactionCodeRequest,
+        >,
+    ) -> String {
+        let (consensus, request) = match style.as_deref().unwrap_or("both") {
+            "both" => (true, true),
+            "consensus" | "struct" => (true, false),
+            "request" | "builder" => (false, true),
+            other => {
+                return format!("Unknown style '{other}'. Use 'consensus', 'request', or 'both'.");
+            }
+        };
+
+        // Clients sometimes pass the JSON object as a string.
+        let transaction = match transaction {
+            Value::String(s) => match serde_json::from_str(&s) {
+                Ok(v) => v,
+                Err(e) => return format!("Invalid transaction JSON: {e}"),
+            },
+            v => v,
+        };
+        let Some(obj) = transaction.as_object() else {
+            return "Transaction must be a JSON object.".into();
+        };
+
+        let tx = match parse_transaction(obj) {
+            Ok(tx) => tx,
+            Err(e) => return format!("Could not parse transaction: {e}"),
+        };
+
+        let mut notes = tx.notes.clone();
+        let mut result = format!(
+            "# {} transaction\n\nType: {}\n\n",
+            tx.tx_type.struct_name(),
+            tx.type_source
+        );
+
+        if consensus {
+            match consensus_code(&tx) {
+                Ok((code, consensus_notes)) => {
+                    let _ = write!(
+                        result,
+                        "## Consensus struct (`{}`)\n\n```rust\n{code}```\n\n",
+                        tx.tx_type.struct_name()
+                    );
+                    notes.extend(consensus_notes);
+                }
+                Err(e) if request => notes.push(e),
+                Err(e) => return format!("Could not build a consensus struct: {e}"),
+            }
+        }
+        if request {
+            let _ = write!(
+                result,
+                "## `TransactionRequest` builder\n\n```rust\n{}```\n\n",
+                request_code(&tx)
+            );
+            notes.push(
+                "`with_*` methods come from the `TransactionBuilder` trait (`alloy::network`); \
+                with the recommended fillers, omitted nonce/gas/chain id fields are filled by the provider."
+                    .into(),
+            );
+        }
+        if tx.gas_price.is_some() && matches!(tx.tx_type, TxType::Eip1559 | TxType::Eip4844) {
+            notes.push(
+                "`gasPrice` was ignored: EIP-1559 style transactions use `max_fee_per_gas`/\
+                `max_priority_fee_per_gas` (RPC responses report the effective gas price here)."
+                    .into(),
+            );
+        }
+
+        let _ = writeln!(
+            result,
+            "## `{}` field types\n\n| Field | Rust type |\n|-------|-----------|",
+            tx.tx_type.struct_name()
+        );
+        for (name, ty) in tx.tx_type.field_types() {
+            let _ = writeln!(result, "| `{name}` | `{ty}` |");
+        }
+        result.push_str("\n`ChainId` is an alias for `u64`.");
+        if tx.tx_type == TxType::Eip4844 {
+            result
+                .push_str(" `to` is a plain `Address`: blob transactions cannot create contracts.");
+        }
+        result.push('\n');
+
+        if !notes.is_empty() {
+            result.push_str("\n## Notes\n\n");
+            for note in &notes {
+                let _ = writeln!(result, "- {note}");
+            }
+        }
+
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://consensus/transactions` — Transaction types\n\
+            - `alloy://rpc/transaction-request` — TransactionRequest builder methods\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn detect(tx: Value) -> Result<(TxType, String), String> {
+        detect_type(tx.as_object().unwrap())
+    }
+
+    #[test]
+    fn detect_type_rejects_types_wider_than_u64() {
+        let err = detect(json!({ "type": "0x10000000000000000000" })).unwrap_err();
+        assert!(err.starts_with("Unsupported transaction type 0x10000000000000000000."));
+    }
+
+    #[test]
+    fn detect_type_reads_explicit_and_inferred_types() {
+        assert!(matches!(
+            detect(json!({ "type": "0x2" })),
+            Ok((TxType::Eip1559, _))
+        ));
+        assert!(matches!(
+            detect(json!({ "gasPrice": "0x1" })),
+            Ok((TxType::Legacy, _))
+        ));
+        assert!(detect(json!({ "type": "0x7e" })).is_err());
+    }
+}
@@ -0,0 +1,325 @@
+use std::fmt::Write;
+
+use alloy_primitives::{
+    U256,
+    utils::{Unit, format_units, parse_units},
+};
+use rmcp::{handler::server::wrapper::Parameters, schemars, tool, tool_router};
+
+use super::codegen::{Imports, int_lit, u256_expr};
+use crate::server::AlloyMcpServer;
+
+/// Name a unit the way `Unit::from_str` spells it, falling back to the
+/// decimal count. Token amounts never get ether denominations: 6 decimals
+/// is USDC, not mwei.
+fn unit_name(unit: Unit, token: bool) -> String {
+    match unit {
+        _ if token => match unit.get() {
+            0 => "base units".into(),
+            decimals => format!("{decimals} decimals"),
+        },
+        Unit::WEI => "wei".into(),
+        Unit::KWEI => "kwei".into(),
+        Unit::MWEI => "mwei".into(),
+        Unit::GWEI => "gwei".into(),
+        Unit::TWEI => "twei".into(),
+        Unit::PWEI => "pwei".into(),
+        Unit::ETHER => "ether".into(),
+        other => format!("{} decimals", other.get()),
+    }
+}
+
+/// Unit name for table rows and headings; unnamed units read as "units",
+/// or "tokens" for a token's whole-token amount.
+fn unit_label(unit: Unit, token: bool) -> String {
+    match unit_name(unit, token) {
+        name if name.ends_with("decimals") && token => "tokens".into(),
+        name if name.ends_with("decimals") => "units".into(),
+        name => name,
+    }
+}
+
+/// The argument to pass to `parse_units`/`format_units` for a unit: the
+/// name for named units, the decimal count otherwise.
+fn unit_arg(unit: Unit, token: bool) -> String {
+    match unit_name(unit, token).as_str() {
+        name if !token && !name.ends_with("decimals") => format!("{name:?}"),
+        _ => format!("{}u8", unit.get()),
+    }
+}
+
+/// Whether a unit argument is a token's decimal count rather than an
+/// ether denomination: any number but 18.
+fn is_token(unit: &str) -> bool {
+    unit.trim()
+        .parse::<u8>()
+        .is_ok_and(|decimals| decimals != 18)
+}
+
+/// Format `wei` in `unit`, trimming trailing fractional zeros.
+fn display(wei: U256, unit: Unit) -> String {
+    let formatted = format_units(wei, unit.get()).unwrap_or_default();
+    if formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        formatted
+    }
+}
+
+/// `m * 10^k` rendering of a value with trailing zeros, e.g.
+/// `U256::from(15) * U256::from(10).pow(U256::from(17))`.
+fn pow10_expr(wei: U256) -> Option<String> {
+    let ten = U256::from(10);
+    let mut mantissa = wei;
+    let mut exponent = 0u32;
+    while !mantissa.is_zero() && (mantissa % ten).is_zero() {
+        mantissa /= ten;
+        exponent += 1;
+    }
+    let mantissa = u64::try_from(mantissa).ok()?;
+    let pow = format!("U256::from(10).pow(U256::from({exponent}))");
+    match (exponent, mantissa) {
+        (0..=2, _) => None,
+        (_, 1) => Some(pow),
+        _ => Some(format!("U256::from({}) * {pow}", int_lit(mantissa))),
+    }
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ConvertUnitsRequest {
+    #[schemars(
+        description = "Amount to convert: a decimal string such as '1.5' or '30', or a 0x-prefixed hex integer"
+    )]
+    amount: String,
+    #[schemars(
+        description = "Unit of `amount`: wei, kwei, mwei, gwei, twei, pwei, ether, or a number of decimals (e.g. '6' for USDC)"
+    )]
+    from_unit: String,
+    #[schemars(
+        description = "Optional target unit (same options as `from_unit`); wei, gwei and ether are shown unless a unit is a token's decimals"
+    )]
+    to_unit: Option<String>,
+}
+
+#[tool_router(router = units_router, vis = "pub(crate)")]
+impl AlloyMcpServer {
+    /// Convert between wei, gwei, ether and arbitrary decimals, with the
+    /// `U256` expressions that produce the value.
+    #[tool(
+        description = "Convert amounts between wei, gwei, ether or arbitrary decimals using alloy-primitives parse_units/format_units semantics, and show the exact U256 Rust expressions (parse_ether, uint!, U256::from(10).pow(..)) that produce the value."
+    )]
+    fn convert_units(
+        &self,
+        Parameters(ConvertUnitsRequest {
+            amount,
+            from_unit,
+            to_unit,
+        }): Parameters<ConvertUnitsRequest>,
+    ) -> String {
+        let from: Unit = match from_unit.trim().parse() {
+            Ok(u) => u,
+            Err(e) => {
+                return format!("Invalid `from_unit`: {e}. Use a unit name or 0-77 decimals.");
+            }
+        };
+        let to: Option<Unit> = match to_unit.as_deref().map(|u| u.trim().parse()) {
+            Some(Ok(u)) => Some(u),
+            Some(Err(e)) => {
+                return format!("Invalid `to_unit`: {e}. Use a unit name or 0-77 decimals.");
+            }
+            None => None,
+        };
+        let token = is_token(&from_unit) || to_unit.as_deref().is_some_and(is_token);
+        // What the generated code calls the raw integer.
+        let raw = if token { "amount" } else { "wei" };
+
+        let amount = amount.trim().replace(['_', ','], "");
+        if amount.starts_with('-') {
+            return "Negative amounts parse to `I256` with `parse_units`; this tool converts \
+                unsigned amounts only."
+                .into();
+        }
+        let mut notes = Vec::new();
+        let (wei, runtime_amount) = if amount.starts_with("0x") {
+            let Ok(raw) = amount.parse::<U256>() else {
+                return format!("`{amount}` is not a valid hex integer.");
+            };
+            match raw.checked_mul(from.wei()) {
+                Some(wei) => (wei, raw.to_string()),
+                None => return format!("{amount} {} overflows U256.", unit_name(from, token)),
+            }
+        } else {
+            match parse_units(&amount, from.get()) {
+                Ok(parsed) => {
+                    let decimals = amount.split_once('.').map_or(0, |(_, d)| d.len());
+                    if decimals > usize::from(from.get()) {
+                        notes.push(format!(
+                            "`{amount}` has {decimals} decimals but {} only has {}: `parse_units` \
+                            silently truncates the extra digits.",
+                            unit_name(from, token),
+                            from.get()
+                        ));
+                    }
+                    (parsed.get_absolute(), amount.clone())
+                }
+                Err(e) => {
+                    return format!(
+                        "Could not parse `{amount}` as {}: {e}",
+                        unit_name(from, token)
+                    );
+                }
+            }
+        };
+
+        let mut result = format!(
+            "# {} {} ({} decimals)\n\n| Unit | Value |\n|------|-------|\n",
+            display(wei, from),
+            unit_label(from, token),
+            from.get()
+        );
+        let mut units = match token {
+            true => vec![Unit::WEI],
+            false => vec![Unit::WEI, Unit::GWEI, Unit::ETHER],
+        };
+        for unit in [Some(from), to].into_iter().flatten() {
+            if !units.contains(&unit) {
+                units.push(unit);
+            }
+        }
+        for unit in &units {
+            let _ = writeln!(
+                result,
+                "| {} ({} decimals) | `{}` |",
+                unit_label(*unit, token),
+                unit.get(),
+                display(wei, *unit)
+            );
+        }
+        let fits = |ok: bool| if ok { "yes" } else { "no" };
+        let (subject, context) = match token {
+            true => ("The base-unit amount fits", ""),
+            false => (
+                "Raw wei fits",
+                " (gas prices are `u128`, gas limits `u64`, values `U256`)",
+            ),
+        };
+        let _ = writeln!(
+            result,
+            "\n{subject} in `u64`: {} · `u128`: {}{context}",
+            fits(u64::try_from(wei).is_ok()),
+            fits(u128::try_from(wei).is_ok()),
+        );
+
+        let mut imports = Imports::default();
+        imports.add("alloy::primitives", "U256");
+        let mut code = String::from("// Parse at runtime (same semantics as this tool)\n");
+        if from == Unit::ETHER {
+            imports.add("alloy::primitives::utils", "parse_ether");
+            let _ = writeln!(code, "let {raw}: U256 = parse_ether({runtime_amount:?})?;");
+        }
+        imports.add("alloy::primitives::utils", "parse_units");
+        let _ = writeln!(
+            code,
+            "let {raw}: U256 = parse_units({runtime_amount:?}, {})?.into();",
+            unit_arg(from, token)
+        );
+
+        code.push_str("\n// Constant expressions\n");
+        imports.add("alloy::primitives", "uint");
+        let _ = writeln!(code, "let {raw} = uint!({}_U256);", int_lit(wei));
+        if u128::try_from(wei).is_ok() {
+            let _ = writeln!(code, "let {raw} = {};", u256_expr(wei));
+        }
+        if let Some(expr) = pow10_expr(wei) {
+            let _ = writeln!(code, "let {raw} = {expr};");
+        }
+
+        code.push_str("\n// Format for display\n");
+        for unit in [Some(from), to].into_iter().flatten() {
+            if unit == Unit::ETHER {
+                imports.add("alloy::primitives::utils", "format_ether");
+                let _ = writeln!(
+                    code,
+                    "let shown = format_ether({raw}); // {:?}",
+                    format_units(wei, unit.get()).unwrap_or_default()
+                );
+            } else {
+                imports.add("alloy::primitives::utils", "format_units");
+                let _ = writeln!(
+                    code,
+                    "let shown = format_units({raw}, {})?; // {:?}",
+                    unit_arg(unit, token),
+                    format_units(wei, unit.get()).unwrap_or_default()
+                );
+            }
+        }
+
+        let _ = write!(
+            result,
+            "\n## Rust\n\n```rust\n{}\n{code}```\n",
+            imports.render()
+        );
+
+        if !token && u64::try_from(wei).is_err() && u128::try_from(wei).is_ok() {
+            notes.push(
+                "The wei value does not fit in `u64`: a `...u64` literal will not compile, and \
+                `u64` arithmetic such as `1_000_000_000_000_000_000u64 * n` overflows above ~18.44 ETH. \
+                Use `u128`, `uint!` or `parse_ether`."
+                    .into(),
+            );
+        }
+        notes.push(
+            "`U256::from(1e18)` does not compile (`f64` has no `U256` conversion), and float math \
+            loses precision: parse decimal strings instead."
+                .into(),
+        );
+        notes.push(
+            "`format_units` always prints every decimal place (`1.500000000000000000`).".into(),
+        );
+        result.push_str("\n## Notes\n\n");
+        for note in &notes {
+            let _ = writeln!(result, "- {note}");
+        }
+        result.push_str(
+            "\n**Key resources:**\n\
+            - `alloy://primitives/core-types` — U256 and literal macros\n\
+            - `alloy://provider/fillers` — Gas price fields\n",
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(amount: &str, from_unit: &str, to_unit: Option<&str>) -> String {
+        AlloyMcpServer::new().convert_units(Parameters(ConvertUnitsRequest {
+            amount: amount.into(),
+            from_unit: from_unit.into(),
+            to_unit: to_unit.map(Into::into),
+        }))
+    }
+
+    #[test]
+    fn converts_ether_denominations() {
+        let result = convert("1.5", "ether", None);
+        assert!(result.contains("| wei (0 decimals) | `1500000000000000000` |"));
+        assert!(result.contains("| gwei (9 decimals) | `1500000000` |"));
+        assert!(result.contains("let wei: U256 = parse_ether(\"1.5\")?;"));
+    }
+
+    #[test]
+    fn token_decimals_get_no_ether_names() {
+        let result = convert("1.5", "6", None);
+        assert!(result.starts_with("# 1.5 tokens (6 decimals)"));
+        assert!(result.contains("| base units (0 decimals) | `1500000` |"));
+        assert!(result.contains("let amount: U256 = parse_units(\"1.5\", 6u8)?.into();"));
+        for ether in ["wei", "mwei", "ether"] {
+            assert!(!result.contains(&format!("| {ether} ")), "{result}");
+        }
+    }
+}
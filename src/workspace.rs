@@ -0,0 +1,351 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use toml::{Table, Value};
+
+/// Dependency tables read from each manifest, besides `target.*` ones.
+const DEPENDENCY_TABLES: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Crates released from alloy-core, versioned apart from the rest of alloy.
+const CORE_CRATES: [&str; 7] = [
+    "alloy-primitives",
+    "alloy-sol-types",
+    "alloy-sol-macro",
+    "alloy-dyn-abi",
+    "alloy-json-abi",
+    "alloy-sol-type-parser",
+    "alloy-core",
+];
+
+/// One alloy dependency of the project, merged across workspace members.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dependency {
+    /// Version requirement as written, e.g. `1.0`.
+    pub requirement: Option<String>,
+    /// Features enabled explicitly.
+    pub features: BTreeSet<String>,
+    /// Whether any member keeps the crate's default features.
+    pub default_features: bool,
+}
+
+impl Dependency {
+    /// The `Cargo.toml` line for `name` with `extra` features added, keeping
+    /// the project's version and `default-features = false`.
+    pub fn line<'a>(&self, name: &str, extra: impl IntoIterator<Item = &'a str>) -> String {
+        let version = self.requirement.as_deref().unwrap_or("1");
+        let mut features = self.features.clone();
+        features.extend(extra.into_iter().map(String::from));
+        let mut fields = vec![format!("version = \"{version}\"")];
+        if !self.default_features {
+            fields.push("default-features = false".into());
+        }
+        if !features.is_empty() {
+            let list: Vec<String> = features.iter().map(|f| format!("{f:?}")).collect();
+            fields.push(format!("features = [{}]", list.join(", ")));
+        }
+        match fields.as_slice() {
+            [_] => format!("{name} = \"{version}\""),
+            _ => format!("{name} = {{ {} }}", fields.join(", ")),
+        }
+    }
+}
+
+/// The alloy setup of a user's project, read from its `Cargo.toml` files and
+/// `Cargo.lock`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workspace {
+    /// Directory holding the root `Cargo.toml`.
+    pub root: PathBuf,
+    /// `alloy` and `alloy-*` dependencies by crate name.
+    pub dependencies: BTreeMap<String, Dependency>,
+    /// Resolved alloy version from `Cargo.lock`, or the requirement when the
+    /// project has no lockfile.
+    pub version: Option<String>,
+}
+
+impl Workspace {
+    /// Read the project at `path`, a directory or its `Cargo.toml`. `None`
+    /// when there is no manifest or it does not depend on alloy.
+    pub fn load(path: &Path) -> Option<Self> {
+        let root = if path.is_file() {
+            path.parent()?.to_path_buf()
+        } else {
+            path.to_path_buf()
+        };
+        let manifest = read_toml(&root.join("Cargo.toml"))?;
+
+        let inherited = manifest
+            .get("workspace")
+            .and_then(|w| w.get("dependencies"))
+            .and_then(Value::as_table)
+            .map(|t| alloy_dependencies(t, &BTreeMap::new()))
+            .unwrap_or_default();
+
+        let mut dependencies = BTreeMap::new();
+        let mut manifests = vec![manifest.clone()];
+        manifests.extend(
+            members(&root, &manifest)
+                .iter()
+                .filter_map(|dir| read_toml(&dir.join("Cargo.toml"))),
+        );
+        for manifest in &manifests {
+            for table in dependency_tables(manifest) {
+                for (name, dependency) in alloy_dependencies(table, &inherited) {
+                    let merged: &mut Dependency = dependencies.entry(name).or_default();
+                    merged.requirement = merged.requirement.take().or(dependency.requirement);
+                    merged.features.extend(dependency.features);
+                    merged.default_features |= dependency.default_features;
+                }
+            }
+        }
+        if dependencies.is_empty() {
+            return None;
+        }
+
+        let locked = read_toml(&root.join("Cargo.lock"))
+            .map(|lock| locked_versions(&lock))
+            .unwrap_or_default();
+        // alloy-core crates have their own version line; prefer the others.
+        let mut names: Vec<&str> = dependencies.keys().map(String::as_str).collect();
+        names.sort_by_key(|name| (*name != "alloy", CORE_CRATES.contains(name)));
+        let version = names
+            .iter()
+            .find_map(|name| locked.get(*name).cloned())
+            .or_else(|| {
+                names
+                    .iter()
+                    .find_map(|name| dependencies[*name].requirement.clone())
+            });
+
+        Some(Self {
+            root,
+            dependencies,
+            version,
+        })
+    }
+
+    /// Find the first of `roots` (MCP root URIs or paths) that holds an
+    /// alloy project.
+    pub fn from_roots<'a>(roots: impl IntoIterator<Item = &'a str>) -> Option<Self> {
+        roots
+            .into_iter()
+            .find_map(|root| Self::load(&root_path(root)?))
+    }
+
+    /// The umbrella `alloy` dependency, if the project uses it.
+    pub fn umbrella(&self) -> Option<&Dependency> {
+        self.dependencies.get("alloy")
+    }
+
+    /// Major version of the resolved alloy release, when known.
+    pub fn major_version(&self) -> Option<u64> {
+        let version = self.version.as_deref()?;
+        version
+            .trim_start_matches(['^', '=', '~', '>', '<', ' '])
+            .split('.')
+            .next()?
+            .parse()
+            .ok()
+    }
+
+    /// Version requirement for adding another alloy crate: `1`, or
+    /// `0.15` on a pre-1.0 release, where minor versions break.
+    pub fn requirement(&self) -> String {
+        let version = self.version.as_deref().unwrap_or("1");
+        let mut parts = version
+            .trim_start_matches(['^', '=', '~', '>', '<', ' '])
+            .split('.');
+        match (parts.next(), parts.next()) {
+            (Some("0"), Some(minor)) => format!("0.{minor}"),
+            (Some(major), _) if !major.is_empty() => major.to_string(),
+            _ => "1".into(),
+        }
+    }
+
+    /// One-line summary, e.g. `alloy 1.0.9 (umbrella crate, features: full)`.
+    pub fn summary(&self) -> String {
+        let version = self.version.as_deref().unwrap_or("unknown version");
+        match self.umbrella() {
+            Some(alloy) if alloy.features.is_empty() => {
+                format!("alloy {version} (umbrella crate, default features)")
+            }
+            Some(alloy) => format!(
+                "alloy {version} (umbrella crate, features: {})",
+                alloy
+                    .features
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            None => format!(
+                "alloy {version} (crates: {})",
+                self.dependencies
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+fn read_toml(path: &Path) -> Option<Table> {
+    let text = fs::read_to_string(path).ok()?;
+    match text.parse::<Table>() {
+        Ok(table) => Some(table),
+        Err(e) => {
+            tracing::warn!("ignoring {}: {e}", path.display());
+            None
+        }
+    }
+}
+
+/// Local path of an MCP root, which clients send as a `file://` URI.
+fn root_path(root: &str) -> Option<PathBuf> {
+    let path = match root.strip_prefix("file://") {
+        Some(rest) => rest.strip_prefix("localhost").unwrap_or(rest),
+        None if root.contains("://") => return None,
+        None => root,
+    };
+    Some(PathBuf::from(percent_decode(path)))
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Member directories of a workspace manifest. Supports plain paths and a
+/// trailing `/*`, which covers the layouts cargo generates.
+fn members(root: &Path, manifest: &Table) -> Vec<PathBuf> {
+    let Some(members) = manifest
+        .get("workspace")
+        .and_then(|w| w.get("members"))
+        .and_then(Value::as_array)
+    else {
+        return Vec::new();
+    };
+    let mut dirs = Vec::new();
+    for member in members.iter().filter_map(Value::as_str) {
+        match member.strip_suffix("/*") {
+            Some(parent) => {
+                if let Ok(entries) = fs::read_dir(root.join(parent)) {
+                    let mut found: Vec<PathBuf> = entries
+                        .filter_map(|e| Some(e.ok()?.path()))
+                        .filter(|p| p.is_dir())
+                        .collect();
+                    found.sort();
+                    dirs.extend(found);
+                }
+            }
+            None => dirs.push(root.join(member)),
+        }
+    }
+    dirs
+}
+
+/// The `[dependencies]`-style tables of a manifest, including
+/// `[target.'cfg(..)'.dependencies]`.
+fn dependency_tables(manifest: &Table) -> Vec<&Table> {
+    let targets = manifest
+        .get("target")
+        .and_then(Value::as_table)
+        .into_iter()
+        .flat_map(|t| t.values())
+        .filter_map(Value::as_table);
+    std::iter::once(manifest)
+        .chain(targets)
+        .flat_map(|t| DEPENDENCY_TABLES.iter().filter_map(|k| t.get(*k)))
+        .filter_map(Value::as_table)
+        .collect()
+}
+
+/// The alloy crates in a dependency table, resolving `package = "..."`
+/// renames and `workspace = true` against `inherited`.
+fn alloy_dependencies(
+    table: &Table,
+    inherited: &BTreeMap<String, Dependency>,
+) -> BTreeMap<String, Dependency> {
+    let mut found = BTreeMap::new();
+    for (key, value) in table {
+        let name = value.get("package").and_then(Value::as_str).unwrap_or(key);
+        if name != "alloy" && !name.starts_with("alloy-") {
+            continue;
+        }
+        let strings = |field: &str| -> BTreeSet<String> {
+            value
+                .get(field)
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(String::from)
+                .collect()
+        };
+        let mut dependency = match value {
+            Value::String(requirement) => Dependency {
+                requirement: Some(requirement.clone()),
+                default_features: true,
+                ..Default::default()
+            },
+            _ if value.get("workspace").and_then(Value::as_bool) == Some(true) => {
+                inherited.get(name).cloned().unwrap_or(Dependency {
+                    default_features: true,
+                    ..Default::default()
+                })
+            }
+            _ => Dependency {
+                requirement: value
+                    .get("version")
+                    .and_then(Value::as_str)
+                    .map(String::from),
+                features: BTreeSet::new(),
+                default_features: value
+                    .get("default-features")
+                    .or_else(|| value.get("default_features"))
+                    .and_then(Value::as_bool)
+                    .unwrap_or(true),
+            },
+        };
+        dependency.features.extend(strings("features"));
+        found.insert(name.to_string(), dependency);
+    }
+    found
+}
+
+/// `name → version` for every alloy package in a `Cargo.lock`.
+fn locked_versions(lock: &Table) -> BTreeMap<String, String> {
+    lock.get("package")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|package| {
+            let name = package.get("name")?.as_str()?;
+            let version = package.get("version")?.as_str()?;
+            (name == "alloy" || name.starts_with("alloy-"))
+                .then(|| (name.to_string(), version.to_string()))
+        })
+        .collect()
+}